@@ -0,0 +1,232 @@
+use crate::rpser::xml::BuildElement;
+use crate::rpser::{self, Method};
+use crate::wsdl::{WsdlCache, WsdlSource};
+use crate::{http, wsdl};
+use crate::{FromElement, Page, PageSummary, Result, ServerInfo, SessionBuilder, UpdatePage};
+use xmltree::Element;
+
+/// Non-blocking client's session.
+///
+/// Offers the same core operations as `Session`, but as `async fn`s, so it can be
+/// used from within a `tokio` runtime without `spawn_blocking`.
+///
+/// Unlike `Session`, the token is not destroyed automatically when `AsyncSession` goes
+/// out of scope, because `Drop` can not wait for a network call to complete. Call
+/// `logout` explicitly when done.
+///
+/// Calls always go through the async `reqwest` client: the custom `Transport`, the
+/// `RetryPolicy` and the automatic re-login configured on `SessionBuilder` apply only
+/// to the blocking `Session`. The `WsdlSource` applies to both; reading a WSDL file
+/// or the cache directory is done on the calling thread.
+pub struct AsyncSession {
+    client: reqwest::Client,
+    wsdl: wsdl::Wsdl,
+    token: String,
+    server_info: Option<ServerInfo>,
+}
+
+impl AsyncSession {
+    /**
+    Create new confluence session without blocking.
+
+    ## Example
+
+    ```no_run
+    # async fn run() -> confluence::Result<()> {
+    let session = confluence::AsyncSession::login(
+        "https://confluence",
+        "user",
+        "pass"
+    ).await?;
+    # Ok(())
+    # }
+    ```
+
+    Use `SessionBuilder::login_async` for more control over the HTTP transport.
+    */
+    pub async fn login(url: &str, user: &str, pass: &str) -> Result<AsyncSession> {
+        SessionBuilder::new().login_async(url, user, pass).await
+    }
+
+    /// Create new confluence session that uses the specified HTTP client and WSDL source.
+    pub(crate) async fn login_with_client(
+        client: reqwest::Client,
+        url: &str,
+        user: &str,
+        pass: &str,
+        source: &WsdlSource,
+    ) -> Result<AsyncSession> {
+        debug!("logging in at url {:?} with user {:?}", url, user);
+
+        let mut fetched = None;
+        let wsdl = match *source {
+            WsdlSource::Fetch => {
+                let wsdl_url = crate::wsdl_url(url);
+                debug!("getting wsdl from url {:?}", wsdl_url);
+                wsdl::fetch_async(&client, &wsdl_url).await?
+            }
+            WsdlSource::Bundled => wsdl::bundled(&crate::endpoint_url(url)),
+            WsdlSource::File(ref path) => wsdl::load(path, &crate::endpoint_url(url))?,
+            WsdlSource::Cache(ref dir) => match WsdlCache::new(dir.clone()).load(url) {
+                Some(wsdl) => wsdl,
+                None => {
+                    let wsdl_url = crate::wsdl_url(url);
+                    debug!("wsdl is not cached, getting it from url {:?}", wsdl_url);
+                    let xml = http::get_async(&client, &wsdl_url).await?.body;
+                    let wsdl = wsdl::parse(&xml)?;
+                    fetched = Some(xml);
+                    wsdl
+                }
+            },
+        };
+        let mut session = AsyncSession {
+            client,
+            wsdl,
+            token: String::new(),
+            server_info: None,
+        };
+
+        let response = session
+            .call(
+                Method::new("login")
+                    .with(Element::node("username").with_text(user))
+                    .with(Element::node("password").with_text(pass)),
+            )
+            .await?;
+
+        session.token = crate::login_token(response)?;
+        session.server_info = Some(session.get_server_info().await?);
+
+        if let WsdlSource::Cache(ref dir) = *source {
+            session.sync_wsdl_cache(&WsdlCache::new(dir.clone()), url, fetched).await?;
+        }
+
+        Ok(session)
+    }
+
+    /// Make sure the cache has WSDL of the server build this session is connected to.
+    ///
+    /// See `Session::sync_wsdl_cache`.
+    async fn sync_wsdl_cache(
+        &mut self,
+        cache: &WsdlCache,
+        url: &str,
+        fetched: Option<String>,
+    ) -> Result<()> {
+        let build_id = match self.server_info {
+            Some(ref info) => info.build_id.clone(),
+            None => return Ok(()),
+        };
+
+        let xml = match fetched {
+            Some(xml) => xml,
+            None if cache.contains(url, &build_id) => return Ok(()),
+            None => {
+                debug!("server build changed to {:?}, getting wsdl again", build_id);
+                let xml = http::get_async(&self.client, &crate::wsdl_url(url)).await?.body;
+                self.wsdl = wsdl::parse(&xml)?;
+                xml
+            }
+        };
+
+        if let Err(e) = cache.store(url, &build_id, &xml) {
+            warn!("failed to cache wsdl: {}", e);
+        }
+
+        Ok(())
+    }
+
+    /// Explicitly log out out of confluence.
+    pub async fn logout(&self) -> Result<bool> {
+        let response = self
+            .call(Method::new("logout").with(Element::node("token").with_text(self.token.clone())))
+            .await?;
+
+        crate::logout_result(response)
+    }
+
+    /// Retrieve some basic information about the server being connected to.
+    ///
+    /// The value fetched during `login` is available in `server_info`.
+    pub async fn get_server_info(&self) -> Result<ServerInfo> {
+        let response = self
+            .call(Method::new("getServerInfo").with(Element::node("token").with_text(&self.token)))
+            .await?;
+
+        let element = response.body.descend(&["getServerInfoReturn"])?;
+
+        ServerInfo::from_element(element)
+    }
+
+    /// Server information received during `login`.
+    pub fn server_info(&self) -> Option<&ServerInfo> {
+        self.server_info.as_ref()
+    }
+
+    /// Returns a single Page by id.
+    pub async fn get_page_by_id(&self, page_id: i64) -> Result<Page> {
+        let response = self
+            .call(
+                Method::new("getPage")
+                    .with(Element::node("token").with_text(self.token.clone()))
+                    .with(Element::node("pageId").with_text(page_id.to_string())),
+            )
+            .await?;
+
+        let element = response.body.descend(&["getPageReturn"])?;
+
+        Page::from_element(element)
+    }
+
+    /// Adds or updates a page.
+    ///
+    /// See `Session::store_page` for the fields required in `UpdatePage`.
+    pub async fn store_page(&self, page: UpdatePage) -> Result<Page> {
+        let response = self
+            .call(
+                Method::new("storePage")
+                    .with(Element::node("token").with_text(self.token.clone()))
+                    .with(Element::node("page").with_children(crate::page_elements(page))),
+            )
+            .await?;
+
+        let element = response.body.descend(&["storePageReturn"])?;
+
+        Page::from_element(element)
+    }
+
+    /// Returns all the direct children of this page.
+    pub async fn get_children(&self, page_id: i64) -> Result<Vec<PageSummary>> {
+        let response = self
+            .call(
+                Method::new("getChildren")
+                    .with(Element::node("token").with_text(self.token.clone()))
+                    .with(Element::node("pageId").with_text(page_id.to_string())),
+            )
+            .await?;
+
+        let element = response.body.descend(&["getChildrenReturn"])?;
+
+        let mut summaries = vec![];
+
+        for element in element.children {
+            summaries.push(PageSummary::from_element(element)?);
+        }
+
+        Ok(summaries)
+    }
+
+    /// Call a custom method on this session without blocking.
+    ///
+    /// The method is sent once through the async HTTP client. Unlike `Session::call`,
+    /// failed calls are not retried, an expired token is not renewed by logging in
+    /// again, and a custom `Transport` is never used.
+    pub async fn call(&self, method: rpser::Method) -> Result<rpser::Response> {
+        let (url, envelope) = crate::prepare_call(&self.wsdl, &method)?;
+
+        let http_response =
+            http::soap_action_async(&self.client, url, &method.name, &envelope).await?;
+
+        crate::parse_call_response(http_response)
+    }
+}
@@ -0,0 +1,92 @@
+use chrono::offset::Utc;
+use chrono::DateTime;
+
+/// Attachment.
+#[derive(Debug)]
+pub struct Attachment {
+    /// The id of the attachment
+    pub id: i64,
+    /// The id of the page or blog post this attachment belongs to
+    pub page_id: i64,
+    /// The title of the attachment
+    pub title: String,
+    /// The file name of the attachment
+    pub file_name: String,
+    /// The size of the latest version in bytes
+    pub file_size: i64,
+    /// The mime type of the attachment
+    pub content_type: String,
+    /// Timestamp attachment was created
+    pub created: DateTime<Utc>,
+    /// Username of the creator
+    pub creator: String,
+    /// The url to download this attachment
+    pub url: String,
+    /// Comment of the attachment
+    pub comment: Option<String>,
+}
+
+/// Attachment Object for uploading an Attachment.
+///
+/// Uploading a file with the name of an existing attachment adds a new version of it.
+#[derive(Debug)]
+pub struct UploadAttachment {
+    /// The file name of the attachment
+    pub file_name: String,
+    /// The mime type of the attachment
+    pub content_type: String,
+    /// Comment of the attachment
+    pub comment: Option<String>,
+}
+
+impl UploadAttachment {
+    pub fn new(file_name: &str, content_type: &str) -> UploadAttachment {
+        UploadAttachment {
+            file_name: file_name.into(),
+            content_type: content_type.into(),
+            comment: None,
+        }
+    }
+
+    pub fn with_comment<S: Into<String>>(mut self, comment: S) -> UploadAttachment {
+        self.comment = Some(comment.into());
+        self
+    }
+}
+
+/// Version of an attachment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttachmentVersion {
+    /// The latest version
+    Latest,
+    /// Specific version, starting from 1
+    Number(i32),
+}
+
+impl AttachmentVersion {
+    /// Version number used by the remote API, where `0` is the latest version.
+    pub fn as_number(&self) -> i32 {
+        match *self {
+            AttachmentVersion::Latest => 0,
+            AttachmentVersion::Number(number) => number,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn latest_attachment_version_is_zero() {
+        assert_eq!(AttachmentVersion::Latest.as_number(), 0);
+        assert_eq!(AttachmentVersion::Number(3).as_number(), 3);
+    }
+
+    #[test]
+    fn upload_comment_is_optional() {
+        let upload = UploadAttachment::new("a.png", "image/png");
+        assert_eq!(upload.comment, None);
+        assert_eq!(upload.with_comment("Diagram").comment, Some("Diagram".into()));
+    }
+}
@@ -0,0 +1,128 @@
+use chrono::offset::Utc;
+use chrono::DateTime;
+
+/// Blog post.
+#[derive(Debug)]
+pub struct BlogEntry {
+    /// The id of the blog entry
+    pub id: i64,
+    /// The key of the space that this blog entry belongs to
+    pub space: String,
+    /// The title of the blog entry
+    pub title: String,
+    /// The url to view this blog entry online
+    pub url: String,
+    /// The version number of this blog entry
+    pub version: i32,
+    /// The blog entry content
+    pub content: String,
+    /// Timestamp blog entry was created
+    pub created: DateTime<Utc>,
+    /// Username of the creator
+    pub creator: String,
+    /// Timestamp blog entry was modified
+    pub modified: DateTime<Utc>,
+    /// Username of the blog entry's last modifier
+    pub modifier: String,
+    /// Timestamp blog entry is published at
+    pub publish_date: DateTime<Utc>,
+    /// Status of the blog entry (eg. current or deleted)
+    pub content_status: String,
+    /// Whether the blog entry is current and not deleted
+    pub current: bool,
+}
+
+/// Blog Entry Summary.
+#[derive(Debug)]
+pub struct BlogEntrySummary {
+    /// The id of the blog entry
+    pub id: i64,
+    /// The key of the space that this blog entry belongs to
+    pub space: String,
+    /// The title of the blog entry
+    pub title: String,
+    /// The url to view this blog entry online
+    pub url: String,
+    /// Username of the author
+    pub author: String,
+    /// Timestamp blog entry is published at
+    pub publish_date: DateTime<Utc>,
+}
+
+/// Blog Entry Object for creating or updating a Blog Entry.
+#[derive(Debug)]
+pub struct UpdateBlogEntry {
+    /// The id of the blog entry
+    pub id: Option<i64>,
+    /// The key of the space that this blog entry belongs to
+    pub space: String,
+    /// The title of the blog entry
+    pub title: String,
+    /// The blog entry content
+    pub content: String,
+    /// The version number of this blog entry
+    pub version: Option<i32>,
+    /// Timestamp to publish the blog entry at, now if not set
+    pub publish_date: Option<DateTime<Utc>>,
+}
+
+impl UpdateBlogEntry {
+    pub fn with_create_fields<S: Into<String>>(
+        space: &str,
+        title: &str,
+        content: S,
+    ) -> UpdateBlogEntry {
+        UpdateBlogEntry {
+            id: None,
+            space: space.into(),
+            title: title.into(),
+            content: content.into(),
+            version: None,
+            publish_date: None,
+        }
+    }
+}
+
+impl From<BlogEntry> for UpdateBlogEntry {
+    fn from(other: BlogEntry) -> UpdateBlogEntry {
+        UpdateBlogEntry {
+            id: Some(other.id),
+            space: other.space,
+            title: other.title,
+            content: other.content,
+            version: Some(other.version),
+            publish_date: Some(other.publish_date),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn update_keeps_version_and_publish_date() {
+        let published = Utc.with_ymd_and_hms(2020, 3, 14, 9, 0, 0).unwrap();
+        let entry = BlogEntry {
+            id: 65601,
+            space: "KEY".into(),
+            title: "Release".into(),
+            url: "https://confluence/display/KEY/2020/03/14/Release".into(),
+            version: 2,
+            content: "<p>Out</p>".into(),
+            created: published,
+            creator: "user".into(),
+            modified: published,
+            modifier: "user".into(),
+            publish_date: published,
+            content_status: "current".into(),
+            current: true,
+        };
+
+        let update = UpdateBlogEntry::from(entry);
+        assert_eq!((update.id, update.version), (Some(65601), Some(2)));
+        assert_eq!(update.publish_date, Some(published));
+        assert_eq!(UpdateBlogEntry::with_create_fields("KEY", "Draft", "").version, None);
+    }
+}
@@ -0,0 +1,211 @@
+use crate::http::{self, Certificate, Identity, Proxy, TransportOptions};
+use crate::transport::{HttpTransport, Transport};
+use crate::wsdl::{WsdlCache, WsdlSource};
+use crate::{Result, RetryPolicy, Session};
+use reqwest::header::{HeaderName, HeaderValue};
+use std::time::Duration;
+
+/**
+Builder for `Session` with custom HTTP transport settings.
+
+The HTTP client is built once and reused for fetching WSDL and for every call
+made by the session. Alternatively, a custom `Transport` can be provided.
+
+## Example
+
+```no_run
+use std::time::Duration;
+
+let session = confluence::SessionBuilder::new()
+    .timeout(Duration::from_secs(30))
+    .user_agent("publisher/1.0")
+    .login("https://confluence", "user", "pass")
+    .unwrap();
+```
+*/
+pub struct SessionBuilder {
+    options: TransportOptions,
+    transport: Option<Box<dyn Transport>>,
+    auto_relogin: bool,
+    keep_alive: bool,
+    retry_policy: RetryPolicy,
+    wsdl_source: WsdlSource,
+    #[cfg(feature = "async")]
+    async_client: Option<reqwest::Client>,
+}
+
+impl SessionBuilder {
+    /// Create new builder with default transport settings.
+    pub fn new() -> SessionBuilder {
+        SessionBuilder {
+            options: TransportOptions::default(),
+            transport: None,
+            auto_relogin: true,
+            keep_alive: false,
+            retry_policy: RetryPolicy::default(),
+            wsdl_source: WsdlSource::default(),
+            #[cfg(feature = "async")]
+            async_client: None,
+        }
+    }
+
+    /// Replace all transport settings.
+    pub fn options(mut self, options: TransportOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Set timeout for the whole request, including reading the response.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Set timeout for establishing the connection.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.options.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Route requests through a proxy.
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.options.proxies.push(proxy);
+        self
+    }
+
+    /// Trust additional CA certificate.
+    pub fn add_root_certificate(mut self, certificate: Certificate) -> Self {
+        self.options.root_certificates.push(certificate);
+        self
+    }
+
+    /// Authenticate with a client certificate.
+    pub fn identity(mut self, identity: Identity) -> Self {
+        self.options.identity = Some(identity);
+        self
+    }
+
+    /// Send additional header with every request.
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.options.headers.insert(name, value);
+        self
+    }
+
+    /// Set `User-Agent` header.
+    pub fn user_agent<S: Into<String>>(mut self, user_agent: S) -> Self {
+        self.options.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Use the specified blocking HTTP client.
+    ///
+    /// Transport settings of this builder are ignored by `login` if the client is set.
+    pub fn client(self, client: http::Client) -> Self {
+        self.transport(HttpTransport::new(client))
+    }
+
+    /// Use the specified transport instead of HTTP.
+    ///
+    /// Transport settings of this builder are ignored by `login` if the transport is set.
+    pub fn transport<T: Transport + 'static>(mut self, transport: T) -> Self {
+        self.transport = Some(Box::new(transport));
+        self
+    }
+
+    /// Use the specified non-blocking HTTP client.
+    ///
+    /// Transport settings of this builder are ignored by `login_async` if the client is set.
+    #[cfg(feature = "async")]
+    pub fn async_client(mut self, client: reqwest::Client) -> Self {
+        self.async_client = Some(client);
+        self
+    }
+
+    /// Log in again and retry the call when the token expires (enabled by default).
+    ///
+    /// See `Session::set_auto_relogin`.
+    pub fn auto_relogin(mut self, enabled: bool) -> Self {
+        self.auto_relogin = enabled;
+        self
+    }
+
+    /// Keep the token valid when the session goes out of scope (disabled by default).
+    ///
+    /// See `Session::set_keep_alive`.
+    pub fn keep_alive(mut self, keep_alive: bool) -> Self {
+        self.keep_alive = keep_alive;
+        self
+    }
+
+    /// Set the policy for retrying calls that failed with transient errors.
+    ///
+    /// See `RetryPolicy`.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Set where the WSDL comes from (fetched from the server by default).
+    ///
+    /// Applies to `login`, `from_token` and `login_async`. See `wsdl::WsdlSource`.
+    pub fn wsdl_source(mut self, source: WsdlSource) -> Self {
+        self.wsdl_source = source;
+        self
+    }
+
+    /// Create new confluence session.
+    pub fn login(mut self, url: &str, user: &str, pass: &str) -> Result<Session> {
+        let (session, fetched) = self.connect(url)?;
+        let session = session.login_with_credentials(user, pass)?;
+        self.configure(session, url, fetched)
+    }
+
+    /// Resume confluence session with the token of an earlier session.
+    ///
+    /// See `Session::from_token`.
+    pub fn from_token(mut self, url: &str, token: &str) -> Result<Session> {
+        let (session, fetched) = self.connect(url)?;
+        let session = session.resume_with_token(token)?;
+        self.configure(session, url, fetched)
+    }
+
+    fn connect(&mut self, url: &str) -> Result<(Session, Option<String>)> {
+        let transport = match self.transport.take() {
+            Some(transport) => transport,
+            None => Box::new(HttpTransport::with_options(&self.options)?),
+        };
+
+        Session::connect(transport, url, self.retry_policy.clone(), &self.wsdl_source)
+    }
+
+    fn configure(&self, mut session: Session, url: &str, fetched: Option<String>) -> Result<Session> {
+        if let WsdlSource::Cache(ref dir) = self.wsdl_source {
+            session.sync_wsdl_cache(&WsdlCache::new(dir.clone()), url, fetched)?;
+        }
+        session.set_auto_relogin(self.auto_relogin);
+        session.set_keep_alive(self.keep_alive);
+        Ok(session)
+    }
+
+    /// Create new non-blocking confluence session.
+    #[cfg(feature = "async")]
+    pub async fn login_async(
+        self,
+        url: &str,
+        user: &str,
+        pass: &str,
+    ) -> Result<crate::AsyncSession> {
+        let client = match self.async_client {
+            Some(client) => client,
+            None => self.options.build_async_client()?,
+        };
+
+        crate::AsyncSession::login_with_client(client, url, user, pass, &self.wsdl_source).await
+    }
+}
+
+impl Default for SessionBuilder {
+    fn default() -> SessionBuilder {
+        SessionBuilder::new()
+    }
+}
@@ -0,0 +1,66 @@
+use chrono::offset::Utc;
+use chrono::DateTime;
+
+/// Comment on a page or blog post.
+#[derive(Debug)]
+pub struct Comment {
+    /// The id of the comment
+    pub id: i64,
+    /// The id of the page or blog post this comment belongs to
+    pub page_id: i64,
+    /// The id of the comment this one replies to, `0` for top level comments
+    pub parent_id: i64,
+    /// The title of the comment
+    pub title: String,
+    /// The comment content
+    pub content: String,
+    /// The url to view this comment online
+    pub url: String,
+    /// Timestamp comment was created
+    pub created: DateTime<Utc>,
+    /// Username of the creator
+    pub creator: String,
+    /// Timestamp comment was modified
+    pub modified: DateTime<Utc>,
+    /// Username of the comment's last modifier
+    pub modifier: String,
+}
+
+/// Comment Object for adding a Comment.
+#[derive(Debug)]
+pub struct NewComment {
+    /// The id of the page or blog post to comment on
+    pub page_id: i64,
+    /// The id of the comment to reply to
+    pub parent_id: Option<i64>,
+    /// The comment content
+    pub content: String,
+}
+
+impl NewComment {
+    pub fn new<S: Into<String>>(page_id: i64, content: S) -> NewComment {
+        NewComment {
+            page_id,
+            parent_id: None,
+            content: content.into(),
+        }
+    }
+
+    /// Make this comment a reply to another comment on the same page.
+    pub fn with_parent(mut self, parent_id: i64) -> NewComment {
+        self.parent_id = Some(parent_id);
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn new_comment_is_not_a_reply() {
+        let comment = NewComment::new(65601, "<p>Hi</p>");
+        assert_eq!(comment.parent_id, None);
+        assert_eq!(comment.with_parent(65602).parent_id, Some(65602));
+    }
+}
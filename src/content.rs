@@ -0,0 +1,36 @@
+use chrono::offset::Utc;
+use chrono::DateTime;
+
+/// Summary of content of any type, such as an item in the trash.
+#[derive(Debug)]
+pub struct ContentSummary {
+    /// The id of the content
+    pub id: i64,
+    /// The type of the content, eg. `page`, `blogpost` or `comment`
+    pub content_type: String,
+    /// The key of the space that this content belongs to
+    pub space: String,
+    /// Status of the content, eg. `current` or `deleted`
+    pub status: String,
+    /// The title of the content
+    pub title: String,
+    /// Timestamp content was created
+    pub created: Option<DateTime<Utc>>,
+    /// Username of the creator
+    pub creator: Option<String>,
+    /// Timestamp content was modified
+    pub modified: Option<DateTime<Utc>>,
+    /// Username of the content's last modifier
+    pub modifier: Option<String>,
+}
+
+/// Part of a longer list of content summaries.
+#[derive(Debug)]
+pub struct ContentSummaries {
+    /// Summaries in this part
+    pub content: Vec<ContentSummary>,
+    /// Position of the first summary of this part in the whole list
+    pub offset: i32,
+    /// Length of the whole list
+    pub total_available: i32,
+}
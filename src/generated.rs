@@ -0,0 +1,2815 @@
+//! Typed wrappers for every operation of the confluenceservice-v2 API.
+//!
+//! Generated by `cargo xtask codegen` from `wsdl/confluenceservice-v2.wsdl`, do not edit.
+
+use crate::rpser::xml::BuildElement;
+use crate::rpser::Method;
+use crate::transforms::{
+    add_field, add_optional_field, decode_base64, field, optional_field, FromElement, ToElement,
+};
+use crate::{Result, Session};
+use chrono::{DateTime, Utc};
+use xmltree::Element;
+
+/// `RemoteAbstractPage` structure of the remote API.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteAbstractPage {
+    pub content: Option<String>,
+    pub content_status: Option<String>,
+    pub created: Option<DateTime<Utc>>,
+    pub creator: Option<String>,
+    pub current: bool,
+    pub home_page: bool,
+    pub id: i64,
+    pub modified: Option<DateTime<Utc>>,
+    pub modifier: Option<String>,
+    pub space: Option<String>,
+    pub title: Option<String>,
+    pub url: Option<String>,
+    pub version: i32,
+}
+
+impl FromElement for RemoteAbstractPage {
+    fn from_element(element: Element) -> Result<RemoteAbstractPage> {
+        Ok(RemoteAbstractPage {
+            content: optional_field(&element, "content")?,
+            content_status: optional_field(&element, "contentStatus")?,
+            created: optional_field(&element, "created")?,
+            creator: optional_field(&element, "creator")?,
+            current: field(&element, "current")?,
+            home_page: field(&element, "homePage")?,
+            id: field(&element, "id")?,
+            modified: optional_field(&element, "modified")?,
+            modifier: optional_field(&element, "modifier")?,
+            space: optional_field(&element, "space")?,
+            title: optional_field(&element, "title")?,
+            url: optional_field(&element, "url")?,
+            version: field(&element, "version")?,
+        })
+    }
+}
+
+impl ToElement for RemoteAbstractPage {
+    fn to_element(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        add_optional_field(&mut element, "content", &self.content);
+        add_optional_field(&mut element, "contentStatus", &self.content_status);
+        add_optional_field(&mut element, "created", &self.created);
+        add_optional_field(&mut element, "creator", &self.creator);
+        add_field(&mut element, "current", &self.current);
+        add_field(&mut element, "homePage", &self.home_page);
+        add_field(&mut element, "id", &self.id);
+        add_optional_field(&mut element, "modified", &self.modified);
+        add_optional_field(&mut element, "modifier", &self.modifier);
+        add_optional_field(&mut element, "space", &self.space);
+        add_optional_field(&mut element, "title", &self.title);
+        add_optional_field(&mut element, "url", &self.url);
+        add_field(&mut element, "version", &self.version);
+        element
+    }
+}
+
+/// `RemoteAttachment` structure of the remote API.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteAttachment {
+    pub comment: Option<String>,
+    pub content_type: Option<String>,
+    pub created: Option<DateTime<Utc>>,
+    pub creator: Option<String>,
+    pub file_name: Option<String>,
+    pub file_size: i64,
+    pub id: i64,
+    pub page_id: i64,
+    pub title: Option<String>,
+    pub url: Option<String>,
+}
+
+impl FromElement for RemoteAttachment {
+    fn from_element(element: Element) -> Result<RemoteAttachment> {
+        Ok(RemoteAttachment {
+            comment: optional_field(&element, "comment")?,
+            content_type: optional_field(&element, "contentType")?,
+            created: optional_field(&element, "created")?,
+            creator: optional_field(&element, "creator")?,
+            file_name: optional_field(&element, "fileName")?,
+            file_size: field(&element, "fileSize")?,
+            id: field(&element, "id")?,
+            page_id: field(&element, "pageId")?,
+            title: optional_field(&element, "title")?,
+            url: optional_field(&element, "url")?,
+        })
+    }
+}
+
+impl ToElement for RemoteAttachment {
+    fn to_element(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        add_optional_field(&mut element, "comment", &self.comment);
+        add_optional_field(&mut element, "contentType", &self.content_type);
+        add_optional_field(&mut element, "created", &self.created);
+        add_optional_field(&mut element, "creator", &self.creator);
+        add_optional_field(&mut element, "fileName", &self.file_name);
+        add_field(&mut element, "fileSize", &self.file_size);
+        add_field(&mut element, "id", &self.id);
+        add_field(&mut element, "pageId", &self.page_id);
+        add_optional_field(&mut element, "title", &self.title);
+        add_optional_field(&mut element, "url", &self.url);
+        element
+    }
+}
+
+/// `RemoteBlogEntry` structure of the remote API.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteBlogEntry {
+    pub content: Option<String>,
+    pub content_status: Option<String>,
+    pub created: Option<DateTime<Utc>>,
+    pub creator: Option<String>,
+    pub current: bool,
+    pub home_page: bool,
+    pub id: i64,
+    pub modified: Option<DateTime<Utc>>,
+    pub modifier: Option<String>,
+    pub space: Option<String>,
+    pub title: Option<String>,
+    pub url: Option<String>,
+    pub version: i32,
+    pub permissions: i32,
+    pub publish_date: Option<DateTime<Utc>>,
+}
+
+impl FromElement for RemoteBlogEntry {
+    fn from_element(element: Element) -> Result<RemoteBlogEntry> {
+        Ok(RemoteBlogEntry {
+            content: optional_field(&element, "content")?,
+            content_status: optional_field(&element, "contentStatus")?,
+            created: optional_field(&element, "created")?,
+            creator: optional_field(&element, "creator")?,
+            current: field(&element, "current")?,
+            home_page: field(&element, "homePage")?,
+            id: field(&element, "id")?,
+            modified: optional_field(&element, "modified")?,
+            modifier: optional_field(&element, "modifier")?,
+            space: optional_field(&element, "space")?,
+            title: optional_field(&element, "title")?,
+            url: optional_field(&element, "url")?,
+            version: field(&element, "version")?,
+            permissions: field(&element, "permissions")?,
+            publish_date: optional_field(&element, "publishDate")?,
+        })
+    }
+}
+
+impl ToElement for RemoteBlogEntry {
+    fn to_element(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        add_optional_field(&mut element, "content", &self.content);
+        add_optional_field(&mut element, "contentStatus", &self.content_status);
+        add_optional_field(&mut element, "created", &self.created);
+        add_optional_field(&mut element, "creator", &self.creator);
+        add_field(&mut element, "current", &self.current);
+        add_field(&mut element, "homePage", &self.home_page);
+        add_field(&mut element, "id", &self.id);
+        add_optional_field(&mut element, "modified", &self.modified);
+        add_optional_field(&mut element, "modifier", &self.modifier);
+        add_optional_field(&mut element, "space", &self.space);
+        add_optional_field(&mut element, "title", &self.title);
+        add_optional_field(&mut element, "url", &self.url);
+        add_field(&mut element, "version", &self.version);
+        add_field(&mut element, "permissions", &self.permissions);
+        add_optional_field(&mut element, "publishDate", &self.publish_date);
+        element
+    }
+}
+
+/// `RemoteBlogEntrySummary` structure of the remote API.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteBlogEntrySummary {
+    pub author: Option<String>,
+    pub id: i64,
+    pub permissions: i32,
+    pub publish_date: Option<DateTime<Utc>>,
+    pub space: Option<String>,
+    pub title: Option<String>,
+    pub url: Option<String>,
+}
+
+impl FromElement for RemoteBlogEntrySummary {
+    fn from_element(element: Element) -> Result<RemoteBlogEntrySummary> {
+        Ok(RemoteBlogEntrySummary {
+            author: optional_field(&element, "author")?,
+            id: field(&element, "id")?,
+            permissions: field(&element, "permissions")?,
+            publish_date: optional_field(&element, "publishDate")?,
+            space: optional_field(&element, "space")?,
+            title: optional_field(&element, "title")?,
+            url: optional_field(&element, "url")?,
+        })
+    }
+}
+
+impl ToElement for RemoteBlogEntrySummary {
+    fn to_element(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        add_optional_field(&mut element, "author", &self.author);
+        add_field(&mut element, "id", &self.id);
+        add_field(&mut element, "permissions", &self.permissions);
+        add_optional_field(&mut element, "publishDate", &self.publish_date);
+        add_optional_field(&mut element, "space", &self.space);
+        add_optional_field(&mut element, "title", &self.title);
+        add_optional_field(&mut element, "url", &self.url);
+        element
+    }
+}
+
+/// `RemoteComment` structure of the remote API.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteComment {
+    pub content: Option<String>,
+    pub created: Option<DateTime<Utc>>,
+    pub creator: Option<String>,
+    pub id: i64,
+    pub modified: Option<DateTime<Utc>>,
+    pub modifier: Option<String>,
+    pub page_id: i64,
+    pub parent_id: i64,
+    pub title: Option<String>,
+    pub url: Option<String>,
+}
+
+impl FromElement for RemoteComment {
+    fn from_element(element: Element) -> Result<RemoteComment> {
+        Ok(RemoteComment {
+            content: optional_field(&element, "content")?,
+            created: optional_field(&element, "created")?,
+            creator: optional_field(&element, "creator")?,
+            id: field(&element, "id")?,
+            modified: optional_field(&element, "modified")?,
+            modifier: optional_field(&element, "modifier")?,
+            page_id: field(&element, "pageId")?,
+            parent_id: field(&element, "parentId")?,
+            title: optional_field(&element, "title")?,
+            url: optional_field(&element, "url")?,
+        })
+    }
+}
+
+impl ToElement for RemoteComment {
+    fn to_element(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        add_optional_field(&mut element, "content", &self.content);
+        add_optional_field(&mut element, "created", &self.created);
+        add_optional_field(&mut element, "creator", &self.creator);
+        add_field(&mut element, "id", &self.id);
+        add_optional_field(&mut element, "modified", &self.modified);
+        add_optional_field(&mut element, "modifier", &self.modifier);
+        add_field(&mut element, "pageId", &self.page_id);
+        add_field(&mut element, "parentId", &self.parent_id);
+        add_optional_field(&mut element, "title", &self.title);
+        add_optional_field(&mut element, "url", &self.url);
+        element
+    }
+}
+
+/// `RemoteContentPermission` structure of the remote API.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteContentPermission {
+    pub group_name: Option<String>,
+    pub type_: Option<String>,
+    pub user_name: Option<String>,
+}
+
+impl FromElement for RemoteContentPermission {
+    fn from_element(element: Element) -> Result<RemoteContentPermission> {
+        Ok(RemoteContentPermission {
+            group_name: optional_field(&element, "groupName")?,
+            type_: optional_field(&element, "type")?,
+            user_name: optional_field(&element, "userName")?,
+        })
+    }
+}
+
+impl ToElement for RemoteContentPermission {
+    fn to_element(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        add_optional_field(&mut element, "groupName", &self.group_name);
+        add_optional_field(&mut element, "type", &self.type_);
+        add_optional_field(&mut element, "userName", &self.user_name);
+        element
+    }
+}
+
+/// `RemoteContentPermissionSet` structure of the remote API.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteContentPermissionSet {
+    pub content_permissions: Option<Vec<RemoteContentPermission>>,
+    pub type_: Option<String>,
+}
+
+impl FromElement for RemoteContentPermissionSet {
+    fn from_element(element: Element) -> Result<RemoteContentPermissionSet> {
+        Ok(RemoteContentPermissionSet {
+            content_permissions: optional_field(&element, "contentPermissions")?,
+            type_: optional_field(&element, "type")?,
+        })
+    }
+}
+
+impl ToElement for RemoteContentPermissionSet {
+    fn to_element(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        add_optional_field(&mut element, "contentPermissions", &self.content_permissions);
+        add_optional_field(&mut element, "type", &self.type_);
+        element
+    }
+}
+
+/// `RemoteContentSummaries` structure of the remote API.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteContentSummaries {
+    pub content: Option<Vec<RemoteContentSummary>>,
+    pub offset: i32,
+    pub total_available: i32,
+}
+
+impl FromElement for RemoteContentSummaries {
+    fn from_element(element: Element) -> Result<RemoteContentSummaries> {
+        Ok(RemoteContentSummaries {
+            content: optional_field(&element, "content")?,
+            offset: field(&element, "offset")?,
+            total_available: field(&element, "totalAvailable")?,
+        })
+    }
+}
+
+impl ToElement for RemoteContentSummaries {
+    fn to_element(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        add_optional_field(&mut element, "content", &self.content);
+        add_field(&mut element, "offset", &self.offset);
+        add_field(&mut element, "totalAvailable", &self.total_available);
+        element
+    }
+}
+
+/// `RemoteContentSummary` structure of the remote API.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteContentSummary {
+    pub created: Option<DateTime<Utc>>,
+    pub creator: Option<String>,
+    pub id: i64,
+    pub modified: Option<DateTime<Utc>>,
+    pub modifier: Option<String>,
+    pub space: Option<String>,
+    pub status: Option<String>,
+    pub title: Option<String>,
+    pub type_: Option<String>,
+}
+
+impl FromElement for RemoteContentSummary {
+    fn from_element(element: Element) -> Result<RemoteContentSummary> {
+        Ok(RemoteContentSummary {
+            created: optional_field(&element, "created")?,
+            creator: optional_field(&element, "creator")?,
+            id: field(&element, "id")?,
+            modified: optional_field(&element, "modified")?,
+            modifier: optional_field(&element, "modifier")?,
+            space: optional_field(&element, "space")?,
+            status: optional_field(&element, "status")?,
+            title: optional_field(&element, "title")?,
+            type_: optional_field(&element, "type")?,
+        })
+    }
+}
+
+impl ToElement for RemoteContentSummary {
+    fn to_element(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        add_optional_field(&mut element, "created", &self.created);
+        add_optional_field(&mut element, "creator", &self.creator);
+        add_field(&mut element, "id", &self.id);
+        add_optional_field(&mut element, "modified", &self.modified);
+        add_optional_field(&mut element, "modifier", &self.modifier);
+        add_optional_field(&mut element, "space", &self.space);
+        add_optional_field(&mut element, "status", &self.status);
+        add_optional_field(&mut element, "title", &self.title);
+        add_optional_field(&mut element, "type", &self.type_);
+        element
+    }
+}
+
+/// `RemoteLabel` structure of the remote API.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteLabel {
+    pub id: i64,
+    pub name: Option<String>,
+    pub namespace: Option<String>,
+    pub owner: Option<String>,
+}
+
+impl FromElement for RemoteLabel {
+    fn from_element(element: Element) -> Result<RemoteLabel> {
+        Ok(RemoteLabel {
+            id: field(&element, "id")?,
+            name: optional_field(&element, "name")?,
+            namespace: optional_field(&element, "namespace")?,
+            owner: optional_field(&element, "owner")?,
+        })
+    }
+}
+
+impl ToElement for RemoteLabel {
+    fn to_element(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        add_field(&mut element, "id", &self.id);
+        add_optional_field(&mut element, "name", &self.name);
+        add_optional_field(&mut element, "namespace", &self.namespace);
+        add_optional_field(&mut element, "owner", &self.owner);
+        element
+    }
+}
+
+/// `RemotePage` structure of the remote API.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemotePage {
+    pub content: Option<String>,
+    pub content_status: Option<String>,
+    pub created: Option<DateTime<Utc>>,
+    pub creator: Option<String>,
+    pub current: bool,
+    pub home_page: bool,
+    pub id: i64,
+    pub modified: Option<DateTime<Utc>>,
+    pub modifier: Option<String>,
+    pub space: Option<String>,
+    pub title: Option<String>,
+    pub url: Option<String>,
+    pub version: i32,
+    pub parent_id: i64,
+    pub permissions: i32,
+}
+
+impl FromElement for RemotePage {
+    fn from_element(element: Element) -> Result<RemotePage> {
+        Ok(RemotePage {
+            content: optional_field(&element, "content")?,
+            content_status: optional_field(&element, "contentStatus")?,
+            created: optional_field(&element, "created")?,
+            creator: optional_field(&element, "creator")?,
+            current: field(&element, "current")?,
+            home_page: field(&element, "homePage")?,
+            id: field(&element, "id")?,
+            modified: optional_field(&element, "modified")?,
+            modifier: optional_field(&element, "modifier")?,
+            space: optional_field(&element, "space")?,
+            title: optional_field(&element, "title")?,
+            url: optional_field(&element, "url")?,
+            version: field(&element, "version")?,
+            parent_id: field(&element, "parentId")?,
+            permissions: field(&element, "permissions")?,
+        })
+    }
+}
+
+impl ToElement for RemotePage {
+    fn to_element(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        add_optional_field(&mut element, "content", &self.content);
+        add_optional_field(&mut element, "contentStatus", &self.content_status);
+        add_optional_field(&mut element, "created", &self.created);
+        add_optional_field(&mut element, "creator", &self.creator);
+        add_field(&mut element, "current", &self.current);
+        add_field(&mut element, "homePage", &self.home_page);
+        add_field(&mut element, "id", &self.id);
+        add_optional_field(&mut element, "modified", &self.modified);
+        add_optional_field(&mut element, "modifier", &self.modifier);
+        add_optional_field(&mut element, "space", &self.space);
+        add_optional_field(&mut element, "title", &self.title);
+        add_optional_field(&mut element, "url", &self.url);
+        add_field(&mut element, "version", &self.version);
+        add_field(&mut element, "parentId", &self.parent_id);
+        add_field(&mut element, "permissions", &self.permissions);
+        element
+    }
+}
+
+/// `RemotePageHistory` structure of the remote API.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemotePageHistory {
+    pub id: i64,
+    pub modified: Option<DateTime<Utc>>,
+    pub modifier: Option<String>,
+    pub version: i32,
+}
+
+impl FromElement for RemotePageHistory {
+    fn from_element(element: Element) -> Result<RemotePageHistory> {
+        Ok(RemotePageHistory {
+            id: field(&element, "id")?,
+            modified: optional_field(&element, "modified")?,
+            modifier: optional_field(&element, "modifier")?,
+            version: field(&element, "version")?,
+        })
+    }
+}
+
+impl ToElement for RemotePageHistory {
+    fn to_element(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        add_field(&mut element, "id", &self.id);
+        add_optional_field(&mut element, "modified", &self.modified);
+        add_optional_field(&mut element, "modifier", &self.modifier);
+        add_field(&mut element, "version", &self.version);
+        element
+    }
+}
+
+/// `RemotePageSummary` structure of the remote API.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemotePageSummary {
+    pub id: i64,
+    pub parent_id: i64,
+    pub permissions: i32,
+    pub space: Option<String>,
+    pub title: Option<String>,
+    pub url: Option<String>,
+}
+
+impl FromElement for RemotePageSummary {
+    fn from_element(element: Element) -> Result<RemotePageSummary> {
+        Ok(RemotePageSummary {
+            id: field(&element, "id")?,
+            parent_id: field(&element, "parentId")?,
+            permissions: field(&element, "permissions")?,
+            space: optional_field(&element, "space")?,
+            title: optional_field(&element, "title")?,
+            url: optional_field(&element, "url")?,
+        })
+    }
+}
+
+impl ToElement for RemotePageSummary {
+    fn to_element(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        add_field(&mut element, "id", &self.id);
+        add_field(&mut element, "parentId", &self.parent_id);
+        add_field(&mut element, "permissions", &self.permissions);
+        add_optional_field(&mut element, "space", &self.space);
+        add_optional_field(&mut element, "title", &self.title);
+        add_optional_field(&mut element, "url", &self.url);
+        element
+    }
+}
+
+/// `RemotePageUpdateOptions` structure of the remote API.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemotePageUpdateOptions {
+    pub minor_edit: bool,
+    pub version_comment: Option<String>,
+}
+
+impl FromElement for RemotePageUpdateOptions {
+    fn from_element(element: Element) -> Result<RemotePageUpdateOptions> {
+        Ok(RemotePageUpdateOptions {
+            minor_edit: field(&element, "minorEdit")?,
+            version_comment: optional_field(&element, "versionComment")?,
+        })
+    }
+}
+
+impl ToElement for RemotePageUpdateOptions {
+    fn to_element(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        add_field(&mut element, "minorEdit", &self.minor_edit);
+        add_optional_field(&mut element, "versionComment", &self.version_comment);
+        element
+    }
+}
+
+/// `RemotePermission` structure of the remote API.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemotePermission {
+    pub lock_type: Option<String>,
+    pub locked_by: Option<String>,
+}
+
+impl FromElement for RemotePermission {
+    fn from_element(element: Element) -> Result<RemotePermission> {
+        Ok(RemotePermission {
+            lock_type: optional_field(&element, "lockType")?,
+            locked_by: optional_field(&element, "lockedBy")?,
+        })
+    }
+}
+
+impl ToElement for RemotePermission {
+    fn to_element(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        add_optional_field(&mut element, "lockType", &self.lock_type);
+        add_optional_field(&mut element, "lockedBy", &self.locked_by);
+        element
+    }
+}
+
+/// `RemoteSearchResult` structure of the remote API.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteSearchResult {
+    pub excerpt: Option<String>,
+    pub id: i64,
+    pub title: Option<String>,
+    pub type_: Option<String>,
+    pub url: Option<String>,
+}
+
+impl FromElement for RemoteSearchResult {
+    fn from_element(element: Element) -> Result<RemoteSearchResult> {
+        Ok(RemoteSearchResult {
+            excerpt: optional_field(&element, "excerpt")?,
+            id: field(&element, "id")?,
+            title: optional_field(&element, "title")?,
+            type_: optional_field(&element, "type")?,
+            url: optional_field(&element, "url")?,
+        })
+    }
+}
+
+impl ToElement for RemoteSearchResult {
+    fn to_element(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        add_optional_field(&mut element, "excerpt", &self.excerpt);
+        add_field(&mut element, "id", &self.id);
+        add_optional_field(&mut element, "title", &self.title);
+        add_optional_field(&mut element, "type", &self.type_);
+        add_optional_field(&mut element, "url", &self.url);
+        element
+    }
+}
+
+/// `RemoteServerInfo` structure of the remote API.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteServerInfo {
+    pub base_url: Option<String>,
+    pub build_id: Option<String>,
+    pub development_build: bool,
+    pub major_version: i32,
+    pub minor_version: i32,
+    pub patch_level: i32,
+}
+
+impl FromElement for RemoteServerInfo {
+    fn from_element(element: Element) -> Result<RemoteServerInfo> {
+        Ok(RemoteServerInfo {
+            base_url: optional_field(&element, "baseUrl")?,
+            build_id: optional_field(&element, "buildId")?,
+            development_build: field(&element, "developmentBuild")?,
+            major_version: field(&element, "majorVersion")?,
+            minor_version: field(&element, "minorVersion")?,
+            patch_level: field(&element, "patchLevel")?,
+        })
+    }
+}
+
+impl ToElement for RemoteServerInfo {
+    fn to_element(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        add_optional_field(&mut element, "baseUrl", &self.base_url);
+        add_optional_field(&mut element, "buildId", &self.build_id);
+        add_field(&mut element, "developmentBuild", &self.development_build);
+        add_field(&mut element, "majorVersion", &self.major_version);
+        add_field(&mut element, "minorVersion", &self.minor_version);
+        add_field(&mut element, "patchLevel", &self.patch_level);
+        element
+    }
+}
+
+/// `RemoteSpace` structure of the remote API.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteSpace {
+    pub key: Option<String>,
+    pub name: Option<String>,
+    pub type_: Option<String>,
+    pub url: Option<String>,
+    pub description: Option<String>,
+    pub home_page: i64,
+    pub space_group: Option<String>,
+}
+
+impl FromElement for RemoteSpace {
+    fn from_element(element: Element) -> Result<RemoteSpace> {
+        Ok(RemoteSpace {
+            key: optional_field(&element, "key")?,
+            name: optional_field(&element, "name")?,
+            type_: optional_field(&element, "type")?,
+            url: optional_field(&element, "url")?,
+            description: optional_field(&element, "description")?,
+            home_page: field(&element, "homePage")?,
+            space_group: optional_field(&element, "spaceGroup")?,
+        })
+    }
+}
+
+impl ToElement for RemoteSpace {
+    fn to_element(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        add_optional_field(&mut element, "key", &self.key);
+        add_optional_field(&mut element, "name", &self.name);
+        add_optional_field(&mut element, "type", &self.type_);
+        add_optional_field(&mut element, "url", &self.url);
+        add_optional_field(&mut element, "description", &self.description);
+        add_field(&mut element, "homePage", &self.home_page);
+        add_optional_field(&mut element, "spaceGroup", &self.space_group);
+        element
+    }
+}
+
+/// `RemoteSpaceGroup` structure of the remote API.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteSpaceGroup {
+    pub creator_name: Option<String>,
+    pub key: Option<String>,
+    pub license_key: Option<String>,
+    pub name: Option<String>,
+}
+
+impl FromElement for RemoteSpaceGroup {
+    fn from_element(element: Element) -> Result<RemoteSpaceGroup> {
+        Ok(RemoteSpaceGroup {
+            creator_name: optional_field(&element, "creatorName")?,
+            key: optional_field(&element, "key")?,
+            license_key: optional_field(&element, "licenseKey")?,
+            name: optional_field(&element, "name")?,
+        })
+    }
+}
+
+impl ToElement for RemoteSpaceGroup {
+    fn to_element(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        add_optional_field(&mut element, "creatorName", &self.creator_name);
+        add_optional_field(&mut element, "key", &self.key);
+        add_optional_field(&mut element, "licenseKey", &self.license_key);
+        add_optional_field(&mut element, "name", &self.name);
+        element
+    }
+}
+
+/// `RemoteSpaceSummary` structure of the remote API.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteSpaceSummary {
+    pub key: Option<String>,
+    pub name: Option<String>,
+    pub type_: Option<String>,
+    pub url: Option<String>,
+}
+
+impl FromElement for RemoteSpaceSummary {
+    fn from_element(element: Element) -> Result<RemoteSpaceSummary> {
+        Ok(RemoteSpaceSummary {
+            key: optional_field(&element, "key")?,
+            name: optional_field(&element, "name")?,
+            type_: optional_field(&element, "type")?,
+            url: optional_field(&element, "url")?,
+        })
+    }
+}
+
+impl ToElement for RemoteSpaceSummary {
+    fn to_element(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        add_optional_field(&mut element, "key", &self.key);
+        add_optional_field(&mut element, "name", &self.name);
+        add_optional_field(&mut element, "type", &self.type_);
+        add_optional_field(&mut element, "url", &self.url);
+        element
+    }
+}
+
+/// `RemoteUser` structure of the remote API.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteUser {
+    pub email: Option<String>,
+    pub fullname: Option<String>,
+    pub name: Option<String>,
+    pub url: Option<String>,
+}
+
+impl FromElement for RemoteUser {
+    fn from_element(element: Element) -> Result<RemoteUser> {
+        Ok(RemoteUser {
+            email: optional_field(&element, "email")?,
+            fullname: optional_field(&element, "fullname")?,
+            name: optional_field(&element, "name")?,
+            url: optional_field(&element, "url")?,
+        })
+    }
+}
+
+impl ToElement for RemoteUser {
+    fn to_element(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        add_optional_field(&mut element, "email", &self.email);
+        add_optional_field(&mut element, "fullname", &self.fullname);
+        add_optional_field(&mut element, "name", &self.name);
+        add_optional_field(&mut element, "url", &self.url);
+        element
+    }
+}
+
+/// `RemoteUserInformation` structure of the remote API.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteUserInformation {
+    pub content: Option<String>,
+    pub creation_date: Option<DateTime<Utc>>,
+    pub creator_name: Option<String>,
+    pub id: i64,
+    pub last_modification_date: Option<DateTime<Utc>>,
+    pub last_modifier_name: Option<String>,
+    pub username: Option<String>,
+    pub version: i32,
+}
+
+impl FromElement for RemoteUserInformation {
+    fn from_element(element: Element) -> Result<RemoteUserInformation> {
+        Ok(RemoteUserInformation {
+            content: optional_field(&element, "content")?,
+            creation_date: optional_field(&element, "creationDate")?,
+            creator_name: optional_field(&element, "creatorName")?,
+            id: field(&element, "id")?,
+            last_modification_date: optional_field(&element, "lastModificationDate")?,
+            last_modifier_name: optional_field(&element, "lastModifierName")?,
+            username: optional_field(&element, "username")?,
+            version: field(&element, "version")?,
+        })
+    }
+}
+
+impl ToElement for RemoteUserInformation {
+    fn to_element(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        add_optional_field(&mut element, "content", &self.content);
+        add_optional_field(&mut element, "creationDate", &self.creation_date);
+        add_optional_field(&mut element, "creatorName", &self.creator_name);
+        add_field(&mut element, "id", &self.id);
+        add_optional_field(&mut element, "lastModificationDate", &self.last_modification_date);
+        add_optional_field(&mut element, "lastModifierName", &self.last_modifier_name);
+        add_optional_field(&mut element, "username", &self.username);
+        add_field(&mut element, "version", &self.version);
+        element
+    }
+}
+
+/// Call `login` with `username: String`, `password: String`.
+pub fn login(session: &Session, username: &str, password: &str) -> Result<String> {
+    let response = session.call(
+        Method::new("login")
+            .with(username.to_element("username"))
+            .with(password.to_element("password"))
+    )?;
+
+    let element = response.body.descend(&["loginReturn"])?;
+
+    String::from_element(element)
+}
+
+/// Call `logout`.
+pub fn logout(session: &Session) -> Result<bool> {
+    let response = session.call(
+        Method::new("logout")
+            .with(Element::node("token").with_text(session.token()))
+    )?;
+
+    let element = response.body.descend(&["logoutReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `getServerInfo`.
+pub fn get_server_info(session: &Session) -> Result<RemoteServerInfo> {
+    let response = session.call(
+        Method::new("getServerInfo")
+            .with(Element::node("token").with_text(session.token()))
+    )?;
+
+    let element = response.body.descend(&["getServerInfoReturn"])?;
+
+    RemoteServerInfo::from_element(element)
+}
+
+/// Call `exportSite` with `exportAttachments: bool`.
+pub fn export_site(session: &Session, export_attachments: bool) -> Result<String> {
+    let response = session.call(
+        Method::new("exportSite")
+            .with(Element::node("token").with_text(session.token()))
+            .with(export_attachments.to_element("exportAttachments"))
+    )?;
+
+    let element = response.body.descend(&["exportSiteReturn"])?;
+
+    String::from_element(element)
+}
+
+/// Call `exportSpace` with `spaceKey: String`, `exportType: String`.
+pub fn export_space(session: &Session, space_key: &str, export_type: &str) -> Result<String> {
+    let response = session.call(
+        Method::new("exportSpace")
+            .with(Element::node("token").with_text(session.token()))
+            .with(space_key.to_element("spaceKey"))
+            .with(export_type.to_element("exportType"))
+    )?;
+
+    let element = response.body.descend(&["exportSpaceReturn"])?;
+
+    String::from_element(element)
+}
+
+/// Call `importSpace` with `importData: Vec<u8>`.
+pub fn import_space(session: &Session, import_data: &[u8]) -> Result<bool> {
+    let response = session.call(
+        Method::new("importSpace")
+            .with(Element::node("token").with_text(session.token()))
+            .with(Element::node("importData").with_text(base64::encode(import_data)))
+    )?;
+
+    let element = response.body.descend(&["importSpaceReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `flushIndexQueue`.
+pub fn flush_index_queue(session: &Session) -> Result<bool> {
+    let response = session.call(
+        Method::new("flushIndexQueue")
+            .with(Element::node("token").with_text(session.token()))
+    )?;
+
+    let element = response.body.descend(&["flushIndexQueueReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `clearIndexQueue`.
+pub fn clear_index_queue(session: &Session) -> Result<bool> {
+    let response = session.call(
+        Method::new("clearIndexQueue")
+            .with(Element::node("token").with_text(session.token()))
+    )?;
+
+    let element = response.body.descend(&["clearIndexQueueReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `isPluginEnabled` with `pluginKey: String`.
+pub fn is_plugin_enabled(session: &Session, plugin_key: &str) -> Result<bool> {
+    let response = session.call(
+        Method::new("isPluginEnabled")
+            .with(Element::node("token").with_text(session.token()))
+            .with(plugin_key.to_element("pluginKey"))
+    )?;
+
+    let element = response.body.descend(&["isPluginEnabledReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `isPluginInstalled` with `pluginKey: String`.
+pub fn is_plugin_installed(session: &Session, plugin_key: &str) -> Result<bool> {
+    let response = session.call(
+        Method::new("isPluginInstalled")
+            .with(Element::node("token").with_text(session.token()))
+            .with(plugin_key.to_element("pluginKey"))
+    )?;
+
+    let element = response.body.descend(&["isPluginInstalledReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `installPlugin` with `pluginFileName: String`, `pluginData: Vec<u8>`.
+pub fn install_plugin(
+    session: &Session,
+    plugin_file_name: &str,
+    plugin_data: &[u8],
+) -> Result<bool> {
+    let response = session.call(
+        Method::new("installPlugin")
+            .with(Element::node("token").with_text(session.token()))
+            .with(plugin_file_name.to_element("pluginFileName"))
+            .with(Element::node("pluginData").with_text(base64::encode(plugin_data)))
+    )?;
+
+    let element = response.body.descend(&["installPluginReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `getSpaces`.
+pub fn get_spaces(session: &Session) -> Result<Vec<RemoteSpaceSummary>> {
+    let response = session.call(
+        Method::new("getSpaces")
+            .with(Element::node("token").with_text(session.token()))
+    )?;
+
+    let element = response.body.descend(&["getSpacesReturn"])?;
+
+    Vec::<RemoteSpaceSummary>::from_element(element)
+}
+
+/// Call `getSpace` with `spaceKey: String`.
+pub fn get_space(session: &Session, space_key: &str) -> Result<RemoteSpace> {
+    let response = session.call(
+        Method::new("getSpace")
+            .with(Element::node("token").with_text(session.token()))
+            .with(space_key.to_element("spaceKey"))
+    )?;
+
+    let element = response.body.descend(&["getSpaceReturn"])?;
+
+    RemoteSpace::from_element(element)
+}
+
+/// Call `getSpacesInGroup` with `spaceGroupKey: String`.
+pub fn get_spaces_in_group(
+    session: &Session,
+    space_group_key: &str,
+) -> Result<Vec<RemoteSpaceSummary>> {
+    let response = session.call(
+        Method::new("getSpacesInGroup")
+            .with(Element::node("token").with_text(session.token()))
+            .with(space_group_key.to_element("spaceGroupKey"))
+    )?;
+
+    let element = response.body.descend(&["getSpacesInGroupReturn"])?;
+
+    Vec::<RemoteSpaceSummary>::from_element(element)
+}
+
+/// Call `getSpaceGroups`.
+pub fn get_space_groups(session: &Session) -> Result<Vec<RemoteSpaceGroup>> {
+    let response = session.call(
+        Method::new("getSpaceGroups")
+            .with(Element::node("token").with_text(session.token()))
+    )?;
+
+    let element = response.body.descend(&["getSpaceGroupsReturn"])?;
+
+    Vec::<RemoteSpaceGroup>::from_element(element)
+}
+
+/// Call `getSpaceGroup` with `spaceGroupKey: String`.
+pub fn get_space_group(session: &Session, space_group_key: &str) -> Result<RemoteSpaceGroup> {
+    let response = session.call(
+        Method::new("getSpaceGroup")
+            .with(Element::node("token").with_text(session.token()))
+            .with(space_group_key.to_element("spaceGroupKey"))
+    )?;
+
+    let element = response.body.descend(&["getSpaceGroupReturn"])?;
+
+    RemoteSpaceGroup::from_element(element)
+}
+
+/// Call `getSpaceStatus` with `spaceKey: String`.
+pub fn get_space_status(session: &Session, space_key: &str) -> Result<String> {
+    let response = session.call(
+        Method::new("getSpaceStatus")
+            .with(Element::node("token").with_text(session.token()))
+            .with(space_key.to_element("spaceKey"))
+    )?;
+
+    let element = response.body.descend(&["getSpaceStatusReturn"])?;
+
+    String::from_element(element)
+}
+
+/// Call `setSpaceStatus` with `spaceKey: String`, `statusString: String`.
+pub fn set_space_status(session: &Session, space_key: &str, status_string: &str) -> Result<bool> {
+    let response = session.call(
+        Method::new("setSpaceStatus")
+            .with(Element::node("token").with_text(session.token()))
+            .with(space_key.to_element("spaceKey"))
+            .with(status_string.to_element("statusString"))
+    )?;
+
+    let element = response.body.descend(&["setSpaceStatusReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `addSpace` with `space: RemoteSpace`.
+pub fn add_space(session: &Session, space: &RemoteSpace) -> Result<RemoteSpace> {
+    let response = session.call(
+        Method::new("addSpace")
+            .with(Element::node("token").with_text(session.token()))
+            .with(space.to_element("space"))
+    )?;
+
+    let element = response.body.descend(&["addSpaceReturn"])?;
+
+    RemoteSpace::from_element(element)
+}
+
+/// Call `addPersonalSpace` with `space: RemoteSpace`, `userName: String`.
+pub fn add_personal_space(
+    session: &Session,
+    space: &RemoteSpace,
+    user_name: &str,
+) -> Result<RemoteSpace> {
+    let response = session.call(
+        Method::new("addPersonalSpace")
+            .with(Element::node("token").with_text(session.token()))
+            .with(space.to_element("space"))
+            .with(user_name.to_element("userName"))
+    )?;
+
+    let element = response.body.descend(&["addPersonalSpaceReturn"])?;
+
+    RemoteSpace::from_element(element)
+}
+
+/// Call `convertToPersonalSpace` with `userName: String`, `spaceKey: String`, `newSpaceName: String`, `updateLinks: bool`.
+pub fn convert_to_personal_space(
+    session: &Session,
+    user_name: &str,
+    space_key: &str,
+    new_space_name: &str,
+    update_links: bool,
+) -> Result<bool> {
+    let response = session.call(
+        Method::new("convertToPersonalSpace")
+            .with(Element::node("token").with_text(session.token()))
+            .with(user_name.to_element("userName"))
+            .with(space_key.to_element("spaceKey"))
+            .with(new_space_name.to_element("newSpaceName"))
+            .with(update_links.to_element("updateLinks"))
+    )?;
+
+    let element = response.body.descend(&["convertToPersonalSpaceReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `storeSpace` with `space: RemoteSpace`.
+pub fn store_space(session: &Session, space: &RemoteSpace) -> Result<RemoteSpace> {
+    let response = session.call(
+        Method::new("storeSpace")
+            .with(Element::node("token").with_text(session.token()))
+            .with(space.to_element("space"))
+    )?;
+
+    let element = response.body.descend(&["storeSpaceReturn"])?;
+
+    RemoteSpace::from_element(element)
+}
+
+/// Call `removeSpace` with `spaceKey: String`.
+pub fn remove_space(session: &Session, space_key: &str) -> Result<bool> {
+    let response = session.call(
+        Method::new("removeSpace")
+            .with(Element::node("token").with_text(session.token()))
+            .with(space_key.to_element("spaceKey"))
+    )?;
+
+    let element = response.body.descend(&["removeSpaceReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `getTrashContents` with `spaceKey: String`, `offset: i32`, `count: i32`.
+pub fn get_trash_contents(
+    session: &Session,
+    space_key: &str,
+    offset: i32,
+    count: i32,
+) -> Result<RemoteContentSummaries> {
+    let response = session.call(
+        Method::new("getTrashContents")
+            .with(Element::node("token").with_text(session.token()))
+            .with(space_key.to_element("spaceKey"))
+            .with(offset.to_element("offset"))
+            .with(count.to_element("count"))
+    )?;
+
+    let element = response.body.descend(&["getTrashContentsReturn"])?;
+
+    RemoteContentSummaries::from_element(element)
+}
+
+/// Call `emptyTrash` with `spaceKey: String`.
+pub fn empty_trash(session: &Session, space_key: &str) -> Result<bool> {
+    let response = session.call(
+        Method::new("emptyTrash")
+            .with(Element::node("token").with_text(session.token()))
+            .with(space_key.to_element("spaceKey"))
+    )?;
+
+    let element = response.body.descend(&["emptyTrashReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `purgeFromTrash` with `spaceKey: String`, `pageId: i64`.
+pub fn purge_from_trash(session: &Session, space_key: &str, page_id: i64) -> Result<bool> {
+    let response = session.call(
+        Method::new("purgeFromTrash")
+            .with(Element::node("token").with_text(session.token()))
+            .with(space_key.to_element("spaceKey"))
+            .with(page_id.to_element("pageId"))
+    )?;
+
+    let element = response.body.descend(&["purgeFromTrashReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `getPages` with `spaceKey: String`.
+pub fn get_pages(session: &Session, space_key: &str) -> Result<Vec<RemotePageSummary>> {
+    let response = session.call(
+        Method::new("getPages")
+            .with(Element::node("token").with_text(session.token()))
+            .with(space_key.to_element("spaceKey"))
+    )?;
+
+    let element = response.body.descend(&["getPagesReturn"])?;
+
+    Vec::<RemotePageSummary>::from_element(element)
+}
+
+/// Call `getTopLevelPages` with `spaceKey: String`.
+pub fn get_top_level_pages(session: &Session, space_key: &str) -> Result<Vec<RemotePageSummary>> {
+    let response = session.call(
+        Method::new("getTopLevelPages")
+            .with(Element::node("token").with_text(session.token()))
+            .with(space_key.to_element("spaceKey"))
+    )?;
+
+    let element = response.body.descend(&["getTopLevelPagesReturn"])?;
+
+    Vec::<RemotePageSummary>::from_element(element)
+}
+
+/// Call `getPage` with `pageId: i64`.
+pub fn get_page(session: &Session, page_id: i64) -> Result<RemotePage> {
+    let response = session.call(
+        Method::new("getPage")
+            .with(Element::node("token").with_text(session.token()))
+            .with(page_id.to_element("pageId"))
+    )?;
+
+    let element = response.body.descend(&["getPageReturn"])?;
+
+    RemotePage::from_element(element)
+}
+
+/// Call `getPage` with `spaceKey: String`, `pageTitle: String`.
+pub fn get_page_by_space_key_and_page_title(
+    session: &Session,
+    space_key: &str,
+    page_title: &str,
+) -> Result<RemotePage> {
+    let response = session.call(
+        Method::new("getPage")
+            .with(Element::node("token").with_text(session.token()))
+            .with(space_key.to_element("spaceKey"))
+            .with(page_title.to_element("pageTitle"))
+    )?;
+
+    let element = response.body.descend(&["getPageReturn"])?;
+
+    RemotePage::from_element(element)
+}
+
+/// Call `getPageSummary` with `pageId: i64`.
+pub fn get_page_summary(session: &Session, page_id: i64) -> Result<RemotePageSummary> {
+    let response = session.call(
+        Method::new("getPageSummary")
+            .with(Element::node("token").with_text(session.token()))
+            .with(page_id.to_element("pageId"))
+    )?;
+
+    let element = response.body.descend(&["getPageSummaryReturn"])?;
+
+    RemotePageSummary::from_element(element)
+}
+
+/// Call `getPageSummary` with `spaceKey: String`, `pageTitle: String`.
+pub fn get_page_summary_by_space_key_and_page_title(
+    session: &Session,
+    space_key: &str,
+    page_title: &str,
+) -> Result<RemotePageSummary> {
+    let response = session.call(
+        Method::new("getPageSummary")
+            .with(Element::node("token").with_text(session.token()))
+            .with(space_key.to_element("spaceKey"))
+            .with(page_title.to_element("pageTitle"))
+    )?;
+
+    let element = response.body.descend(&["getPageSummaryReturn"])?;
+
+    RemotePageSummary::from_element(element)
+}
+
+/// Call `getPageHistory` with `pageId: i64`.
+pub fn get_page_history(session: &Session, page_id: i64) -> Result<Vec<RemotePageHistory>> {
+    let response = session.call(
+        Method::new("getPageHistory")
+            .with(Element::node("token").with_text(session.token()))
+            .with(page_id.to_element("pageId"))
+    )?;
+
+    let element = response.body.descend(&["getPageHistoryReturn"])?;
+
+    Vec::<RemotePageHistory>::from_element(element)
+}
+
+/// Call `getAncestors` with `pageId: i64`.
+pub fn get_ancestors(session: &Session, page_id: i64) -> Result<Vec<RemotePageSummary>> {
+    let response = session.call(
+        Method::new("getAncestors")
+            .with(Element::node("token").with_text(session.token()))
+            .with(page_id.to_element("pageId"))
+    )?;
+
+    let element = response.body.descend(&["getAncestorsReturn"])?;
+
+    Vec::<RemotePageSummary>::from_element(element)
+}
+
+/// Call `getChildren` with `pageId: i64`.
+pub fn get_children(session: &Session, page_id: i64) -> Result<Vec<RemotePageSummary>> {
+    let response = session.call(
+        Method::new("getChildren")
+            .with(Element::node("token").with_text(session.token()))
+            .with(page_id.to_element("pageId"))
+    )?;
+
+    let element = response.body.descend(&["getChildrenReturn"])?;
+
+    Vec::<RemotePageSummary>::from_element(element)
+}
+
+/// Call `getDescendents` with `pageId: i64`.
+pub fn get_descendents(session: &Session, page_id: i64) -> Result<Vec<RemotePageSummary>> {
+    let response = session.call(
+        Method::new("getDescendents")
+            .with(Element::node("token").with_text(session.token()))
+            .with(page_id.to_element("pageId"))
+    )?;
+
+    let element = response.body.descend(&["getDescendentsReturn"])?;
+
+    Vec::<RemotePageSummary>::from_element(element)
+}
+
+/// Call `storePage` with `page: RemotePage`.
+pub fn store_page(session: &Session, page: &RemotePage) -> Result<RemotePage> {
+    let response = session.call(
+        Method::new("storePage")
+            .with(Element::node("token").with_text(session.token()))
+            .with(page.to_element("page"))
+    )?;
+
+    let element = response.body.descend(&["storePageReturn"])?;
+
+    RemotePage::from_element(element)
+}
+
+/// Call `updatePage` with `page: RemotePage`, `pageUpdateOptions: RemotePageUpdateOptions`.
+pub fn update_page(
+    session: &Session,
+    page: &RemotePage,
+    page_update_options: &RemotePageUpdateOptions,
+) -> Result<RemotePage> {
+    let response = session.call(
+        Method::new("updatePage")
+            .with(Element::node("token").with_text(session.token()))
+            .with(page.to_element("page"))
+            .with(page_update_options.to_element("pageUpdateOptions"))
+    )?;
+
+    let element = response.body.descend(&["updatePageReturn"])?;
+
+    RemotePage::from_element(element)
+}
+
+/// Call `movePage` with `sourcePageId: i64`, `targetPageId: i64`, `position: String`.
+pub fn move_page(
+    session: &Session,
+    source_page_id: i64,
+    target_page_id: i64,
+    position: &str,
+) -> Result<bool> {
+    let response = session.call(
+        Method::new("movePage")
+            .with(Element::node("token").with_text(session.token()))
+            .with(source_page_id.to_element("sourcePageId"))
+            .with(target_page_id.to_element("targetPageId"))
+            .with(position.to_element("position"))
+    )?;
+
+    let element = response.body.descend(&["movePageReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `movePageToTopLevel` with `pageId: i64`, `targetSpaceKey: String`.
+pub fn move_page_to_top_level(
+    session: &Session,
+    page_id: i64,
+    target_space_key: &str,
+) -> Result<bool> {
+    let response = session.call(
+        Method::new("movePageToTopLevel")
+            .with(Element::node("token").with_text(session.token()))
+            .with(page_id.to_element("pageId"))
+            .with(target_space_key.to_element("targetSpaceKey"))
+    )?;
+
+    let element = response.body.descend(&["movePageToTopLevelReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `removePage` with `pageId: i64`.
+pub fn remove_page(session: &Session, page_id: i64) -> Result<bool> {
+    let response = session.call(
+        Method::new("removePage")
+            .with(Element::node("token").with_text(session.token()))
+            .with(page_id.to_element("pageId"))
+    )?;
+
+    let element = response.body.descend(&["removePageReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `removePageVersionById` with `historicalPageId: i64`.
+pub fn remove_page_version_by_id(session: &Session, historical_page_id: i64) -> Result<bool> {
+    let response = session.call(
+        Method::new("removePageVersionById")
+            .with(Element::node("token").with_text(session.token()))
+            .with(historical_page_id.to_element("historicalPageId"))
+    )?;
+
+    let element = response.body.descend(&["removePageVersionByIdReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `removePageVersionByVersion` with `pageId: i64`, `version: i32`.
+pub fn remove_page_version_by_version(
+    session: &Session,
+    page_id: i64,
+    version: i32,
+) -> Result<bool> {
+    let response = session.call(
+        Method::new("removePageVersionByVersion")
+            .with(Element::node("token").with_text(session.token()))
+            .with(page_id.to_element("pageId"))
+            .with(version.to_element("version"))
+    )?;
+
+    let element = response.body.descend(&["removePageVersionByVersionReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `renderContent` with `spaceKey: String`, `pageId: i64`, `newContent: String`.
+pub fn render_content(
+    session: &Session,
+    space_key: &str,
+    page_id: i64,
+    new_content: &str,
+) -> Result<String> {
+    let response = session.call(
+        Method::new("renderContent")
+            .with(Element::node("token").with_text(session.token()))
+            .with(space_key.to_element("spaceKey"))
+            .with(page_id.to_element("pageId"))
+            .with(new_content.to_element("newContent"))
+    )?;
+
+    let element = response.body.descend(&["renderContentReturn"])?;
+
+    String::from_element(element)
+}
+
+/// Call `renderContent` with `spaceKey: String`, `pageId: i64`, `newContent: String`, `parameters: Element`.
+pub fn render_content_with_parameters(
+    session: &Session,
+    space_key: &str,
+    page_id: i64,
+    new_content: &str,
+    parameters: &Element,
+) -> Result<String> {
+    let response = session.call(
+        Method::new("renderContent")
+            .with(Element::node("token").with_text(session.token()))
+            .with(space_key.to_element("spaceKey"))
+            .with(page_id.to_element("pageId"))
+            .with(new_content.to_element("newContent"))
+            .with(parameters.to_element("parameters"))
+    )?;
+
+    let element = response.body.descend(&["renderContentReturn"])?;
+
+    String::from_element(element)
+}
+
+/// Call `convertWikiToStorageFormat` with `wiki: String`.
+pub fn convert_wiki_to_storage_format(session: &Session, wiki: &str) -> Result<String> {
+    let response = session.call(
+        Method::new("convertWikiToStorageFormat")
+            .with(Element::node("token").with_text(session.token()))
+            .with(wiki.to_element("wiki"))
+    )?;
+
+    let element = response.body.descend(&["convertWikiToStorageFormatReturn"])?;
+
+    String::from_element(element)
+}
+
+/// Call `getAttachments` with `pageId: i64`.
+pub fn get_attachments(session: &Session, page_id: i64) -> Result<Vec<RemoteAttachment>> {
+    let response = session.call(
+        Method::new("getAttachments")
+            .with(Element::node("token").with_text(session.token()))
+            .with(page_id.to_element("pageId"))
+    )?;
+
+    let element = response.body.descend(&["getAttachmentsReturn"])?;
+
+    Vec::<RemoteAttachment>::from_element(element)
+}
+
+/// Call `getAttachment` with `pageId: i64`, `fileName: String`, `version: i32`.
+pub fn get_attachment(
+    session: &Session,
+    page_id: i64,
+    file_name: &str,
+    version: i32,
+) -> Result<RemoteAttachment> {
+    let response = session.call(
+        Method::new("getAttachment")
+            .with(Element::node("token").with_text(session.token()))
+            .with(page_id.to_element("pageId"))
+            .with(file_name.to_element("fileName"))
+            .with(version.to_element("version"))
+    )?;
+
+    let element = response.body.descend(&["getAttachmentReturn"])?;
+
+    RemoteAttachment::from_element(element)
+}
+
+/// Call `getAttachmentData` with `pageId: i64`, `fileName: String`, `version: i32`.
+pub fn get_attachment_data(
+    session: &Session,
+    page_id: i64,
+    file_name: &str,
+    version: i32,
+) -> Result<Vec<u8>> {
+    let response = session.call(
+        Method::new("getAttachmentData")
+            .with(Element::node("token").with_text(session.token()))
+            .with(page_id.to_element("pageId"))
+            .with(file_name.to_element("fileName"))
+            .with(version.to_element("version"))
+    )?;
+
+    let element = response.body.descend(&["getAttachmentDataReturn"])?;
+
+    decode_base64(&element)
+}
+
+/// Call `addAttachment` with `contentId: i64`, `attachment: RemoteAttachment`, `attachmentData: Vec<u8>`.
+pub fn add_attachment_with_content_id(
+    session: &Session,
+    content_id: i64,
+    attachment: &RemoteAttachment,
+    attachment_data: &[u8],
+) -> Result<RemoteAttachment> {
+    let response = session.call(
+        Method::new("addAttachment")
+            .with(Element::node("token").with_text(session.token()))
+            .with(content_id.to_element("contentId"))
+            .with(attachment.to_element("attachment"))
+            .with(Element::node("attachmentData").with_text(base64::encode(attachment_data)))
+    )?;
+
+    let element = response.body.descend(&["addAttachmentReturn"])?;
+
+    RemoteAttachment::from_element(element)
+}
+
+/// Call `addAttachment` with `attachment: RemoteAttachment`, `attachmentData: Vec<u8>`.
+pub fn add_attachment(
+    session: &Session,
+    attachment: &RemoteAttachment,
+    attachment_data: &[u8],
+) -> Result<RemoteAttachment> {
+    let response = session.call(
+        Method::new("addAttachment")
+            .with(Element::node("token").with_text(session.token()))
+            .with(attachment.to_element("attachment"))
+            .with(Element::node("attachmentData").with_text(base64::encode(attachment_data)))
+    )?;
+
+    let element = response.body.descend(&["addAttachmentReturn"])?;
+
+    RemoteAttachment::from_element(element)
+}
+
+/// Call `removeAttachment` with `contentId: i64`, `fileName: String`.
+pub fn remove_attachment(session: &Session, content_id: i64, file_name: &str) -> Result<bool> {
+    let response = session.call(
+        Method::new("removeAttachment")
+            .with(Element::node("token").with_text(session.token()))
+            .with(content_id.to_element("contentId"))
+            .with(file_name.to_element("fileName"))
+    )?;
+
+    let element = response.body.descend(&["removeAttachmentReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `moveAttachment` with `originalContentId: i64`, `originalName: String`, `newContentEntityId: i64`, `newName: String`.
+pub fn move_attachment(
+    session: &Session,
+    original_content_id: i64,
+    original_name: &str,
+    new_content_entity_id: i64,
+    new_name: &str,
+) -> Result<bool> {
+    let response = session.call(
+        Method::new("moveAttachment")
+            .with(Element::node("token").with_text(session.token()))
+            .with(original_content_id.to_element("originalContentId"))
+            .with(original_name.to_element("originalName"))
+            .with(new_content_entity_id.to_element("newContentEntityId"))
+            .with(new_name.to_element("newName"))
+    )?;
+
+    let element = response.body.descend(&["moveAttachmentReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `getComments` with `pageId: i64`.
+pub fn get_comments(session: &Session, page_id: i64) -> Result<Vec<RemoteComment>> {
+    let response = session.call(
+        Method::new("getComments")
+            .with(Element::node("token").with_text(session.token()))
+            .with(page_id.to_element("pageId"))
+    )?;
+
+    let element = response.body.descend(&["getCommentsReturn"])?;
+
+    Vec::<RemoteComment>::from_element(element)
+}
+
+/// Call `getComment` with `commentId: i64`.
+pub fn get_comment(session: &Session, comment_id: i64) -> Result<RemoteComment> {
+    let response = session.call(
+        Method::new("getComment")
+            .with(Element::node("token").with_text(session.token()))
+            .with(comment_id.to_element("commentId"))
+    )?;
+
+    let element = response.body.descend(&["getCommentReturn"])?;
+
+    RemoteComment::from_element(element)
+}
+
+/// Call `addComment` with `comment: RemoteComment`.
+pub fn add_comment(session: &Session, comment: &RemoteComment) -> Result<RemoteComment> {
+    let response = session.call(
+        Method::new("addComment")
+            .with(Element::node("token").with_text(session.token()))
+            .with(comment.to_element("comment"))
+    )?;
+
+    let element = response.body.descend(&["addCommentReturn"])?;
+
+    RemoteComment::from_element(element)
+}
+
+/// Call `editComment` with `comment: RemoteComment`.
+pub fn edit_comment(session: &Session, comment: &RemoteComment) -> Result<RemoteComment> {
+    let response = session.call(
+        Method::new("editComment")
+            .with(Element::node("token").with_text(session.token()))
+            .with(comment.to_element("comment"))
+    )?;
+
+    let element = response.body.descend(&["editCommentReturn"])?;
+
+    RemoteComment::from_element(element)
+}
+
+/// Call `removeComment` with `commentId: i64`.
+pub fn remove_comment(session: &Session, comment_id: i64) -> Result<bool> {
+    let response = session.call(
+        Method::new("removeComment")
+            .with(Element::node("token").with_text(session.token()))
+            .with(comment_id.to_element("commentId"))
+    )?;
+
+    let element = response.body.descend(&["removeCommentReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `getBlogEntries` with `spaceKey: String`.
+pub fn get_blog_entries(session: &Session, space_key: &str) -> Result<Vec<RemoteBlogEntrySummary>> {
+    let response = session.call(
+        Method::new("getBlogEntries")
+            .with(Element::node("token").with_text(session.token()))
+            .with(space_key.to_element("spaceKey"))
+    )?;
+
+    let element = response.body.descend(&["getBlogEntriesReturn"])?;
+
+    Vec::<RemoteBlogEntrySummary>::from_element(element)
+}
+
+/// Call `getBlogEntry` with `pageId: i64`.
+pub fn get_blog_entry(session: &Session, page_id: i64) -> Result<RemoteBlogEntry> {
+    let response = session.call(
+        Method::new("getBlogEntry")
+            .with(Element::node("token").with_text(session.token()))
+            .with(page_id.to_element("pageId"))
+    )?;
+
+    let element = response.body.descend(&["getBlogEntryReturn"])?;
+
+    RemoteBlogEntry::from_element(element)
+}
+
+/// Call `getBlogEntryByDayAndTitle` with `spaceKey: String`, `dayOfMonth: i32`, `postTitle: String`.
+pub fn get_blog_entry_by_day_and_title(
+    session: &Session,
+    space_key: &str,
+    day_of_month: i32,
+    post_title: &str,
+) -> Result<RemoteBlogEntry> {
+    let response = session.call(
+        Method::new("getBlogEntryByDayAndTitle")
+            .with(Element::node("token").with_text(session.token()))
+            .with(space_key.to_element("spaceKey"))
+            .with(day_of_month.to_element("dayOfMonth"))
+            .with(post_title.to_element("postTitle"))
+    )?;
+
+    let element = response.body.descend(&["getBlogEntryByDayAndTitleReturn"])?;
+
+    RemoteBlogEntry::from_element(element)
+}
+
+/// Call `getBlogEntryByDateAndTitle` with `spaceKey: String`, `year: i32`, `month: i32`, `dayOfMonth: i32`, `postTitle: String`.
+pub fn get_blog_entry_by_date_and_title(
+    session: &Session,
+    space_key: &str,
+    year: i32,
+    month: i32,
+    day_of_month: i32,
+    post_title: &str,
+) -> Result<RemoteBlogEntry> {
+    let response = session.call(
+        Method::new("getBlogEntryByDateAndTitle")
+            .with(Element::node("token").with_text(session.token()))
+            .with(space_key.to_element("spaceKey"))
+            .with(year.to_element("year"))
+            .with(month.to_element("month"))
+            .with(day_of_month.to_element("dayOfMonth"))
+            .with(post_title.to_element("postTitle"))
+    )?;
+
+    let element = response.body.descend(&["getBlogEntryByDateAndTitleReturn"])?;
+
+    RemoteBlogEntry::from_element(element)
+}
+
+/// Call `storeBlogEntry` with `entry: RemoteBlogEntry`.
+pub fn store_blog_entry(session: &Session, entry: &RemoteBlogEntry) -> Result<RemoteBlogEntry> {
+    let response = session.call(
+        Method::new("storeBlogEntry")
+            .with(Element::node("token").with_text(session.token()))
+            .with(entry.to_element("entry"))
+    )?;
+
+    let element = response.body.descend(&["storeBlogEntryReturn"])?;
+
+    RemoteBlogEntry::from_element(element)
+}
+
+/// Call `search` with `query: String`, `maxResults: i32`.
+pub fn search(session: &Session, query: &str, max_results: i32) -> Result<Vec<RemoteSearchResult>> {
+    let response = session.call(
+        Method::new("search")
+            .with(Element::node("token").with_text(session.token()))
+            .with(query.to_element("query"))
+            .with(max_results.to_element("maxResults"))
+    )?;
+
+    let element = response.body.descend(&["searchReturn"])?;
+
+    Vec::<RemoteSearchResult>::from_element(element)
+}
+
+/// Call `search` with `query: String`, `parameters: Element`, `maxResults: i32`.
+pub fn search_with_parameters(
+    session: &Session,
+    query: &str,
+    parameters: &Element,
+    max_results: i32,
+) -> Result<Vec<RemoteSearchResult>> {
+    let response = session.call(
+        Method::new("search")
+            .with(Element::node("token").with_text(session.token()))
+            .with(query.to_element("query"))
+            .with(parameters.to_element("parameters"))
+            .with(max_results.to_element("maxResults"))
+    )?;
+
+    let element = response.body.descend(&["searchReturn"])?;
+
+    Vec::<RemoteSearchResult>::from_element(element)
+}
+
+/// Call `getPermissions` with `spaceKey: String`.
+pub fn get_permissions(session: &Session, space_key: &str) -> Result<Vec<String>> {
+    let response = session.call(
+        Method::new("getPermissions")
+            .with(Element::node("token").with_text(session.token()))
+            .with(space_key.to_element("spaceKey"))
+    )?;
+
+    let element = response.body.descend(&["getPermissionsReturn"])?;
+
+    Vec::<String>::from_element(element)
+}
+
+/// Call `getPermissionsForUser` with `spaceKey: String`, `userName: String`.
+pub fn get_permissions_for_user(
+    session: &Session,
+    space_key: &str,
+    user_name: &str,
+) -> Result<Vec<String>> {
+    let response = session.call(
+        Method::new("getPermissionsForUser")
+            .with(Element::node("token").with_text(session.token()))
+            .with(space_key.to_element("spaceKey"))
+            .with(user_name.to_element("userName"))
+    )?;
+
+    let element = response.body.descend(&["getPermissionsForUserReturn"])?;
+
+    Vec::<String>::from_element(element)
+}
+
+/// Call `getPagePermissions` with `pageId: i64`.
+pub fn get_page_permissions(session: &Session, page_id: i64) -> Result<Vec<RemotePermission>> {
+    let response = session.call(
+        Method::new("getPagePermissions")
+            .with(Element::node("token").with_text(session.token()))
+            .with(page_id.to_element("pageId"))
+    )?;
+
+    let element = response.body.descend(&["getPagePermissionsReturn"])?;
+
+    Vec::<RemotePermission>::from_element(element)
+}
+
+/// Call `getContentPermissionSets` with `contentId: i64`.
+pub fn get_content_permission_sets(
+    session: &Session,
+    content_id: i64,
+) -> Result<Vec<RemoteContentPermissionSet>> {
+    let response = session.call(
+        Method::new("getContentPermissionSets")
+            .with(Element::node("token").with_text(session.token()))
+            .with(content_id.to_element("contentId"))
+    )?;
+
+    let element = response.body.descend(&["getContentPermissionSetsReturn"])?;
+
+    Vec::<RemoteContentPermissionSet>::from_element(element)
+}
+
+/// Call `getContentPermissionSet` with `contentId: i64`, `permissionType: String`.
+pub fn get_content_permission_set(
+    session: &Session,
+    content_id: i64,
+    permission_type: &str,
+) -> Result<RemoteContentPermissionSet> {
+    let response = session.call(
+        Method::new("getContentPermissionSet")
+            .with(Element::node("token").with_text(session.token()))
+            .with(content_id.to_element("contentId"))
+            .with(permission_type.to_element("permissionType"))
+    )?;
+
+    let element = response.body.descend(&["getContentPermissionSetReturn"])?;
+
+    RemoteContentPermissionSet::from_element(element)
+}
+
+/// Call `setContentPermissions` with `contentId: i64`, `permissionType: String`, `permissions: Vec<RemoteContentPermission>`.
+pub fn set_content_permissions(
+    session: &Session,
+    content_id: i64,
+    permission_type: &str,
+    permissions: &[RemoteContentPermission],
+) -> Result<bool> {
+    let response = session.call(
+        Method::new("setContentPermissions")
+            .with(Element::node("token").with_text(session.token()))
+            .with(content_id.to_element("contentId"))
+            .with(permission_type.to_element("permissionType"))
+            .with(permissions.to_element("permissions"))
+    )?;
+
+    let element = response.body.descend(&["setContentPermissionsReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `getSpaceLevelPermissions`.
+pub fn get_space_level_permissions(session: &Session) -> Result<Vec<String>> {
+    let response = session.call(
+        Method::new("getSpaceLevelPermissions")
+            .with(Element::node("token").with_text(session.token()))
+    )?;
+
+    let element = response.body.descend(&["getSpaceLevelPermissionsReturn"])?;
+
+    Vec::<String>::from_element(element)
+}
+
+/// Call `addPermissionToSpace` with `permission: String`, `remoteEntityName: String`, `spaceKey: String`.
+pub fn add_permission_to_space(
+    session: &Session,
+    permission: &str,
+    remote_entity_name: &str,
+    space_key: &str,
+) -> Result<bool> {
+    let response = session.call(
+        Method::new("addPermissionToSpace")
+            .with(Element::node("token").with_text(session.token()))
+            .with(permission.to_element("permission"))
+            .with(remote_entity_name.to_element("remoteEntityName"))
+            .with(space_key.to_element("spaceKey"))
+    )?;
+
+    let element = response.body.descend(&["addPermissionToSpaceReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `addPermissionsToSpace` with `permissions: Vec<String>`, `remoteEntityName: String`, `spaceKey: String`.
+pub fn add_permissions_to_space(
+    session: &Session,
+    permissions: &[String],
+    remote_entity_name: &str,
+    space_key: &str,
+) -> Result<bool> {
+    let response = session.call(
+        Method::new("addPermissionsToSpace")
+            .with(Element::node("token").with_text(session.token()))
+            .with(permissions.to_element("permissions"))
+            .with(remote_entity_name.to_element("remoteEntityName"))
+            .with(space_key.to_element("spaceKey"))
+    )?;
+
+    let element = response.body.descend(&["addPermissionsToSpaceReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `removePermissionFromSpace` with `permission: String`, `remoteEntityName: String`, `spaceKey: String`.
+pub fn remove_permission_from_space(
+    session: &Session,
+    permission: &str,
+    remote_entity_name: &str,
+    space_key: &str,
+) -> Result<bool> {
+    let response = session.call(
+        Method::new("removePermissionFromSpace")
+            .with(Element::node("token").with_text(session.token()))
+            .with(permission.to_element("permission"))
+            .with(remote_entity_name.to_element("remoteEntityName"))
+            .with(space_key.to_element("spaceKey"))
+    )?;
+
+    let element = response.body.descend(&["removePermissionFromSpaceReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `addAnonymousPermissionToSpace` with `permission: String`, `spaceKey: String`.
+pub fn add_anonymous_permission_to_space(
+    session: &Session,
+    permission: &str,
+    space_key: &str,
+) -> Result<bool> {
+    let response = session.call(
+        Method::new("addAnonymousPermissionToSpace")
+            .with(Element::node("token").with_text(session.token()))
+            .with(permission.to_element("permission"))
+            .with(space_key.to_element("spaceKey"))
+    )?;
+
+    let element = response.body.descend(&["addAnonymousPermissionToSpaceReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `addAnonymousPermissionsToSpace` with `permissions: Vec<String>`, `spaceKey: String`.
+pub fn add_anonymous_permissions_to_space(
+    session: &Session,
+    permissions: &[String],
+    space_key: &str,
+) -> Result<bool> {
+    let response = session.call(
+        Method::new("addAnonymousPermissionsToSpace")
+            .with(Element::node("token").with_text(session.token()))
+            .with(permissions.to_element("permissions"))
+            .with(space_key.to_element("spaceKey"))
+    )?;
+
+    let element = response.body.descend(&["addAnonymousPermissionsToSpaceReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `removeAnonymousPermissionFromSpace` with `permission: String`, `spaceKey: String`.
+pub fn remove_anonymous_permission_from_space(
+    session: &Session,
+    permission: &str,
+    space_key: &str,
+) -> Result<bool> {
+    let response = session.call(
+        Method::new("removeAnonymousPermissionFromSpace")
+            .with(Element::node("token").with_text(session.token()))
+            .with(permission.to_element("permission"))
+            .with(space_key.to_element("spaceKey"))
+    )?;
+
+    let element = response.body.descend(&["removeAnonymousPermissionFromSpaceReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `getUser` with `username: String`.
+pub fn get_user(session: &Session, username: &str) -> Result<RemoteUser> {
+    let response = session.call(
+        Method::new("getUser")
+            .with(Element::node("token").with_text(session.token()))
+            .with(username.to_element("username"))
+    )?;
+
+    let element = response.body.descend(&["getUserReturn"])?;
+
+    RemoteUser::from_element(element)
+}
+
+/// Call `addUser` with `user: RemoteUser`, `password: String`.
+pub fn add_user(session: &Session, user: &RemoteUser, password: &str) -> Result<()> {
+    session.call(
+        Method::new("addUser")
+            .with(Element::node("token").with_text(session.token()))
+            .with(user.to_element("user"))
+            .with(password.to_element("password"))
+    )?;
+
+    Ok(())
+}
+
+/// Call `editUser` with `user: RemoteUser`.
+pub fn edit_user(session: &Session, user: &RemoteUser) -> Result<bool> {
+    let response = session.call(
+        Method::new("editUser")
+            .with(Element::node("token").with_text(session.token()))
+            .with(user.to_element("user"))
+    )?;
+
+    let element = response.body.descend(&["editUserReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `removeUser` with `username: String`.
+pub fn remove_user(session: &Session, username: &str) -> Result<bool> {
+    let response = session.call(
+        Method::new("removeUser")
+            .with(Element::node("token").with_text(session.token()))
+            .with(username.to_element("username"))
+    )?;
+
+    let element = response.body.descend(&["removeUserReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `deactivateUser` with `username: String`.
+pub fn deactivate_user(session: &Session, username: &str) -> Result<bool> {
+    let response = session.call(
+        Method::new("deactivateUser")
+            .with(Element::node("token").with_text(session.token()))
+            .with(username.to_element("username"))
+    )?;
+
+    let element = response.body.descend(&["deactivateUserReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `reactivateUser` with `username: String`.
+pub fn reactivate_user(session: &Session, username: &str) -> Result<bool> {
+    let response = session.call(
+        Method::new("reactivateUser")
+            .with(Element::node("token").with_text(session.token()))
+            .with(username.to_element("username"))
+    )?;
+
+    let element = response.body.descend(&["reactivateUserReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `isActiveUser` with `username: String`.
+pub fn is_active_user(session: &Session, username: &str) -> Result<bool> {
+    let response = session.call(
+        Method::new("isActiveUser")
+            .with(Element::node("token").with_text(session.token()))
+            .with(username.to_element("username"))
+    )?;
+
+    let element = response.body.descend(&["isActiveUserReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `hasUser` with `username: String`.
+pub fn has_user(session: &Session, username: &str) -> Result<bool> {
+    let response = session.call(
+        Method::new("hasUser")
+            .with(Element::node("token").with_text(session.token()))
+            .with(username.to_element("username"))
+    )?;
+
+    let element = response.body.descend(&["hasUserReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `renameUser` with `oldUsername: String`, `newUsername: String`.
+pub fn rename_user(session: &Session, old_username: &str, new_username: &str) -> Result<bool> {
+    let response = session.call(
+        Method::new("renameUser")
+            .with(Element::node("token").with_text(session.token()))
+            .with(old_username.to_element("oldUsername"))
+            .with(new_username.to_element("newUsername"))
+    )?;
+
+    let element = response.body.descend(&["renameUserReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `getActiveUsers` with `viewAll: bool`.
+pub fn get_active_users(session: &Session, view_all: bool) -> Result<Vec<String>> {
+    let response = session.call(
+        Method::new("getActiveUsers")
+            .with(Element::node("token").with_text(session.token()))
+            .with(view_all.to_element("viewAll"))
+    )?;
+
+    let element = response.body.descend(&["getActiveUsersReturn"])?;
+
+    Vec::<String>::from_element(element)
+}
+
+/// Call `getUserInformation` with `username: String`.
+pub fn get_user_information(session: &Session, username: &str) -> Result<RemoteUserInformation> {
+    let response = session.call(
+        Method::new("getUserInformation")
+            .with(Element::node("token").with_text(session.token()))
+            .with(username.to_element("username"))
+    )?;
+
+    let element = response.body.descend(&["getUserInformationReturn"])?;
+
+    RemoteUserInformation::from_element(element)
+}
+
+/// Call `setUserInformation` with `userInfo: RemoteUserInformation`.
+pub fn set_user_information(session: &Session, user_info: &RemoteUserInformation) -> Result<bool> {
+    let response = session.call(
+        Method::new("setUserInformation")
+            .with(Element::node("token").with_text(session.token()))
+            .with(user_info.to_element("userInfo"))
+    )?;
+
+    let element = response.body.descend(&["setUserInformationReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `changeMyPassword` with `oldPass: String`, `newPass: String`.
+pub fn change_my_password(session: &Session, old_pass: &str, new_pass: &str) -> Result<bool> {
+    let response = session.call(
+        Method::new("changeMyPassword")
+            .with(Element::node("token").with_text(session.token()))
+            .with(old_pass.to_element("oldPass"))
+            .with(new_pass.to_element("newPass"))
+    )?;
+
+    let element = response.body.descend(&["changeMyPasswordReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `changeUserPassword` with `username: String`, `newPass: String`.
+pub fn change_user_password(session: &Session, username: &str, new_pass: &str) -> Result<bool> {
+    let response = session.call(
+        Method::new("changeUserPassword")
+            .with(Element::node("token").with_text(session.token()))
+            .with(username.to_element("username"))
+            .with(new_pass.to_element("newPass"))
+    )?;
+
+    let element = response.body.descend(&["changeUserPasswordReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `getGroups`.
+pub fn get_groups(session: &Session) -> Result<Vec<String>> {
+    let response = session.call(
+        Method::new("getGroups")
+            .with(Element::node("token").with_text(session.token()))
+    )?;
+
+    let element = response.body.descend(&["getGroupsReturn"])?;
+
+    Vec::<String>::from_element(element)
+}
+
+/// Call `hasGroup` with `groupname: String`.
+pub fn has_group(session: &Session, groupname: &str) -> Result<bool> {
+    let response = session.call(
+        Method::new("hasGroup")
+            .with(Element::node("token").with_text(session.token()))
+            .with(groupname.to_element("groupname"))
+    )?;
+
+    let element = response.body.descend(&["hasGroupReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `addGroup` with `groupname: String`.
+pub fn add_group(session: &Session, groupname: &str) -> Result<bool> {
+    let response = session.call(
+        Method::new("addGroup")
+            .with(Element::node("token").with_text(session.token()))
+            .with(groupname.to_element("groupname"))
+    )?;
+
+    let element = response.body.descend(&["addGroupReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `removeGroup` with `groupname: String`, `defaultGroupName: String`.
+pub fn remove_group(session: &Session, groupname: &str, default_group_name: &str) -> Result<bool> {
+    let response = session.call(
+        Method::new("removeGroup")
+            .with(Element::node("token").with_text(session.token()))
+            .with(groupname.to_element("groupname"))
+            .with(default_group_name.to_element("defaultGroupName"))
+    )?;
+
+    let element = response.body.descend(&["removeGroupReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `getUserGroups` with `username: String`.
+pub fn get_user_groups(session: &Session, username: &str) -> Result<Vec<String>> {
+    let response = session.call(
+        Method::new("getUserGroups")
+            .with(Element::node("token").with_text(session.token()))
+            .with(username.to_element("username"))
+    )?;
+
+    let element = response.body.descend(&["getUserGroupsReturn"])?;
+
+    Vec::<String>::from_element(element)
+}
+
+/// Call `addUserToGroup` with `username: String`, `groupname: String`.
+pub fn add_user_to_group(session: &Session, username: &str, groupname: &str) -> Result<bool> {
+    let response = session.call(
+        Method::new("addUserToGroup")
+            .with(Element::node("token").with_text(session.token()))
+            .with(username.to_element("username"))
+            .with(groupname.to_element("groupname"))
+    )?;
+
+    let element = response.body.descend(&["addUserToGroupReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `removeUserFromGroup` with `username: String`, `groupname: String`.
+pub fn remove_user_from_group(session: &Session, username: &str, groupname: &str) -> Result<bool> {
+    let response = session.call(
+        Method::new("removeUserFromGroup")
+            .with(Element::node("token").with_text(session.token()))
+            .with(username.to_element("username"))
+            .with(groupname.to_element("groupname"))
+    )?;
+
+    let element = response.body.descend(&["removeUserFromGroupReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `getLabelsById` with `objectId: i64`.
+pub fn get_labels_by_id(session: &Session, object_id: i64) -> Result<Vec<RemoteLabel>> {
+    let response = session.call(
+        Method::new("getLabelsById")
+            .with(Element::node("token").with_text(session.token()))
+            .with(object_id.to_element("objectId"))
+    )?;
+
+    let element = response.body.descend(&["getLabelsByIdReturn"])?;
+
+    Vec::<RemoteLabel>::from_element(element)
+}
+
+/// Call `getMostPopularLabels` with `maxCount: i32`.
+pub fn get_most_popular_labels(session: &Session, max_count: i32) -> Result<Vec<RemoteLabel>> {
+    let response = session.call(
+        Method::new("getMostPopularLabels")
+            .with(Element::node("token").with_text(session.token()))
+            .with(max_count.to_element("maxCount"))
+    )?;
+
+    let element = response.body.descend(&["getMostPopularLabelsReturn"])?;
+
+    Vec::<RemoteLabel>::from_element(element)
+}
+
+/// Call `getMostPopularLabelsInSpace` with `spaceKey: String`, `maxCount: i32`.
+pub fn get_most_popular_labels_in_space(
+    session: &Session,
+    space_key: &str,
+    max_count: i32,
+) -> Result<Vec<RemoteLabel>> {
+    let response = session.call(
+        Method::new("getMostPopularLabelsInSpace")
+            .with(Element::node("token").with_text(session.token()))
+            .with(space_key.to_element("spaceKey"))
+            .with(max_count.to_element("maxCount"))
+    )?;
+
+    let element = response.body.descend(&["getMostPopularLabelsInSpaceReturn"])?;
+
+    Vec::<RemoteLabel>::from_element(element)
+}
+
+/// Call `getRecentlyUsedLabels` with `maxResults: i32`.
+pub fn get_recently_used_labels(session: &Session, max_results: i32) -> Result<Vec<RemoteLabel>> {
+    let response = session.call(
+        Method::new("getRecentlyUsedLabels")
+            .with(Element::node("token").with_text(session.token()))
+            .with(max_results.to_element("maxResults"))
+    )?;
+
+    let element = response.body.descend(&["getRecentlyUsedLabelsReturn"])?;
+
+    Vec::<RemoteLabel>::from_element(element)
+}
+
+/// Call `getRecentlyUsedLabelsInSpace` with `spaceKey: String`, `maxResults: i32`.
+pub fn get_recently_used_labels_in_space(
+    session: &Session,
+    space_key: &str,
+    max_results: i32,
+) -> Result<Vec<RemoteLabel>> {
+    let response = session.call(
+        Method::new("getRecentlyUsedLabelsInSpace")
+            .with(Element::node("token").with_text(session.token()))
+            .with(space_key.to_element("spaceKey"))
+            .with(max_results.to_element("maxResults"))
+    )?;
+
+    let element = response.body.descend(&["getRecentlyUsedLabelsInSpaceReturn"])?;
+
+    Vec::<RemoteLabel>::from_element(element)
+}
+
+/// Call `getRelatedLabels` with `labelName: String`, `maxResults: i32`.
+pub fn get_related_labels(
+    session: &Session,
+    label_name: &str,
+    max_results: i32,
+) -> Result<Vec<RemoteLabel>> {
+    let response = session.call(
+        Method::new("getRelatedLabels")
+            .with(Element::node("token").with_text(session.token()))
+            .with(label_name.to_element("labelName"))
+            .with(max_results.to_element("maxResults"))
+    )?;
+
+    let element = response.body.descend(&["getRelatedLabelsReturn"])?;
+
+    Vec::<RemoteLabel>::from_element(element)
+}
+
+/// Call `getRelatedLabelsInSpace` with `labelName: String`, `spaceKey: String`, `maxResults: i32`.
+pub fn get_related_labels_in_space(
+    session: &Session,
+    label_name: &str,
+    space_key: &str,
+    max_results: i32,
+) -> Result<Vec<RemoteLabel>> {
+    let response = session.call(
+        Method::new("getRelatedLabelsInSpace")
+            .with(Element::node("token").with_text(session.token()))
+            .with(label_name.to_element("labelName"))
+            .with(space_key.to_element("spaceKey"))
+            .with(max_results.to_element("maxResults"))
+    )?;
+
+    let element = response.body.descend(&["getRelatedLabelsInSpaceReturn"])?;
+
+    Vec::<RemoteLabel>::from_element(element)
+}
+
+/// Call `getLabelsByDetail` with `labelName: String`, `namespace: String`, `spaceKey: String`, `owner: String`.
+pub fn get_labels_by_detail(
+    session: &Session,
+    label_name: &str,
+    namespace: &str,
+    space_key: &str,
+    owner: &str,
+) -> Result<Vec<RemoteLabel>> {
+    let response = session.call(
+        Method::new("getLabelsByDetail")
+            .with(Element::node("token").with_text(session.token()))
+            .with(label_name.to_element("labelName"))
+            .with(namespace.to_element("namespace"))
+            .with(space_key.to_element("spaceKey"))
+            .with(owner.to_element("owner"))
+    )?;
+
+    let element = response.body.descend(&["getLabelsByDetailReturn"])?;
+
+    Vec::<RemoteLabel>::from_element(element)
+}
+
+/// Call `getLabelContentById` with `labelId: i64`.
+pub fn get_label_content_by_id(
+    session: &Session,
+    label_id: i64,
+) -> Result<Vec<RemoteSearchResult>> {
+    let response = session.call(
+        Method::new("getLabelContentById")
+            .with(Element::node("token").with_text(session.token()))
+            .with(label_id.to_element("labelId"))
+    )?;
+
+    let element = response.body.descend(&["getLabelContentByIdReturn"])?;
+
+    Vec::<RemoteSearchResult>::from_element(element)
+}
+
+/// Call `getLabelContentByName` with `labelName: String`.
+pub fn get_label_content_by_name(
+    session: &Session,
+    label_name: &str,
+) -> Result<Vec<RemoteSearchResult>> {
+    let response = session.call(
+        Method::new("getLabelContentByName")
+            .with(Element::node("token").with_text(session.token()))
+            .with(label_name.to_element("labelName"))
+    )?;
+
+    let element = response.body.descend(&["getLabelContentByNameReturn"])?;
+
+    Vec::<RemoteSearchResult>::from_element(element)
+}
+
+/// Call `getLabelContentByObject` with `labelObject: RemoteLabel`.
+pub fn get_label_content_by_object(
+    session: &Session,
+    label_object: &RemoteLabel,
+) -> Result<Vec<RemoteSearchResult>> {
+    let response = session.call(
+        Method::new("getLabelContentByObject")
+            .with(Element::node("token").with_text(session.token()))
+            .with(label_object.to_element("labelObject"))
+    )?;
+
+    let element = response.body.descend(&["getLabelContentByObjectReturn"])?;
+
+    Vec::<RemoteSearchResult>::from_element(element)
+}
+
+/// Call `getSpacesWithLabel` with `labelName: String`.
+pub fn get_spaces_with_label(session: &Session, label_name: &str) -> Result<Vec<RemoteSpace>> {
+    let response = session.call(
+        Method::new("getSpacesWithLabel")
+            .with(Element::node("token").with_text(session.token()))
+            .with(label_name.to_element("labelName"))
+    )?;
+
+    let element = response.body.descend(&["getSpacesWithLabelReturn"])?;
+
+    Vec::<RemoteSpace>::from_element(element)
+}
+
+/// Call `getSpacesContainingContentWithLabel` with `labelName: String`.
+pub fn get_spaces_containing_content_with_label(
+    session: &Session,
+    label_name: &str,
+) -> Result<Vec<RemoteSpace>> {
+    let response = session.call(
+        Method::new("getSpacesContainingContentWithLabel")
+            .with(Element::node("token").with_text(session.token()))
+            .with(label_name.to_element("labelName"))
+    )?;
+
+    let element = response.body.descend(&["getSpacesContainingContentWithLabelReturn"])?;
+
+    Vec::<RemoteSpace>::from_element(element)
+}
+
+/// Call `addLabelByName` with `labelName: String`, `objectId: i64`.
+pub fn add_label_by_name(session: &Session, label_name: &str, object_id: i64) -> Result<bool> {
+    let response = session.call(
+        Method::new("addLabelByName")
+            .with(Element::node("token").with_text(session.token()))
+            .with(label_name.to_element("labelName"))
+            .with(object_id.to_element("objectId"))
+    )?;
+
+    let element = response.body.descend(&["addLabelByNameReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `addLabelById` with `labelId: i64`, `objectId: i64`.
+pub fn add_label_by_id(session: &Session, label_id: i64, object_id: i64) -> Result<bool> {
+    let response = session.call(
+        Method::new("addLabelById")
+            .with(Element::node("token").with_text(session.token()))
+            .with(label_id.to_element("labelId"))
+            .with(object_id.to_element("objectId"))
+    )?;
+
+    let element = response.body.descend(&["addLabelByIdReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `addLabelByObject` with `labelObject: RemoteLabel`, `objectId: i64`.
+pub fn add_label_by_object(
+    session: &Session,
+    label_object: &RemoteLabel,
+    object_id: i64,
+) -> Result<bool> {
+    let response = session.call(
+        Method::new("addLabelByObject")
+            .with(Element::node("token").with_text(session.token()))
+            .with(label_object.to_element("labelObject"))
+            .with(object_id.to_element("objectId"))
+    )?;
+
+    let element = response.body.descend(&["addLabelByObjectReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `addLabelByNameToSpace` with `labelName: String`, `spaceKey: String`.
+pub fn add_label_by_name_to_space(
+    session: &Session,
+    label_name: &str,
+    space_key: &str,
+) -> Result<bool> {
+    let response = session.call(
+        Method::new("addLabelByNameToSpace")
+            .with(Element::node("token").with_text(session.token()))
+            .with(label_name.to_element("labelName"))
+            .with(space_key.to_element("spaceKey"))
+    )?;
+
+    let element = response.body.descend(&["addLabelByNameToSpaceReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `removeLabelByName` with `labelName: String`, `objectId: i64`.
+pub fn remove_label_by_name(session: &Session, label_name: &str, object_id: i64) -> Result<bool> {
+    let response = session.call(
+        Method::new("removeLabelByName")
+            .with(Element::node("token").with_text(session.token()))
+            .with(label_name.to_element("labelName"))
+            .with(object_id.to_element("objectId"))
+    )?;
+
+    let element = response.body.descend(&["removeLabelByNameReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `removeLabelById` with `labelId: i64`, `objectId: i64`.
+pub fn remove_label_by_id(session: &Session, label_id: i64, object_id: i64) -> Result<bool> {
+    let response = session.call(
+        Method::new("removeLabelById")
+            .with(Element::node("token").with_text(session.token()))
+            .with(label_id.to_element("labelId"))
+            .with(object_id.to_element("objectId"))
+    )?;
+
+    let element = response.body.descend(&["removeLabelByIdReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `removeLabelByObject` with `labelObject: RemoteLabel`, `objectId: i64`.
+pub fn remove_label_by_object(
+    session: &Session,
+    label_object: &RemoteLabel,
+    object_id: i64,
+) -> Result<bool> {
+    let response = session.call(
+        Method::new("removeLabelByObject")
+            .with(Element::node("token").with_text(session.token()))
+            .with(label_object.to_element("labelObject"))
+            .with(object_id.to_element("objectId"))
+    )?;
+
+    let element = response.body.descend(&["removeLabelByObjectReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `removeLabelByNameFromSpace` with `labelName: String`, `spaceKey: String`.
+pub fn remove_label_by_name_from_space(
+    session: &Session,
+    label_name: &str,
+    space_key: &str,
+) -> Result<bool> {
+    let response = session.call(
+        Method::new("removeLabelByNameFromSpace")
+            .with(Element::node("token").with_text(session.token()))
+            .with(label_name.to_element("labelName"))
+            .with(space_key.to_element("spaceKey"))
+    )?;
+
+    let element = response.body.descend(&["removeLabelByNameFromSpaceReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `watchPage` with `pageId: i64`.
+pub fn watch_page(session: &Session, page_id: i64) -> Result<bool> {
+    let response = session.call(
+        Method::new("watchPage")
+            .with(Element::node("token").with_text(session.token()))
+            .with(page_id.to_element("pageId"))
+    )?;
+
+    let element = response.body.descend(&["watchPageReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `watchPageForUser` with `pageId: i64`, `username: String`.
+pub fn watch_page_for_user(session: &Session, page_id: i64, username: &str) -> Result<bool> {
+    let response = session.call(
+        Method::new("watchPageForUser")
+            .with(Element::node("token").with_text(session.token()))
+            .with(page_id.to_element("pageId"))
+            .with(username.to_element("username"))
+    )?;
+
+    let element = response.body.descend(&["watchPageForUserReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `watchSpace` with `spaceKey: String`.
+pub fn watch_space(session: &Session, space_key: &str) -> Result<bool> {
+    let response = session.call(
+        Method::new("watchSpace")
+            .with(Element::node("token").with_text(session.token()))
+            .with(space_key.to_element("spaceKey"))
+    )?;
+
+    let element = response.body.descend(&["watchSpaceReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `removePageWatch` with `pageId: i64`.
+pub fn remove_page_watch(session: &Session, page_id: i64) -> Result<bool> {
+    let response = session.call(
+        Method::new("removePageWatch")
+            .with(Element::node("token").with_text(session.token()))
+            .with(page_id.to_element("pageId"))
+    )?;
+
+    let element = response.body.descend(&["removePageWatchReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `removePageWatchForUser` with `pageId: i64`, `username: String`.
+pub fn remove_page_watch_for_user(session: &Session, page_id: i64, username: &str) -> Result<bool> {
+    let response = session.call(
+        Method::new("removePageWatchForUser")
+            .with(Element::node("token").with_text(session.token()))
+            .with(page_id.to_element("pageId"))
+            .with(username.to_element("username"))
+    )?;
+
+    let element = response.body.descend(&["removePageWatchForUserReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `removeSpaceWatch` with `spaceKey: String`.
+pub fn remove_space_watch(session: &Session, space_key: &str) -> Result<bool> {
+    let response = session.call(
+        Method::new("removeSpaceWatch")
+            .with(Element::node("token").with_text(session.token()))
+            .with(space_key.to_element("spaceKey"))
+    )?;
+
+    let element = response.body.descend(&["removeSpaceWatchReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `isWatchingPage` with `pageId: i64`, `username: String`.
+pub fn is_watching_page(session: &Session, page_id: i64, username: &str) -> Result<bool> {
+    let response = session.call(
+        Method::new("isWatchingPage")
+            .with(Element::node("token").with_text(session.token()))
+            .with(page_id.to_element("pageId"))
+            .with(username.to_element("username"))
+    )?;
+
+    let element = response.body.descend(&["isWatchingPageReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `isWatchingSpace` with `spaceKey: String`, `username: String`.
+pub fn is_watching_space(session: &Session, space_key: &str, username: &str) -> Result<bool> {
+    let response = session.call(
+        Method::new("isWatchingSpace")
+            .with(Element::node("token").with_text(session.token()))
+            .with(space_key.to_element("spaceKey"))
+            .with(username.to_element("username"))
+    )?;
+
+    let element = response.body.descend(&["isWatchingSpaceReturn"])?;
+
+    bool::from_element(element)
+}
+
+/// Call `getWatchersForPage` with `pageId: i64`.
+pub fn get_watchers_for_page(session: &Session, page_id: i64) -> Result<Vec<RemoteUser>> {
+    let response = session.call(
+        Method::new("getWatchersForPage")
+            .with(Element::node("token").with_text(session.token()))
+            .with(page_id.to_element("pageId"))
+    )?;
+
+    let element = response.body.descend(&["getWatchersForPageReturn"])?;
+
+    Vec::<RemoteUser>::from_element(element)
+}
+
+/// Call `getWatchersForSpace` with `spaceKey: String`.
+pub fn get_watchers_for_space(session: &Session, space_key: &str) -> Result<Vec<RemoteUser>> {
+    let response = session.call(
+        Method::new("getWatchersForSpace")
+            .with(Element::node("token").with_text(session.token()))
+            .with(space_key.to_element("spaceKey"))
+    )?;
+
+    let element = response.body.descend(&["getWatchersForSpaceReturn"])?;
+
+    Vec::<RemoteUser>::from_element(element)
+}
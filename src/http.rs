@@ -1,10 +1,14 @@
 //! HTTP helpers.
 
+pub use reqwest::blocking::Client;
 use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE};
 pub use reqwest::Error as HttpError;
 pub use reqwest::StatusCode;
+pub use reqwest::{Certificate, Identity, Proxy};
+use std::fmt;
 use std::io::Read;
 use std::result;
+use std::time::Duration;
 
 /// Simplified HTTP response representation.
 #[derive(Debug)]
@@ -13,36 +17,177 @@ pub struct Response {
     pub body: String,
 }
 
+/// Body of a request that is read while it is being sent.
+pub type RequestBody = Box<dyn Read + Send>;
+
+/// HTTP response whose body is read on demand.
+pub struct StreamedResponse {
+    pub status: StatusCode,
+    pub body: Box<dyn Read + Send>,
+}
+
+impl fmt::Debug for StreamedResponse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("StreamedResponse")
+            .field("status", &self.status)
+            .finish()
+    }
+}
+
+/// Settings of the HTTP client used to talk to Confluence.
+#[derive(Debug, Clone, Default)]
+pub struct TransportOptions {
+    /// Timeout for the whole request, including reading the response
+    pub timeout: Option<Duration>,
+    /// Timeout for establishing the connection
+    pub connect_timeout: Option<Duration>,
+    /// Proxies to route requests through
+    pub proxies: Vec<Proxy>,
+    /// Additional trusted CA certificates
+    pub root_certificates: Vec<Certificate>,
+    /// Client certificate
+    pub identity: Option<Identity>,
+    /// Headers sent with every request
+    pub headers: HeaderMap,
+    /// Value of `User-Agent` header
+    pub user_agent: Option<String>,
+}
+
+impl TransportOptions {
+    /// Build blocking HTTP client with these options.
+    pub fn build_client(&self) -> Result<Client> {
+        let mut builder = Client::builder().default_headers(self.headers.clone());
+
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        for proxy in &self.proxies {
+            builder = builder.proxy(proxy.clone());
+        }
+        for certificate in &self.root_certificates {
+            builder = builder.add_root_certificate(certificate.clone());
+        }
+        if let Some(ref identity) = self.identity {
+            builder = builder.identity(identity.clone());
+        }
+        if let Some(ref user_agent) = self.user_agent {
+            builder = builder.user_agent(user_agent.clone());
+        }
+
+        builder.build()
+    }
+
+    /// Build non-blocking HTTP client with these options.
+    #[cfg(feature = "async")]
+    pub fn build_async_client(&self) -> Result<reqwest::Client> {
+        let mut builder = reqwest::Client::builder().default_headers(self.headers.clone());
+
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        for proxy in &self.proxies {
+            builder = builder.proxy(proxy.clone());
+        }
+        for certificate in &self.root_certificates {
+            builder = builder.add_root_certificate(certificate.clone());
+        }
+        if let Some(ref identity) = self.identity {
+            builder = builder.identity(identity.clone());
+        }
+        if let Some(ref user_agent) = self.user_agent {
+            builder = builder.user_agent(user_agent.clone());
+        }
+
+        builder.build()
+    }
+}
+
 /// Perform a GET request to specified URL.
-pub fn get(url: &str) -> Result<Response> {
-    let mut res = reqwest::get(url)?;
-    let mut body = String::new();
-    res.read_to_string(&mut body).unwrap();
+pub fn get(client: &Client, url: &str) -> Result<Response> {
+    let res = client.get(url).send()?;
     let status = res.status();
+    let body = res.text()?;
 
     Ok(Response { status, body })
 }
 
 /// Perform a SOAP action to specified URL.
-pub fn soap_action(url: &str, action: &str, xml: &str) -> Result<Response> {
-    let soap_action = HeaderName::from_bytes(b"SOAPAction").unwrap();
-    let soap_value = HeaderValue::from_str(action).unwrap();
-    let mut hmap = HeaderMap::new();
-    hmap.insert(CONTENT_TYPE, "text/xml; charset=utf-8".parse().unwrap());
-    hmap.insert(soap_action, soap_value);
-
-    let client = reqwest::Client::new();
-    let mut response = client
+pub fn soap_action(client: &Client, url: &str, action: &str, xml: &str) -> Result<Response> {
+    let response = client
         .post(url)
-        .headers(hmap)
+        .headers(soap_headers(action))
         .body(xml.to_string())
         .send()?;
 
-    let mut body = String::new();
-    response.read_to_string(&mut body).unwrap();
     let status = response.status();
+    let body = response.text()?;
+
+    Ok(Response { status, body })
+}
+
+/// Perform a SOAP action with streamed envelope, without reading the response body.
+pub fn soap_action_streamed(
+    client: &Client,
+    url: &str,
+    action: &str,
+    body: RequestBody,
+) -> Result<StreamedResponse> {
+    let response = client
+        .post(url)
+        .headers(soap_headers(action))
+        .body(reqwest::blocking::Body::new(body))
+        .send()?;
+
+    Ok(StreamedResponse {
+        status: response.status(),
+        body: Box::new(response),
+    })
+}
+
+/// Perform a non-blocking GET request to specified URL.
+#[cfg(feature = "async")]
+pub async fn get_async(client: &reqwest::Client, url: &str) -> Result<Response> {
+    let res = client.get(url).send().await?;
+    let status = res.status();
+    let body = res.text().await?;
+
+    Ok(Response { status, body })
+}
+
+/// Perform a non-blocking SOAP action to specified URL.
+#[cfg(feature = "async")]
+pub async fn soap_action_async(
+    client: &reqwest::Client,
+    url: &str,
+    action: &str,
+    xml: &str,
+) -> Result<Response> {
+    let response = client
+        .post(url)
+        .headers(soap_headers(action))
+        .body(xml.to_string())
+        .send()
+        .await?;
+
+    let status = response.status();
+    let body = response.text().await?;
 
     Ok(Response { status, body })
 }
 
+fn soap_headers(action: &str) -> HeaderMap {
+    let soap_action = HeaderName::from_bytes(b"SOAPAction").unwrap();
+    let soap_value = HeaderValue::from_str(action).unwrap();
+    let mut hmap = HeaderMap::new();
+    hmap.insert(CONTENT_TYPE, "text/xml; charset=utf-8".parse().unwrap());
+    hmap.insert(soap_action, soap_value);
+    hmap
+}
+
 pub type Result<T> = result::Result<T, HttpError>;
@@ -0,0 +1,12 @@
+/// Label of a page, blog post or space.
+#[derive(Debug)]
+pub struct Label {
+    /// The id of the label
+    pub id: i64,
+    /// The name of the label
+    pub name: String,
+    /// The namespace of the label, eg. `global` or `my` for personal labels
+    pub namespace: String,
+    /// Username of the owner of a personal label
+    pub owner: Option<String>,
+}
@@ -7,52 +7,145 @@ To start, create a new `Session` by calling a `login` on it
 with your credentials.
 
 Internally, the `Session` struct stores the auth `token`
-and uses it when calling remote methods.
+and uses it when calling remote methods. If the token expires,
+the session logs in again with the same credentials. Calls that fail
+with transient errors are retried according to `RetryPolicy`.
 
-The token will be destroyed (automatic logout) when `Session` goes out of scope.
+The token will be destroyed (automatic logout) when `Session` goes out of scope,
+unless the session is kept alive with `set_keep_alive` or converted with `into_token`.
+
+Use `SessionBuilder` to configure timeouts, proxies, certificates
+or headers of the underlying HTTP client, or to replace HTTP with
+a custom `transport::Transport`, such as `transport::ScriptedTransport` in tests.
+It can also skip downloading the WSDL on every login, see `wsdl::WsdlSource`.
+
+## Whole remote API
+
+Operations without a method on `Session` are available as typed functions
+in the `generated` module, produced by `cargo xtask codegen` from
+the bundled `wsdl/confluenceservice-v2.wsdl`.
+
+## Testing
+
+With the `testing` cargo feature enabled, `testing::FakeServer` provides
+an in-memory Confluence SOAP server on a localhost port for offline
+end-to-end tests.
+
+## Non-blocking client
+
+With the `async` cargo feature enabled, `AsyncSession` offers the same
+core operations as `async fn`s, for use from within a `tokio` runtime.
+
+## Limitations
+
+The remote API has no operation to restore content from the trash.
+`Session::get_trash_contents`, `Session::purge_from_trash` and `Session::empty_trash`
+cover the rest of the trash, restoring is only possible in the web interface.
+
+Search results can not be fetched in pages, because the remote `search` operation
+has no offset parameter. `Session::search` returns at most `SearchParams::max_results`
+results in a single response; narrow the query or the parameters to reach the rest.
 */
 
 #[macro_use]
 extern crate log;
+extern crate base64;
 extern crate chrono;
 extern crate reqwest;
 extern crate xml;
 extern crate xmltree;
 
+pub mod generated;
 pub mod http;
 pub mod rpser;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+pub mod transport;
 pub mod wsdl;
 
+#[cfg(feature = "async")]
+mod async_session;
+mod attachment;
+mod blog;
+mod builder;
+mod comment;
+mod content;
+mod label;
 mod page;
+mod permission;
+mod render;
+mod retry;
+mod search;
 mod server;
 mod space;
+mod transfer;
 mod transforms;
-
-pub use page::{Page, PageSummary, PageUpdateOptions, UpdatePage};
-pub use server::ServerInfo;
-pub use space::Space;
-pub use transforms::FromElement;
+mod user;
+
+#[cfg(feature = "async")]
+pub use crate::async_session::AsyncSession;
+pub use crate::attachment::{Attachment, AttachmentVersion, UploadAttachment};
+pub use crate::blog::{BlogEntry, BlogEntrySummary, UpdateBlogEntry};
+pub use crate::builder::SessionBuilder;
+pub use crate::comment::{Comment, NewComment};
+pub use crate::content::{ContentSummaries, ContentSummary};
+pub use crate::label::Label;
+pub use crate::page::{
+    Page, PageHistorySummary, PageSummary, PageUpdateOptions, Position, UpdatePage,
+};
+pub use crate::permission::{
+    ContentPermission, ContentPermissionSet, ContentPermissionType, Permission,
+};
+pub use crate::render::RenderOptions;
+pub use crate::retry::RetryPolicy;
+pub use crate::search::{ModifiedWithin, SearchParams, SearchResult, SearchType};
+pub use crate::server::ServerInfo;
+pub use crate::space::{Space, SpaceStatus, SpaceSummary, UpdateSpace};
+pub use crate::transforms::{FromElement, ToElement};
+pub use crate::user::{Group, UpdateUser, User, UserInformation};
 
 use std::io::Error as IoError;
+use std::io::{Read, Write};
 use std::result;
+use std::sync::RwLock;
 
-use self::http::HttpError;
-use self::rpser::xml::BuildElement;
-use self::rpser::{Method, RpcError};
+use crate::http::HttpError;
+use crate::rpser::xml::BuildElement;
+use crate::rpser::{Method, RpcError};
+use crate::wsdl::{WsdlCache, WsdlSource};
 use xmltree::Element;
 
 const V2_API_RPC_PATH: &str = "/rpc/soap-axis/confluenceservice-v2?wsdl";
 
 /// Client's session.
 pub struct Session {
+    transport: Box<dyn transport::Transport>,
     wsdl: wsdl::Wsdl,
-    token: String,
+    token: RwLock<String>,
+    credentials: Option<Credentials>,
+    auto_relogin: bool,
+    keep_alive: bool,
+    retry_policy: RetryPolicy,
     server_info: Option<ServerInfo>,
 }
 
+/// Credentials kept by `Session` to log in again when the token expires.
+struct Credentials {
+    user: String,
+    pass: String,
+}
+
 impl Drop for Session {
     fn drop(&mut self) {
-        self.logout().unwrap();
+        if self.keep_alive || self.token().is_empty() {
+            return;
+        }
+
+        match self.logout() {
+            Ok(true) => {}
+            Ok(false) => warn!("log out at the end of session failed"),
+            Err(e) => warn!("log out at the end of session failed: {:?}", e),
+        }
     }
 }
 
@@ -69,45 +162,230 @@ impl Session {
         "pass"
     ).unwrap();
     ```
+
+    Use `SessionBuilder` for more control over the HTTP transport.
     */
     pub fn login(url: &str, user: &str, pass: &str) -> Result<Session> {
-        debug!("logging in at url {:?} with user {:?}", url, user);
+        SessionBuilder::new().login(url, user, pass)
+    }
 
-        let url = if url.ends_with('/') {
-            &url[..url.len() - 1]
-        } else {
-            url
-        };
-        let wsdl_url = [url, V2_API_RPC_PATH].concat();
+    /**
+    Resume confluence session with the token of an earlier session.
+
+    The token is checked by fetching server info. Such session has no credentials,
+    so it can not log in again when the token expires.
+
+    ## Example
 
-        debug!("getting wsdl from url {:?}", wsdl_url);
+    ```no_run
+    let token = confluence::Session::login("https://confluence", "user", "pass")
+        .unwrap()
+        .into_token();
+
+    // later, possibly in another process
+    let session = confluence::Session::from_token("https://confluence", &token).unwrap();
+    ```
+    */
+    pub fn from_token(url: &str, token: &str) -> Result<Session> {
+        SessionBuilder::new().from_token(url, token)
+    }
+
+    /// Create new session without token, getting WSDL from the specified source.
+    ///
+    /// Also returns the WSDL fetched because it was missing in the cache.
+    fn connect(
+        transport: Box<dyn transport::Transport>,
+        url: &str,
+        retry_policy: RetryPolicy,
+        source: &WsdlSource,
+    ) -> Result<(Session, Option<String>)> {
+        let mut fetched = None;
+        let wsdl = match *source {
+            WsdlSource::Fetch => {
+                let wsdl_url = wsdl_url(url);
+                debug!("getting wsdl from url {:?}", wsdl_url);
+                wsdl::fetch(&*transport, &wsdl_url)?
+            }
+            WsdlSource::Bundled => wsdl::bundled(&endpoint_url(url)),
+            WsdlSource::File(ref path) => wsdl::load(path, &endpoint_url(url))?,
+            WsdlSource::Cache(ref dir) => match WsdlCache::new(dir.clone()).load(url) {
+                Some(wsdl) => wsdl,
+                None => {
+                    let wsdl_url = wsdl_url(url);
+                    debug!("wsdl is not cached, getting it from url {:?}", wsdl_url);
+                    let xml = transport.get(&wsdl_url)?.body;
+                    let wsdl = wsdl::parse(&xml)?;
+                    fetched = Some(xml);
+                    wsdl
+                }
+            },
+        };
 
-        let wsdl = wsdl::fetch(&wsdl_url)?;
-        let mut session = Session {
+        let session = Session {
+            transport,
             wsdl,
-            token: String::new(),
+            token: RwLock::new(String::new()),
+            credentials: None,
+            auto_relogin: true,
+            keep_alive: false,
+            retry_policy,
             server_info: None,
         };
 
-        let response = session.call(
-            Method::new("login")
-                .with(Element::node("username").with_text(user))
-                .with(Element::node("password").with_text(pass))
-        )?;
+        Ok((session, fetched))
+    }
+
+    /// Make sure the cache has WSDL of the server build this session is connected to.
+    ///
+    /// If the build changed since the WSDL was cached, it is fetched again.
+    fn sync_wsdl_cache(&mut self, cache: &WsdlCache, url: &str, fetched: Option<String>) -> Result<()> {
+        let build_id = match self.server_info {
+            Some(ref info) => info.build_id.clone(),
+            None => return Ok(()),
+        };
 
-        let token = match response.body.descend(&["loginReturn"])?.text {
-            Some(token) => token,
-            _ => return Err(Error::ReceivedNoLoginToken),
+        let xml = match fetched {
+            Some(xml) => xml,
+            None if cache.contains(url, &build_id) => return Ok(()),
+            None => {
+                debug!("server build changed to {:?}, getting wsdl again", build_id);
+                let xml = self.transport.get(&wsdl_url(url))?.body;
+                self.wsdl = wsdl::parse(&xml)?;
+                xml
+            }
         };
 
-        session.token = token;
+        if let Err(e) = cache.store(url, &build_id, &xml) {
+            warn!("failed to cache wsdl: {}", e);
+        }
+
+        Ok(())
+    }
+
+    /// Log in with credentials on the session created by `connect`.
+    fn login_with_credentials(mut self, user: &str, pass: &str) -> Result<Session> {
+        debug!("logging in with user {:?}", user);
+
+        self.credentials = Some(Credentials {
+            user: user.into(),
+            pass: pass.into(),
+        });
+        self.token = RwLock::new(self.request_token()?);
+        self.server_info = Some(self.get_server_info()?);
+
+        Ok(self)
+    }
+
+    /// Use existing token on the session created by `connect`.
+    fn resume_with_token(mut self, token: &str) -> Result<Session> {
+        debug!("resuming session with existing token");
+
+        self.token = RwLock::new(token.into());
+        // do not log out the token we did not check
+        self.keep_alive = true;
+        self.server_info = Some(self.get_server_info()?);
+        self.keep_alive = false;
 
-        match session.get_server_info() {
-            Ok(result) => session.server_info = Some(result),
-            Err(e) => return Err(e),
+        Ok(self)
+    }
+
+    /// Enable or disable automatic login when the token expires.
+    ///
+    /// When enabled (the default), a call that fails with `InvalidSessionException`
+    /// fault logs in again with the same credentials and is retried once.
+    /// Sessions created with `from_token` have no credentials and never log in again.
+    pub fn set_auto_relogin(&mut self, enabled: bool) {
+        self.auto_relogin = enabled;
+    }
+
+    /// Keep the token valid when the session goes out of scope.
+    ///
+    /// By default, the session logs out when dropped. With `keep_alive` enabled,
+    /// the token can be used later with `from_token`, until it expires on the server.
+    pub fn set_keep_alive(&mut self, keep_alive: bool) {
+        self.keep_alive = keep_alive;
+    }
+
+    /// Set the policy for retrying calls that failed with transient errors.
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        self.retry_policy = policy;
+    }
+
+    /// Current auth token.
+    pub fn token(&self) -> String {
+        // the token is replaced in a single assignment, so it stays valid in a poisoned lock
+        match self.token.read() {
+            Ok(token) => token.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
         }
+    }
+
+    /// Consume the session without logging out and return its token.
+    ///
+    /// Use `from_token` to resume the session.
+    pub fn into_token(mut self) -> String {
+        self.keep_alive = true;
+        self.token()
+    }
+
+    /// Server information received when the session was created.
+    pub fn server_info(&self) -> Option<&ServerInfo> {
+        self.server_info.as_ref()
+    }
 
-        Ok(session)
+    /// Description of the remote API, fetched when the session was created.
+    pub fn wsdl(&self) -> &wsdl::Wsdl {
+        &self.wsdl
+    }
+
+    /**
+    Check that the method matches one of its signatures in WSDL, without sending it.
+
+    ## Example
+
+    ```no_run
+    use confluence::rpser::Method;
+    use confluence::rpser::xml::BuildElement;
+    use xmltree::Element;
+
+    # let session = confluence::Session::login("https://confluence","user","pass").unwrap();
+    let method = Method::new("getPage")
+        .with(Element::node("token").with_text(session.token()))
+        .with(Element::node("pageId").with_text("65601"));
+    session.validate(&method).unwrap();
+    ```
+    */
+    pub fn validate(&self, method: &Method) -> Result<()> {
+        Ok(self.wsdl.validate(method)?)
+    }
+
+    /// Log in with stored credentials and return the new token.
+    fn request_token(&self) -> Result<String> {
+        let credentials = match self.credentials {
+            Some(ref credentials) => credentials,
+            None => return Err(Error::NoCredentials),
+        };
+
+        let response = self.call_with_retry(
+            &Method::new("login")
+                .with(Element::node("username").with_text(credentials.user.as_str()))
+                .with(Element::node("password").with_text(credentials.pass.as_str()))
+        )?;
+
+        login_token(response)
+    }
+
+    /// Replace the expired `stale_token` with a new one, unless another call already did it.
+    fn relogin(&self, stale_token: &str) -> Result<String> {
+        let mut token = match self.token.write() {
+            Ok(token) => token,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        if *token == stale_token {
+            debug!("token expired, logging in again");
+            *token = self.request_token()?;
+        }
+        Ok(token.clone())
     }
 
     /// Explicitly log out out of confluence.
@@ -115,19 +393,10 @@ impl Session {
     /// This is done automatically at the end of Session's lifetime.
     pub fn logout(&self) -> Result<bool> {
         let response = self.call(
-            Method::new("logout").with(Element::node("token").with_text(self.token.clone()))
+            Method::new("logout").with(Element::node("token").with_text(self.token()))
         )?;
 
-        Ok(match response.body.descend(&["logoutReturn"])?.text {
-            Some(ref v) if v == "true" => {
-                debug!("logged out successfully");
-                true
-            }
-            _ => {
-                debug!("log out failed (maybe expired token, maybe not loged in)");
-                false
-            }
-        })
+        logout_result(response)
     }
 
     /**
@@ -137,12 +406,12 @@ impl Session {
     pub fn get_server_info(&self) -> Result<ServerInfo> {
         let response = self.call(
             Method::new("getServerInfo")
-                .with(Element::node("token").with_text(&self.token))
+                .with(Element::node("token").with_text(self.token()))
         )?;
 
         let element = response.body.descend(&["getServerInfoReturn"])?;
 
-        Ok(ServerInfo::from_element(element)?)
+        ServerInfo::from_element(element)
     }
 
     /**
@@ -166,13 +435,233 @@ impl Session {
     pub fn get_space(&self, space_key: &str) -> Result<Space> {
         let response = self.call(
             Method::new("getSpace")
-                .with(Element::node("token").with_text(self.token.clone()))
+                .with(Element::node("token").with_text(self.token()))
                 .with(Element::node("spaceKey").with_text(space_key))
         )?;
 
         let element = response.body.descend(&["getSpaceReturn"])?;
 
-        Ok(Space::from_element(element)?)
+        Space::from_element(element)
+    }
+
+    /**
+    Returns summaries of all spaces visible to the user.
+
+    ## Example
+
+    ```no_run
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    for space in session.get_spaces().unwrap() {
+        println!("{}: {}", space.key, space.name);
+    }
+    ```
+    */
+    pub fn get_spaces(&self) -> Result<Vec<SpaceSummary>> {
+        let response = self.call(
+            Method::new("getSpaces")
+                .with(Element::node("token").with_text(self.token()))
+        )?;
+
+        let element = response.body.descend(&["getSpacesReturn"])?;
+
+        Vec::<SpaceSummary>::from_element(element)
+    }
+
+    /**
+    Creates a new space.
+
+    ## Example
+
+    ```no_run
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    let space = session.add_space(
+        confluence::UpdateSpace::with_create_fields("DOCS", "Documentation", Some("Generated docs"))
+    ).unwrap();
+    ```
+    */
+    pub fn add_space(&self, space: UpdateSpace) -> Result<Space> {
+        let response = self.call(
+            Method::new("addSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("space").with_children(space_elements(space)))
+        )?;
+
+        let element = response.body.descend(&["addSpaceReturn"])?;
+
+        Space::from_element(element)
+    }
+
+    /**
+    Creates a personal space for the user.
+
+    The key of the space is ignored, personal spaces always use `~username`.
+    */
+    pub fn add_personal_space(&self, space: UpdateSpace, user_name: &str) -> Result<Space> {
+        let response = self.call(
+            Method::new("addPersonalSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("space").with_children(space_elements(space)))
+                .with(Element::node("userName").with_text(user_name))
+        )?;
+
+        let element = response.body.descend(&["addPersonalSpaceReturn"])?;
+
+        Space::from_element(element)
+    }
+
+    /**
+    Updates name and description of the space.
+
+    ## Example
+
+    ```no_run
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    let mut space: confluence::UpdateSpace = session.get_space("DOCS").unwrap().into();
+    space.description = Some("Documentation of all projects".into());
+    session.store_space(space).unwrap();
+    ```
+    */
+    pub fn store_space(&self, space: UpdateSpace) -> Result<Space> {
+        let response = self.call(
+            Method::new("storeSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("space").with_children(space_elements(space)))
+        )?;
+
+        let element = response.body.descend(&["storeSpaceReturn"])?;
+
+        Space::from_element(element)
+    }
+
+    /// Removes the space with all its content.
+    pub fn remove_space(&self, space_key: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("removeSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("spaceKey").with_text(space_key))
+        )?;
+
+        let element = response.body.descend(&["removeSpaceReturn"])?;
+
+        Ok(element.as_boolean()?)
+    }
+
+    /// Returns the status of the space, e.g. whether it is archived.
+    pub fn get_space_status(&self, space_key: &str) -> Result<SpaceStatus> {
+        let response = self.call(
+            Method::new("getSpaceStatus")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("spaceKey").with_text(space_key))
+        )?;
+
+        let element = response.body.descend(&["getSpaceStatusReturn"])?;
+
+        SpaceStatus::from_element(element)
+    }
+
+    /**
+    Changes the status of the space, e.g. archives it.
+
+    ## Example
+
+    ```no_run
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    session.set_space_status("OLD", &confluence::SpaceStatus::Archived).unwrap();
+    ```
+    */
+    pub fn set_space_status(&self, space_key: &str, status: &SpaceStatus) -> Result<bool> {
+        let response = self.call(
+            Method::new("setSpaceStatus")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("spaceKey").with_text(space_key))
+                .with(Element::node("statusString").with_text(status.as_str()))
+        )?;
+
+        let element = response.body.descend(&["setSpaceStatusReturn"])?;
+
+        Ok(element.as_boolean()?)
+    }
+
+    /// Returns permissions of the logged in user in the space.
+    pub fn get_permissions(&self, space_key: &str) -> Result<Vec<Permission>> {
+        let response = self.call(
+            Method::new("getPermissions")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("spaceKey").with_text(space_key))
+        )?;
+
+        let element = response.body.descend(&["getPermissionsReturn"])?;
+
+        Vec::<Permission>::from_element(element)
+    }
+
+    /// Returns permissions of the user in the space.
+    pub fn get_permissions_for_user(
+        &self,
+        space_key: &str,
+        user_name: &str,
+    ) -> Result<Vec<Permission>> {
+        let response = self.call(
+            Method::new("getPermissionsForUser")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("spaceKey").with_text(space_key))
+                .with(Element::node("userName").with_text(user_name))
+        )?;
+
+        let element = response.body.descend(&["getPermissionsForUserReturn"])?;
+
+        Vec::<Permission>::from_element(element)
+    }
+
+    /**
+    Grants the permission in the space to the user or group named `entity_name`.
+
+    ## Example
+
+    ```no_run
+    use confluence::Permission;
+
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    session.add_permission_to_space(&Permission::View, "confluence-users", "DOCS").unwrap();
+    ```
+    */
+    pub fn add_permission_to_space(
+        &self,
+        permission: &Permission,
+        entity_name: &str,
+        space_key: &str,
+    ) -> Result<bool> {
+        let response = self.call(
+            Method::new("addPermissionToSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("permission").with_text(permission.as_str()))
+                .with(Element::node("remoteEntityName").with_text(entity_name))
+                .with(Element::node("spaceKey").with_text(space_key))
+        )?;
+
+        let element = response.body.descend(&["addPermissionToSpaceReturn"])?;
+
+        Ok(element.as_boolean()?)
+    }
+
+    /// Takes the permission in the space from the user or group named `entity_name`.
+    pub fn remove_permission_from_space(
+        &self,
+        permission: &Permission,
+        entity_name: &str,
+        space_key: &str,
+    ) -> Result<bool> {
+        let response = self.call(
+            Method::new("removePermissionFromSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("permission").with_text(permission.as_str()))
+                .with(Element::node("remoteEntityName").with_text(entity_name))
+                .with(Element::node("spaceKey").with_text(space_key))
+        )?;
+
+        let element = response.body.descend(&["removePermissionFromSpaceReturn"])?;
+
+        Ok(element.as_boolean()?)
     }
 
     /**
@@ -192,14 +681,14 @@ impl Session {
     pub fn get_page_by_title(&self, space_key: &str, page_title: &str) -> Result<Page> {
         let response = self.call(
             Method::new("getPage")
-                .with(Element::node("token").with_text(self.token.clone()))
+                .with(Element::node("token").with_text(self.token()))
                 .with(Element::node("spaceKey").with_text(space_key))
                 .with(Element::node("pageTitle").with_text(page_title))
         )?;
 
         let element = response.body.descend(&["getPageReturn"])?;
 
-        Ok(Page::from_element(element)?)
+        Page::from_element(element)
     }
 
     /**
@@ -219,13 +708,13 @@ impl Session {
     pub fn get_page_by_id(&self, page_id: i64) -> Result<Page> {
         let response = self.call(
             Method::new("getPage")
-                .with(Element::node("token").with_text(self.token.clone()))
+                .with(Element::node("token").with_text(self.token()))
                 .with(Element::node("pageId").with_text(page_id.to_string()))
         )?;
 
         let element = response.body.descend(&["getPageReturn"])?;
 
-        Ok(Page::from_element(element)?)
+        Page::from_element(element)
     }
 
     /**
@@ -291,33 +780,15 @@ impl Session {
     ```
     */
     pub fn store_page(&self, page: UpdatePage) -> Result<Page> {
-        let mut element_items = vec![
-            Element::node("space").with_text(page.space),
-            Element::node("title").with_text(page.title),
-            Element::node("content").with_text(page.content),
-        ];
-
-        if let Some(id) = page.id {
-            element_items.push(Element::node("id").with_text(id.to_string()));
-        }
-
-        if let Some(version) = page.version {
-            element_items.push(Element::node("version").with_text(version.to_string()));
-        }
-
-        if let Some(parent_id) = page.parent_id {
-            element_items.push(Element::node("parentId").with_text(parent_id.to_string()));
-        }
-
         let response = self.call(
             Method::new("storePage")
-                .with(Element::node("token").with_text(self.token.clone()))
-                .with(Element::node("page").with_children(element_items))
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("page").with_children(page_elements(page)))
         )?;
 
         let element = response.body.descend(&["storePageReturn"])?;
 
-        Ok(Page::from_element(element)?)
+        Page::from_element(element)
     }
 
     /**
@@ -326,24 +797,6 @@ impl Session {
     Same as `store_page`, but with additional update options parameter.
     */
     pub fn update_page(&self, page: UpdatePage, options: PageUpdateOptions) -> Result<Page> {
-        let mut element_items = vec![
-            Element::node("space").with_text(page.space),
-            Element::node("title").with_text(page.title),
-            Element::node("content").with_text(page.content),
-        ];
-
-        if let Some(id) = page.id {
-            element_items.push(Element::node("id").with_text(id.to_string()));
-        }
-
-        if let Some(version) = page.version {
-            element_items.push(Element::node("version").with_text(version.to_string()));
-        }
-
-        if let Some(parent_id) = page.parent_id {
-            element_items.push(Element::node("parentId").with_text(parent_id.to_string()));
-        }
-
         let mut update_options = vec![];
 
         if let Some(comment) = options.version_comment {
@@ -358,68 +811,1422 @@ impl Session {
 
         let response = self.call(
             Method::new("updatePage")
-                .with(Element::node("token").with_text(self.token.clone()))
-                .with(Element::node("page").with_children(element_items))
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("page").with_children(page_elements(page)))
                 .with(Element::node("pageUpdateOptions").with_children(update_options))
         )?;
 
         let element = response.body.descend(&["updatePageReturn"])?;
 
-        Ok(Page::from_element(element)?)
+        Page::from_element(element)
+    }
+
+    /// Returns historical versions of the page, newest first, without the current one.
+    pub fn get_page_history(&self, page_id: i64) -> Result<Vec<PageHistorySummary>> {
+        let response = self.call(
+            Method::new("getPageHistory")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("pageId").with_text(page_id.to_string()))
+        )?;
+
+        let element = response.body.descend(&["getPageHistoryReturn"])?;
+
+        Vec::<PageHistorySummary>::from_element(element)
     }
 
     /**
-    Returns all the direct children of this page.
+    Returns the page as it was in the version.
+
+    The returned `Page` has the id of the historical version,
+    unless the version is the current one.
 
     ## Example
 
     ```no_run
     # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
-    println!("Page Summaries: {:#?}",
-        session.get_children(
-            123456
-        )
-    );
+    let first = session.get_page_version(65601, 1).unwrap();
+    println!("Original content: {}", first.content);
     ```
     */
-    pub fn get_children(&self, page_id: i64) -> Result<Vec<PageSummary>> {
-        let response = self.call(
-            Method::new("getChildren")
-                .with(Element::node("token").with_text(self.token.clone()))
-                .with(Element::node("pageId").with_text(page_id.to_string()))
-        )?;
-
-        let element = response.body.descend(&["getChildrenReturn"])?;
-
-        let mut summaries = vec![];
-
-        for element in element.children {
-            summaries.push(PageSummary::from_element(element)?);
+    pub fn get_page_version(&self, page_id: i64, version: i32) -> Result<Page> {
+        let page = self.get_page_by_id(page_id)?;
+        if page.version == version {
+            return Ok(page);
         }
 
-        Ok(summaries)
+        match self
+            .get_page_history(page_id)?
+            .into_iter()
+            .find(|summary| summary.version == version)
+        {
+            Some(summary) => self.get_page_by_id(summary.id),
+            None => Err(Error::PageVersionNotFound(page_id, version)),
+        }
     }
 
     /**
-     * getDescendents
-     * returns all the descendants of this page (children, children's children etc).
-     */
+    Restores title and content of the version, storing them as a new version.
+
+    ## Example
+
+    ```no_run
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    let page = session.revert_to_version(65601, 3).unwrap();
+    println!("Reverted, now at version {}", page.version);
+    ```
+    */
+    pub fn revert_to_version(&self, page_id: i64, version: i32) -> Result<Page> {
+        let old = self.get_page_version(page_id, version)?;
+        let mut page: UpdatePage = self.get_page_by_id(page_id)?.into();
+        page.title = old.title;
+        page.content = old.content;
+
+        self.update_page(
+            page,
+            PageUpdateOptions {
+                version_comment: Some(format!("Reverted to version {}", version)),
+                minor_edit: false,
+            },
+        )
+    }
+
+    /**
+    Moves the page with its descendants to the position relative to the target page.
+
+    Only the place of the page in the tree changes, the content and version stay the same.
+    The page is moved to the space of the target page, if it is in another space.
+
+    ## Example
+
+    ```no_run
+    use confluence::Position;
+
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    // put the page right after its new sibling
+    session.move_page(65601, 65602, Position::Below).unwrap();
+    ```
+    */
+    pub fn move_page(&self, page_id: i64, target_id: i64, position: Position) -> Result<bool> {
+        let response = self.call(
+            Method::new("movePage")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("sourcePageId").with_text(page_id.to_string()))
+                .with(Element::node("targetPageId").with_text(target_id.to_string()))
+                .with(Element::node("position").with_text(position.as_str()))
+        )?;
+
+        let element = response.body.descend(&["movePageReturn"])?;
+
+        Ok(element.as_boolean()?)
+    }
+
+    /// Moves the page with its descendants to the top level of the space.
+    pub fn move_page_to_top_level(&self, page_id: i64, space_key: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("movePageToTopLevel")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("pageId").with_text(page_id.to_string()))
+                .with(Element::node("targetSpaceKey").with_text(space_key))
+        )?;
+
+        let element = response.body.descend(&["movePageToTopLevelReturn"])?;
+
+        Ok(element.as_boolean()?)
+    }
+
+    /**
+    Removes the page, moving it to the trash of its space.
+
+    Children of the page are moved to its parent. Fails with `Error::NotFound`
+    if the page does not exist or is already in the trash, and with
+    `Error::NotPermitted` if the user is not allowed to remove it.
+
+    ## Example
+
+    ```no_run
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    match session.remove_page(65601) {
+        Ok(_) => println!("Moved to trash"),
+        Err(confluence::Error::NotPermitted(message)) => println!("Not allowed: {}", message),
+        Err(e) => panic!("{:?}", e),
+    }
+    ```
+    */
+    pub fn remove_page(&self, page_id: i64) -> Result<bool> {
+        let response = self
+            .call(
+                Method::new("removePage")
+                    .with(Element::node("token").with_text(self.token()))
+                    .with(Element::node("pageId").with_text(page_id.to_string()))
+            )
+            .map_err(|error| {
+                confirm_missing(error, || Ok(!self.get_page_by_id(page_id)?.current))
+            })?;
+
+        let element = response.body.descend(&["removePageReturn"])?;
+
+        Ok(element.as_boolean()?)
+    }
+
+    /// Removes the historical version of the page, the current version can not be removed.
+    ///
+    /// Fails with `Error::NotFound` if the page does not exist or has no such version.
+    pub fn remove_page_version(&self, page_id: i64, version: i32) -> Result<bool> {
+        let response = self
+            .call(
+                Method::new("removePageVersionByVersion")
+                    .with(Element::node("token").with_text(self.token()))
+                    .with(Element::node("pageId").with_text(page_id.to_string()))
+                    .with(Element::node("version").with_text(version.to_string()))
+            )
+            .map_err(|error| {
+                confirm_missing(error, || {
+                    let history = self.get_page_history(page_id)?;
+                    Ok(self.get_page_by_id(page_id)?.version != version
+                        && history.iter().all(|summary| summary.version != version))
+                })
+            })?;
+
+        let element = response.body.descend(&["removePageVersionByVersionReturn"])?;
+
+        Ok(element.as_boolean()?)
+    }
+
+    /**
+    Returns `count` items of the trash of the space, starting at `offset`.
+
+    The remote API can not restore content from the trash, that is only possible
+    in the web interface. See the Limitations section of the crate documentation.
+
+    ## Example
+
+    ```no_run
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    let trash = session.get_trash_contents("DOCS", 0, 50).unwrap();
+    println!("{} of {} items:", trash.content.len(), trash.total_available);
+    for item in trash.content {
+        println!("{} {}", item.content_type, item.title);
+    }
+    ```
+    */
+    pub fn get_trash_contents(
+        &self,
+        space_key: &str,
+        offset: i32,
+        count: i32,
+    ) -> Result<ContentSummaries> {
+        let response = self
+            .call(
+                Method::new("getTrashContents")
+                    .with(Element::node("token").with_text(self.token()))
+                    .with(Element::node("spaceKey").with_text(space_key))
+                    .with(Element::node("offset").with_text(offset.to_string()))
+                    .with(Element::node("count").with_text(count.to_string()))
+            )
+            .map_err(typed_fault)?;
+
+        let element = response.body.descend(&["getTrashContentsReturn"])?;
+
+        ContentSummaries::from_element(element)
+    }
+
+    /// Removes the content from the trash of the space for good.
+    ///
+    /// Fails with `Error::NotFound` if the content is not in the trash.
+    pub fn purge_from_trash(&self, space_key: &str, content_id: i64) -> Result<bool> {
+        let response = self
+            .call(
+                Method::new("purgeFromTrash")
+                    .with(Element::node("token").with_text(self.token()))
+                    .with(Element::node("spaceKey").with_text(space_key))
+                    .with(Element::node("pageId").with_text(content_id.to_string()))
+            )
+            .map_err(|error| {
+                confirm_missing(error, || Ok(!self.is_in_trash(space_key, content_id)?))
+            })?;
+
+        let element = response.body.descend(&["purgeFromTrashReturn"])?;
+
+        Ok(element.as_boolean()?)
+    }
+
+    /// Check if the content is in the trash of the space, looking through all of it.
+    fn is_in_trash(&self, space_key: &str, content_id: i64) -> Result<bool> {
+        let mut offset = 0;
+        loop {
+            let trash = self.get_trash_contents(space_key, offset, 100)?;
+            if trash.content.iter().any(|item| item.id == content_id) {
+                return Ok(true);
+            }
+            offset += trash.content.len() as i32;
+            if trash.content.is_empty() || offset >= trash.total_available {
+                return Ok(false);
+            }
+        }
+    }
+
+    /// Removes all content in the trash of the space for good.
+    pub fn empty_trash(&self, space_key: &str) -> Result<bool> {
+        let response = self
+            .call(
+                Method::new("emptyTrash")
+                    .with(Element::node("token").with_text(self.token()))
+                    .with(Element::node("spaceKey").with_text(space_key))
+            )
+            .map_err(typed_fault)?;
+
+        let element = response.body.descend(&["emptyTrashReturn"])?;
+
+        Ok(element.as_boolean()?)
+    }
+
+    /**
+    Returns all the direct children of this page.
+
+    ## Example
+
+    ```no_run
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    println!("Page Summaries: {:#?}",
+        session.get_children(
+            123456
+        )
+    );
+    ```
+    */
+    pub fn get_children(&self, page_id: i64) -> Result<Vec<PageSummary>> {
+        let response = self.call(
+            Method::new("getChildren")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("pageId").with_text(page_id.to_string()))
+        )?;
+
+        let element = response.body.descend(&["getChildrenReturn"])?;
+
+        let mut summaries = vec![];
+
+        for element in element.children {
+            summaries.push(PageSummary::from_element(element)?);
+        }
+
+        Ok(summaries)
+    }
+
+    /**
+     * getDescendents
+     * returns all the descendants of this page (children, children's children etc).
+     */
     pub fn get_descendents(&self, page_id: i64) -> Result<Vec<PageSummary>> {
         let response = self.call(
-            Method::new("getDescendents")
-                .with(Element::node("token").with_text(&self.token))
+            Method::new("getDescendents")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("pageId").with_text(page_id.to_string()))
+        )?;
+
+        let element = response.body.descend(&["getDescendentsReturn"])?;
+
+        let mut summaries = vec![];
+
+        for element in element.children {
+            summaries.push(PageSummary::from_element(element)?);
+        }
+
+        Ok(summaries)
+    }
+
+    /**
+    Returns all the ancestors of this page, starting with the top level page.
+
+    ## Example
+
+    ```no_run
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    let breadcrumbs = session
+        .get_ancestors(65601)
+        .unwrap()
+        .into_iter()
+        .map(|page| page.title)
+        .collect::<Vec<_>>()
+        .join(" > ");
+    println!("{}", breadcrumbs);
+    ```
+    */
+    pub fn get_ancestors(&self, page_id: i64) -> Result<Vec<PageSummary>> {
+        let response = self.call(
+            Method::new("getAncestors")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("pageId").with_text(page_id.to_string()))
+        )?;
+
+        let element = response.body.descend(&["getAncestorsReturn"])?;
+
+        Vec::<PageSummary>::from_element(element)
+    }
+
+    /// Returns summaries of all pages in the space.
+    pub fn get_pages(&self, space_key: &str) -> Result<Vec<PageSummary>> {
+        let response = self.call(
+            Method::new("getPages")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("spaceKey").with_text(space_key))
+        )?;
+
+        let element = response.body.descend(&["getPagesReturn"])?;
+
+        Vec::<PageSummary>::from_element(element)
+    }
+
+    /// Returns summaries of the pages without a parent in the space.
+    pub fn get_top_level_pages(&self, space_key: &str) -> Result<Vec<PageSummary>> {
+        let response = self.call(
+            Method::new("getTopLevelPages")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("spaceKey").with_text(space_key))
+        )?;
+
+        let element = response.body.descend(&["getTopLevelPagesReturn"])?;
+
+        Vec::<PageSummary>::from_element(element)
+    }
+
+    /// Returns summary of the page, without its content.
+    pub fn get_page_summary(&self, page_id: i64) -> Result<PageSummary> {
+        let response = self.call(
+            Method::new("getPageSummary")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("pageId").with_text(page_id.to_string()))
+        )?;
+
+        let element = response.body.descend(&["getPageSummaryReturn"])?;
+
+        PageSummary::from_element(element)
+    }
+
+    /// Returns summaries of all blog entries in the space.
+    pub fn get_blog_entries(&self, space_key: &str) -> Result<Vec<BlogEntrySummary>> {
+        let response = self.call(
+            Method::new("getBlogEntries")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("spaceKey").with_text(space_key))
+        )?;
+
+        let element = response.body.descend(&["getBlogEntriesReturn"])?;
+
+        Vec::<BlogEntrySummary>::from_element(element)
+    }
+
+    /// Returns a single blog entry by id.
+    pub fn get_blog_entry(&self, entry_id: i64) -> Result<BlogEntry> {
+        let response = self.call(
+            Method::new("getBlogEntry")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("pageId").with_text(entry_id.to_string()))
+        )?;
+
+        let element = response.body.descend(&["getBlogEntryReturn"])?;
+
+        BlogEntry::from_element(element)
+    }
+
+    /**
+    Returns a blog entry published on the day of the current month with the title.
+
+    ## Example
+
+    ```no_run
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    println!("Blog entry: {:#?}",
+        session.get_blog_entry_by_day_and_title(
+            "SomeSpaceKey",
+            14,
+            "Release 1.2"
+        )
+    );
+    ```
+    */
+    pub fn get_blog_entry_by_day_and_title(
+        &self,
+        space_key: &str,
+        day_of_month: u32,
+        title: &str,
+    ) -> Result<BlogEntry> {
+        let response = self.call(
+            Method::new("getBlogEntryByDayAndTitle")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("spaceKey").with_text(space_key))
+                .with(Element::node("dayOfMonth").with_text(day_of_month.to_string()))
+                .with(Element::node("postTitle").with_text(title))
+        )?;
+
+        let element = response.body.descend(&["getBlogEntryByDayAndTitleReturn"])?;
+
+        BlogEntry::from_element(element)
+    }
+
+    /**
+    Adds or updates a blog entry.
+
+    Works like `store_page`: a new entry needs space, title and content,
+    while an update also needs the id and the current version.
+    Use helper `UpdateBlogEntry::with_create_fields` to create such entry.
+
+    ## Example
+
+    ```no_run
+    use confluence::UpdateBlogEntry;
+
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    session.store_blog_entry(
+        UpdateBlogEntry::with_create_fields(
+            "SomeSpaceKey",
+            "Release 1.2",
+            "<p>Version 1.2 is out.</p>"
+        )
+    ).unwrap();
+    ```
+    */
+    pub fn store_blog_entry(&self, entry: UpdateBlogEntry) -> Result<BlogEntry> {
+        let response = self.call(
+            Method::new("storeBlogEntry")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("entry").with_children(blog_entry_elements(entry)))
+        )?;
+
+        let element = response.body.descend(&["storeBlogEntryReturn"])?;
+
+        BlogEntry::from_element(element)
+    }
+
+    /// Returns comments of the page or blog post, including replies.
+    pub fn get_comments(&self, page_id: i64) -> Result<Vec<Comment>> {
+        let response = self.call(
+            Method::new("getComments")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("pageId").with_text(page_id.to_string()))
+        )?;
+
+        let element = response.body.descend(&["getCommentsReturn"])?;
+
+        Vec::<Comment>::from_element(element)
+    }
+
+    /// Returns a single comment by id.
+    pub fn get_comment(&self, comment_id: i64) -> Result<Comment> {
+        let response = self.call(
+            Method::new("getComment")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("commentId").with_text(comment_id.to_string()))
+        )?;
+
+        let element = response.body.descend(&["getCommentReturn"])?;
+
+        Comment::from_element(element)
+    }
+
+    /**
+    Adds the comment to the page or blog post.
+
+    ## Example
+
+    ```no_run
+    use confluence::NewComment;
+
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    let comment = session
+        .add_comment(NewComment::new(65601, "<p>Found 2 broken links.</p>"))
+        .unwrap();
+    session
+        .add_comment(NewComment::new(65601, "<p>Fixed.</p>").with_parent(comment.id))
+        .unwrap();
+    ```
+    */
+    pub fn add_comment(&self, comment: NewComment) -> Result<Comment> {
+        let mut element_items = vec![
+            Element::node("pageId").with_text(comment.page_id.to_string()),
+            Element::node("content").with_text(comment.content),
+        ];
+        if let Some(parent_id) = comment.parent_id {
+            element_items.push(Element::node("parentId").with_text(parent_id.to_string()));
+        }
+
+        let response = self.call(
+            Method::new("addComment")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("comment").with_children(element_items))
+        )?;
+
+        let element = response.body.descend(&["addCommentReturn"])?;
+
+        Comment::from_element(element)
+    }
+
+    /// Replaces the content of the comment.
+    pub fn edit_comment(&self, comment_id: i64, content: &str) -> Result<Comment> {
+        let response = self.call(
+            Method::new("editComment")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("comment").with_children(vec![
+                    Element::node("id").with_text(comment_id.to_string()),
+                    Element::node("content").with_text(content),
+                ]))
+        )?;
+
+        let element = response.body.descend(&["editCommentReturn"])?;
+
+        Comment::from_element(element)
+    }
+
+    /// Removes the comment.
+    pub fn remove_comment(&self, comment_id: i64) -> Result<bool> {
+        let response = self.call(
+            Method::new("removeComment")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("commentId").with_text(comment_id.to_string()))
+        )?;
+
+        let element = response.body.descend(&["removeCommentReturn"])?;
+
+        Ok(element.as_boolean()?)
+    }
+
+    /// Returns labels of the page, blog post or other content.
+    pub fn get_labels(&self, content_id: i64) -> Result<Vec<Label>> {
+        let response = self.call(
+            Method::new("getLabelsById")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("objectId").with_text(content_id.to_string()))
+        )?;
+
+        let element = response.body.descend(&["getLabelsByIdReturn"])?;
+
+        Vec::<Label>::from_element(element)
+    }
+
+    /**
+    Adds labels to the content.
+
+    The `label_name` may contain several labels separated by spaces or commas.
+    Names prefixed with `my:` are added as personal labels.
+
+    ## Example
+
+    ```no_run
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    session.add_label(65601, "generated nightly").unwrap();
+    ```
+    */
+    pub fn add_label(&self, content_id: i64, label_name: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("addLabelByName")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("labelName").with_text(label_name))
+                .with(Element::node("objectId").with_text(content_id.to_string()))
+        )?;
+
+        let element = response.body.descend(&["addLabelByNameReturn"])?;
+
+        Ok(element.as_boolean()?)
+    }
+
+    /// Removes labels from the content, accepting names like `add_label`.
+    pub fn remove_label(&self, content_id: i64, label_name: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("removeLabelByName")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("labelName").with_text(label_name))
+                .with(Element::node("objectId").with_text(content_id.to_string()))
+        )?;
+
+        let element = response.body.descend(&["removeLabelByNameReturn"])?;
+
+        Ok(element.as_boolean()?)
+    }
+
+    /**
+    Returns all content with the label.
+
+    ## Example
+
+    ```no_run
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    for result in session.get_label_content("generated").unwrap() {
+        if result.content_type == "page" {
+            session.remove_label(result.id, "generated").unwrap();
+        }
+    }
+    ```
+    */
+    pub fn get_label_content(&self, label_name: &str) -> Result<Vec<SearchResult>> {
+        let response = self.call(
+            Method::new("getLabelContentByName")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("labelName").with_text(label_name))
+        )?;
+
+        let element = response.body.descend(&["getLabelContentByNameReturn"])?;
+
+        Vec::<SearchResult>::from_element(element)
+    }
+
+    /// Returns up to `max_count` labels used the most in the space, most popular first.
+    pub fn get_most_popular_labels_in_space(
+        &self,
+        space_key: &str,
+        max_count: i32,
+    ) -> Result<Vec<Label>> {
+        let response = self.call(
+            Method::new("getMostPopularLabelsInSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("spaceKey").with_text(space_key))
+                .with(Element::node("maxCount").with_text(max_count.to_string()))
+        )?;
+
+        let element = response.body.descend(&["getMostPopularLabelsInSpaceReturn"])?;
+
+        Vec::<Label>::from_element(element)
+    }
+
+    /// Returns spaces that are labelled with the label.
+    pub fn get_spaces_with_label(&self, label_name: &str) -> Result<Vec<Space>> {
+        let response = self.call(
+            Method::new("getSpacesWithLabel")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("labelName").with_text(label_name))
+        )?;
+
+        let element = response.body.descend(&["getSpacesWithLabelReturn"])?;
+
+        Vec::<Space>::from_element(element)
+    }
+
+    /// Returns view and edit restrictions of the page or blog post.
+    pub fn get_content_permission_sets(
+        &self,
+        content_id: i64,
+    ) -> Result<Vec<ContentPermissionSet>> {
+        let response = self.call(
+            Method::new("getContentPermissionSets")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("contentId").with_text(content_id.to_string()))
+        )?;
+
+        let element = response.body.descend(&["getContentPermissionSetsReturn"])?;
+
+        Vec::<ContentPermissionSet>::from_element(element)
+    }
+
+    /**
+    Replaces restrictions of the type of the page or blog post.
+
+    Only the listed users and groups can view or edit the content afterwards,
+    an empty list removes the restrictions.
+
+    ## Example
+
+    ```no_run
+    use confluence::{ContentPermission, ContentPermissionType};
+
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    // only the bot may edit the generated page
+    session.set_content_permissions(
+        65601,
+        ContentPermissionType::Edit,
+        &[ContentPermission::user(ContentPermissionType::Edit, "docs-bot")],
+    ).unwrap();
+    ```
+    */
+    pub fn set_content_permissions(
+        &self,
+        content_id: i64,
+        permission_type: ContentPermissionType,
+        permissions: &[ContentPermission],
+    ) -> Result<bool> {
+        let response = self.call(
+            Method::new("setContentPermissions")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("contentId").with_text(content_id.to_string()))
+                .with(Element::node("permissionType").with_text(permission_type.as_str()))
+                .with(Element::node("permissions").with_children(permissions.iter().map(
+                    |permission| {
+                        Element::node("item").with_children(content_permission_elements(permission))
+                    },
+                )))
+        )?;
+
+        let element = response.body.descend(&["setContentPermissionsReturn"])?;
+
+        Ok(element.as_boolean()?)
+    }
+
+    /// Returns the user.
+    pub fn get_user(&self, user_name: &str) -> Result<User> {
+        let response = self.call(
+            Method::new("getUser")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("username").with_text(user_name))
+        )?;
+
+        let element = response.body.descend(&["getUserReturn"])?;
+
+        User::from_element(element)
+    }
+
+    /// Returns whether the user exists.
+    pub fn has_user(&self, user_name: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("hasUser")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("username").with_text(user_name))
+        )?;
+
+        let element = response.body.descend(&["hasUserReturn"])?;
+
+        Ok(element.as_boolean()?)
+    }
+
+    /// Returns profile information of the user.
+    pub fn get_user_information(&self, user_name: &str) -> Result<UserInformation> {
+        let response = self.call(
+            Method::new("getUserInformation")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("username").with_text(user_name))
+        )?;
+
+        let element = response.body.descend(&["getUserInformationReturn"])?;
+
+        UserInformation::from_element(element)
+    }
+
+    /**
+    Creates a new user with the password.
+
+    ## Example
+
+    ```no_run
+    use confluence::UpdateUser;
+
+    # let session = confluence::Session::login("https://confluence", "admin", "pass").unwrap();
+    session.add_user(
+        UpdateUser::new("jdoe", "Jane Doe", "jdoe@example.com"),
+        "initial password",
+    ).unwrap();
+    session.add_user_to_group("jdoe", "confluence-users").unwrap();
+    ```
+    */
+    pub fn add_user(&self, user: UpdateUser, password: &str) -> Result<()> {
+        self.call(
+            Method::new("addUser")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("user").with_children(user_elements(user)))
+                .with(Element::node("password").with_text(password))
+        )?;
+
+        Ok(())
+    }
+
+    /// Updates the full name and email of the user.
+    pub fn edit_user(&self, user: UpdateUser) -> Result<bool> {
+        let response = self.call(
+            Method::new("editUser")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("user").with_children(user_elements(user)))
+        )?;
+
+        let element = response.body.descend(&["editUserReturn"])?;
+
+        Ok(element.as_boolean()?)
+    }
+
+    /// Deactivates the user, who can not log in until reactivated.
+    pub fn deactivate_user(&self, user_name: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("deactivateUser")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("username").with_text(user_name))
+        )?;
+
+        let element = response.body.descend(&["deactivateUserReturn"])?;
+
+        Ok(element.as_boolean()?)
+    }
+
+    /// Reactivates the deactivated user.
+    pub fn reactivate_user(&self, user_name: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("reactivateUser")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("username").with_text(user_name))
+        )?;
+
+        let element = response.body.descend(&["reactivateUserReturn"])?;
+
+        Ok(element.as_boolean()?)
+    }
+
+    /// Returns all groups.
+    pub fn get_groups(&self) -> Result<Vec<Group>> {
+        let response = self.call(
+            Method::new("getGroups").with(Element::node("token").with_text(self.token()))
+        )?;
+
+        let element = response.body.descend(&["getGroupsReturn"])?;
+
+        Vec::<Group>::from_element(element)
+    }
+
+    /// Creates a new group.
+    pub fn add_group(&self, group_name: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("addGroup")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("groupname").with_text(group_name))
+        )?;
+
+        let element = response.body.descend(&["addGroupReturn"])?;
+
+        Ok(element.as_boolean()?)
+    }
+
+    /// Adds the user to the group.
+    pub fn add_user_to_group(&self, user_name: &str, group_name: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("addUserToGroup")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("username").with_text(user_name))
+                .with(Element::node("groupname").with_text(group_name))
+        )?;
+
+        let element = response.body.descend(&["addUserToGroupReturn"])?;
+
+        Ok(element.as_boolean()?)
+    }
+
+    /// Removes the user from the group.
+    pub fn remove_user_from_group(&self, user_name: &str, group_name: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("removeUserFromGroup")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("username").with_text(user_name))
+                .with(Element::node("groupname").with_text(group_name))
+        )?;
+
+        let element = response.body.descend(&["removeUserFromGroupReturn"])?;
+
+        Ok(element.as_boolean()?)
+    }
+
+    /// Returns groups the user is a member of.
+    pub fn get_user_groups(&self, user_name: &str) -> Result<Vec<Group>> {
+        let response = self.call(
+            Method::new("getUserGroups")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("username").with_text(user_name))
+        )?;
+
+        let element = response.body.descend(&["getUserGroupsReturn"])?;
+
+        Vec::<Group>::from_element(element)
+    }
+
+    /**
+    Adds the page or blog post to the watched content of the logged in user.
+
+    ## Example
+
+    ```no_run
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    session.watch_page(65601).unwrap();
+    assert!(session.is_watching_page(65601, "user").unwrap());
+    ```
+    */
+    pub fn watch_page(&self, page_id: i64) -> Result<bool> {
+        let response = self.call(
+            Method::new("watchPage")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("pageId").with_text(page_id.to_string()))
+        )?;
+
+        let element = response.body.descend(&["watchPageReturn"])?;
+
+        Ok(element.as_boolean()?)
+    }
+
+    /// Adds the page or blog post to the watched content of the user, needs administrator rights.
+    pub fn watch_page_for_user(&self, page_id: i64, user_name: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("watchPageForUser")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("pageId").with_text(page_id.to_string()))
+                .with(Element::node("username").with_text(user_name))
+        )?;
+
+        let element = response.body.descend(&["watchPageForUserReturn"])?;
+
+        Ok(element.as_boolean()?)
+    }
+
+    /// Adds the space to the watched content of the logged in user.
+    pub fn watch_space(&self, space_key: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("watchSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("spaceKey").with_text(space_key))
+        )?;
+
+        let element = response.body.descend(&["watchSpaceReturn"])?;
+
+        Ok(element.as_boolean()?)
+    }
+
+    /// Stops watching of the page or blog post by the logged in user.
+    pub fn remove_page_watch(&self, page_id: i64) -> Result<bool> {
+        let response = self.call(
+            Method::new("removePageWatch")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("pageId").with_text(page_id.to_string()))
+        )?;
+
+        let element = response.body.descend(&["removePageWatchReturn"])?;
+
+        Ok(element.as_boolean()?)
+    }
+
+    /// Stops watching of the page or blog post by the user, needs administrator rights.
+    pub fn remove_page_watch_for_user(&self, page_id: i64, user_name: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("removePageWatchForUser")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("pageId").with_text(page_id.to_string()))
+                .with(Element::node("username").with_text(user_name))
+        )?;
+
+        let element = response.body.descend(&["removePageWatchForUserReturn"])?;
+
+        Ok(element.as_boolean()?)
+    }
+
+    /// Stops watching of the space by the logged in user.
+    pub fn remove_space_watch(&self, space_key: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("removeSpaceWatch")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("spaceKey").with_text(space_key))
+        )?;
+
+        let element = response.body.descend(&["removeSpaceWatchReturn"])?;
+
+        Ok(element.as_boolean()?)
+    }
+
+    /// Returns whether the user watches the page or blog post.
+    pub fn is_watching_page(&self, page_id: i64, user_name: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("isWatchingPage")
+                .with(Element::node("token").with_text(self.token()))
                 .with(Element::node("pageId").with_text(page_id.to_string()))
+                .with(Element::node("username").with_text(user_name))
         )?;
 
-        let element = response.body.descend(&["getDescendentsReturn"])?;
+        let element = response.body.descend(&["isWatchingPageReturn"])?;
 
-        let mut summaries = vec![];
+        Ok(element.as_boolean()?)
+    }
 
-        for element in element.children {
-            summaries.push(PageSummary::from_element(element)?);
+    /// Returns whether the user watches the space.
+    pub fn is_watching_space(&self, space_key: &str, user_name: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("isWatchingSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("spaceKey").with_text(space_key))
+                .with(Element::node("username").with_text(user_name))
+        )?;
+
+        let element = response.body.descend(&["isWatchingSpaceReturn"])?;
+
+        Ok(element.as_boolean()?)
+    }
+
+    /// Returns users watching the page or blog post.
+    pub fn get_watchers_for_page(&self, page_id: i64) -> Result<Vec<User>> {
+        let response = self.call(
+            Method::new("getWatchersForPage")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("pageId").with_text(page_id.to_string()))
+        )?;
+
+        let element = response.body.descend(&["getWatchersForPageReturn"])?;
+
+        Vec::<User>::from_element(element)
+    }
+
+    /// Returns users watching the space.
+    pub fn get_watchers_for_space(&self, space_key: &str) -> Result<Vec<User>> {
+        let response = self.call(
+            Method::new("getWatchersForSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("spaceKey").with_text(space_key))
+        )?;
+
+        let element = response.body.descend(&["getWatchersForSpaceReturn"])?;
+
+        Vec::<User>::from_element(element)
+    }
+
+    /**
+    Renders the content to HTML, as it would be displayed in the page.
+
+    When `content` is empty, the stored content of the page is rendered.
+    With `page_id` of `0`, the content is rendered in the context of the space only.
+
+    ## Example
+
+    ```no_run
+    use confluence::RenderOptions;
+
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    let storage = session.convert_wiki_to_storage_format("h1. Preview").unwrap();
+    let html = session
+        .render_content("DOCS", 0, &storage, &RenderOptions::new().with_clean_style())
+        .unwrap();
+    println!("{}", html);
+    ```
+    */
+    pub fn render_content(
+        &self,
+        space_key: &str,
+        page_id: i64,
+        content: &str,
+        options: &RenderOptions,
+    ) -> Result<String> {
+        let response = self.call(
+            Method::new("renderContent")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("spaceKey").with_text(space_key))
+                .with(Element::node("pageId").with_text(page_id.to_string()))
+                .with(Element::node("newContent").with_text(content))
+                .with(Element::node("parameters").with_children(map_items(options.entries())))
+        )?;
+
+        let element = response.body.descend(&["renderContentReturn"])?;
+
+        String::from_element(element)
+    }
+
+    /// Converts content in wiki markup to the storage format used by `store_page`.
+    pub fn convert_wiki_to_storage_format(&self, wiki: &str) -> Result<String> {
+        let response = self.call(
+            Method::new("convertWikiToStorageFormat")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("wiki").with_text(wiki))
+        )?;
+
+        let element = response.body.descend(&["convertWikiToStorageFormatReturn"])?;
+
+        String::from_element(element)
+    }
+
+    /**
+    Searches content with the query, returning at most `params.max_results` results.
+
+    The remote API has no offset parameter, so results can not be fetched in pages:
+    all of them arrive in a single response. See the Limitations section of the crate
+    documentation.
+
+    ## Example
+
+    ```no_run
+    use confluence::SearchParams;
+
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    let params = SearchParams::new().with_space_key("OPS");
+    for result in session.search("deployment", &params).unwrap() {
+        println!("{} {}", result.title, result.url);
+    }
+    ```
+    */
+    pub fn search(&self, query: &str, params: &SearchParams) -> Result<Vec<SearchResult>> {
+        let response = self.call(
+            Method::new("search")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("query").with_text(query))
+                .with(Element::node("parameters").with_children(map_items(params.entries())))
+                .with(Element::node("maxResults").with_text(params.max_results.to_string()))
+        )?;
+
+        let element = response.body.descend(&["searchReturn"])?;
+
+        Vec::<SearchResult>::from_element(element)
+    }
+
+    /// Returns attachments of the page or blog post.
+    pub fn get_attachments(&self, page_id: i64) -> Result<Vec<Attachment>> {
+        let response = self.call(
+            Method::new("getAttachments")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("pageId").with_text(page_id.to_string()))
+        )?;
+
+        let element = response.body.descend(&["getAttachmentsReturn"])?;
+
+        Vec::<Attachment>::from_element(element)
+    }
+
+    /// Returns a single attachment of the page by file name.
+    ///
+    /// The remote API can not list the versions of an attachment, but the `file_size` and
+    /// `created` of any known version number can be looked up here.
+    pub fn get_attachment(
+        &self,
+        page_id: i64,
+        file_name: &str,
+        version: AttachmentVersion,
+    ) -> Result<Attachment> {
+        let response = self.call(
+            Method::new("getAttachment")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("pageId").with_text(page_id.to_string()))
+                .with(Element::node("fileName").with_text(file_name))
+                .with(Element::node("version").with_text(version.as_number().to_string()))
+        )?;
+
+        let element = response.body.descend(&["getAttachmentReturn"])?;
+
+        Attachment::from_element(element)
+    }
+
+    /**
+    Downloads the content of the attachment.
+
+    ## Example
+
+    ```no_run
+    use confluence::AttachmentVersion;
+
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    let data = session
+        .get_attachment_data(65601, "diagram.png", AttachmentVersion::Latest)
+        .unwrap();
+    std::fs::write("diagram.png", data).unwrap();
+    ```
+    */
+    pub fn get_attachment_data(
+        &self,
+        page_id: i64,
+        file_name: &str,
+        version: AttachmentVersion,
+    ) -> Result<Vec<u8>> {
+        let response = self.call(
+            Method::new("getAttachmentData")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("pageId").with_text(page_id.to_string()))
+                .with(Element::node("fileName").with_text(file_name))
+                .with(Element::node("version").with_text(version.as_number().to_string()))
+        )?;
+
+        let element = response.body.descend(&["getAttachmentDataReturn"])?;
+
+        transforms::decode_base64(&element)
+    }
+
+    /**
+    Uploads the attachment to the page or blog post.
+
+    If the page already has an attachment with the same file name,
+    the upload becomes its new version.
+
+    ## Example
+
+    ```no_run
+    use confluence::UploadAttachment;
+
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    let data = std::fs::read("diagram.png").unwrap();
+    session.add_attachment(
+        65601,
+        UploadAttachment::new("diagram.png", "image/png").with_comment("Architecture"),
+        &data
+    ).unwrap();
+    ```
+    */
+    pub fn add_attachment(
+        &self,
+        page_id: i64,
+        attachment: UploadAttachment,
+        data: &[u8],
+    ) -> Result<Attachment> {
+        let method = self.add_attachment_method(page_id, attachment, base64::encode(data));
+        let response = self.call(method)?;
+
+        let element = response.body.descend(&["addAttachmentReturn"])?;
+
+        Attachment::from_element(element)
+    }
+
+    /**
+    Uploads the attachment, reading its content from `data` while it is being sent.
+
+    Unlike `add_attachment`, the content is never held in memory as a whole, so this
+    is the way to upload large files. `progress` is called with the number of bytes
+    read from `data` so far.
+
+    The upload can not be replayed, so it is neither retried on transient errors
+    nor repeated after logging in again with expired token.
+
+    ## Example
+
+    ```no_run
+    use confluence::UploadAttachment;
+
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    let file = std::fs::File::open("backup.zip").unwrap();
+    session.upload_attachment(
+        65601,
+        UploadAttachment::new("backup.zip", "application/zip"),
+        file,
+        |sent| println!("{} bytes sent", sent)
+    ).unwrap();
+    ```
+    */
+    pub fn upload_attachment<R, F>(
+        &self,
+        page_id: i64,
+        attachment: UploadAttachment,
+        data: R,
+        progress: F,
+    ) -> Result<Attachment>
+    where
+        R: Read + Send + 'static,
+        F: FnMut(u64) + Send + 'static,
+    {
+        let placeholder = transfer::DATA_PLACEHOLDER.to_string();
+        let method = self.add_attachment_method(page_id, attachment, placeholder);
+        let (url, envelope) = prepare_call(&self.wsdl, &method)?;
+
+        let body = transfer::upload_body(&envelope, data, progress);
+        let response = self.transport.soap_action_streamed(url, &method.name, body)?;
+
+        let element = transfer::read_response(response)?
+            .body
+            .descend(&["addAttachmentReturn"])?;
+
+        Attachment::from_element(element)
+    }
+
+    fn add_attachment_method(
+        &self,
+        page_id: i64,
+        attachment: UploadAttachment,
+        data: String,
+    ) -> Method {
+        Method::new("addAttachment")
+            .with(Element::node("token").with_text(self.token()))
+            .with(Element::node("contentId").with_text(page_id.to_string()))
+            .with(Element::node("attachment").with_children(attachment_elements(attachment)))
+            .with(Element::node("attachmentData").with_text(data))
+    }
+
+    /**
+    Downloads the content of the attachment into `output`, decoding it as it arrives.
+
+    Unlike `get_attachment_data`, the content is never held in memory as a whole.
+    `progress` is called with the number of bytes written so far, and the total
+    is returned. The download is not retried on transient errors.
+
+    ## Example
+
+    ```no_run
+    use confluence::AttachmentVersion;
+
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    let mut file = std::fs::File::create("backup.zip").unwrap();
+    session.download_attachment(
+        65601,
+        "backup.zip",
+        AttachmentVersion::Latest,
+        &mut file,
+        |received| println!("{} bytes received", received)
+    ).unwrap();
+    ```
+    */
+    pub fn download_attachment<W, F>(
+        &self,
+        page_id: i64,
+        file_name: &str,
+        version: AttachmentVersion,
+        output: &mut W,
+        mut progress: F,
+    ) -> Result<u64>
+    where
+        W: Write + ?Sized,
+        F: FnMut(u64),
+    {
+        let mut method = Method::new("getAttachmentData")
+            .with(Element::node("token").with_text(self.token()))
+            .with(Element::node("pageId").with_text(page_id.to_string()))
+            .with(Element::node("fileName").with_text(file_name))
+            .with(Element::node("version").with_text(version.as_number().to_string()));
+
+        // a fault arrives before any data is written, so the call can be repeated
+        let error = match self.download_once(&method, output, &mut progress) {
+            Err(Error::Rpc(error)) => error,
+            other => return other,
+        };
+        if !self.renew_expired_token(&mut method, &error)? {
+            return Err(Error::Rpc(error));
         }
 
-        Ok(summaries)
+        self.download_once(&method, output, &mut progress)
+    }
+
+    fn download_once<W, F>(&self, method: &Method, output: &mut W, progress: F) -> Result<u64>
+    where
+        W: Write + ?Sized,
+        F: FnMut(u64),
+    {
+        let (url, envelope) = prepare_call(&self.wsdl, method)?;
+
+        let body = Box::new(std::io::Cursor::new(envelope.into_bytes()));
+        let response = self.transport.soap_action_streamed(url, &method.name, body)?;
+
+        transfer::decode_response(response, "getAttachmentDataReturn", output, progress)
+    }
+
+    /// Moves the attachment to another page, possibly with a new file name.
+    ///
+    /// Pass the same page id to rename the attachment in place.
+    pub fn move_attachment(
+        &self,
+        page_id: i64,
+        file_name: &str,
+        new_page_id: i64,
+        new_file_name: &str,
+    ) -> Result<bool> {
+        let response = self.call(
+            Method::new("moveAttachment")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("originalContentId").with_text(page_id.to_string()))
+                .with(Element::node("originalName").with_text(file_name))
+                .with(Element::node("newContentEntityId").with_text(new_page_id.to_string()))
+                .with(Element::node("newName").with_text(new_file_name))
+        )?;
+
+        let element = response.body.descend(&["moveAttachmentReturn"])?;
+
+        Ok(element.as_boolean()?)
+    }
+
+    /// Removes the attachment with all its versions.
+    pub fn remove_attachment(&self, page_id: i64, file_name: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("removeAttachment")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("contentId").with_text(page_id.to_string()))
+                .with(Element::node("fileName").with_text(file_name))
+        )?;
+
+        let element = response.body.descend(&["removeAttachmentReturn"])?;
+
+        Ok(element.as_boolean()?)
     }
 
     /// Call a custom method on this session.
@@ -440,31 +2247,327 @@ impl Session {
     /// If you need an example, look at how these convenience methods are implemented.
     ///
     /// Pull requests are welcome!
-    pub fn call(&self, method: rpser::Method) -> Result<rpser::Response> {
-        let url = match self.wsdl.operations.get(&method.name) {
-            None => return Err(Error::MethodNotFoundInWsdl(method.name)),
-            Some(ref op) => &op.url,
+    ///
+    /// ## Expired token
+    ///
+    /// If the `token` argument of the method is rejected with `InvalidSessionException`,
+    /// the session logs in again and retries the method once with the new token.
+    /// Use `set_auto_relogin` to disable this.
+    ///
+    /// ## Transient errors
+    ///
+    /// Methods allowed by the session's `RetryPolicy` are retried with backoff
+    /// when they fail with a transient error.
+    pub fn call(&self, mut method: rpser::Method) -> Result<rpser::Response> {
+        let error = match self.call_with_retry(&method) {
+            Err(Error::Rpc(error)) => error,
+            other => return other,
         };
 
-        // do now show password in logs
-        if method.name == "login" {
-            debug!("[call] login ******");
-        } else {
-            debug!("[call] {}", method);
+        if !self.renew_expired_token(&mut method, &error)? {
+            return Err(Error::Rpc(error));
+        }
+
+        self.call_with_retry(&method)
+    }
+
+    /// Log in again if the method failed because its token expired, and put the new token in it.
+    ///
+    /// Returns `false` if the error is not caused by expired token or relogin is not possible.
+    fn renew_expired_token(&self, method: &mut Method, error: &RpcError) -> Result<bool> {
+        let expired = self.auto_relogin
+            && self.credentials.is_some()
+            && method.name != "logout"
+            && error.exception_name() == Some("InvalidSessionException");
+        let stale_token = match method.args.iter().find(|arg| arg.name == "token") {
+            Some(arg) if expired => arg.text.clone().unwrap_or_default(),
+            _ => return Ok(false),
+        };
+
+        let token = self.relogin(&stale_token)?;
+        if let Some(arg) = method.args.iter_mut().find(|arg| arg.name == "token") {
+            arg.text = Some(token);
+        }
+
+        Ok(true)
+    }
+
+    /// Call a method, retrying it on transient errors if the policy allows.
+    fn call_with_retry(&self, method: &rpser::Method) -> Result<rpser::Response> {
+        let policy = &self.retry_policy;
+        let mut attempt = 1;
+        loop {
+            match self.call_once(method) {
+                Err(ref e)
+                    if attempt < policy.max_attempts
+                        && policy.retries_method(&method.name)
+                        && policy.is_transient(e) =>
+                {
+                    let backoff = policy.backoff(attempt);
+                    warn!(
+                        "[call] {} attempt {} failed ({:?}), retrying in {:?}",
+                        method.name, attempt, e, backoff
+                    );
+                    std::thread::sleep(backoff);
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Call a method once, without handling expired token.
+    fn call_once(&self, method: &rpser::Method) -> Result<rpser::Response> {
+        let (url, envelope) = prepare_call(&self.wsdl, method)?;
+
+        let http_response = self.transport.soap_action(url, &method.name, &envelope)?;
+
+        parse_call_response(http_response)
+    }
+}
+
+/// Build the WSDL url from the base url of Confluence server.
+fn wsdl_url(url: &str) -> String {
+    let url = url.strip_suffix('/').unwrap_or(url);
+    [url, V2_API_RPC_PATH].concat()
+}
+
+/// Build the SOAP endpoint url from the base url of Confluence server.
+fn endpoint_url(url: &str) -> String {
+    let wsdl_url = wsdl_url(url);
+    wsdl_url.trim_end_matches("?wsdl").into()
+}
+
+/// Find the method's endpoint url in WSDL and build the SOAP envelope for it.
+fn prepare_call<'w>(wsdl: &'w wsdl::Wsdl, method: &Method) -> Result<(&'w str, String)> {
+    let url = match wsdl.operations.get(&method.name) {
+        None => return Err(Error::MethodNotFoundInWsdl(method.name.clone())),
+        Some(op) => &op.url,
+    };
+
+    // do now show password in logs
+    if method.name == "login" {
+        debug!("[call] login ******");
+    } else {
+        debug!("[call] {}", method);
+    }
+
+    let envelope = method.as_xml(url);
+
+    // do now show password in logs
+    if method.name != "login" {
+        trace!("[method xml] {}", envelope);
+    }
+
+    Ok((url, envelope))
+}
+
+/// Parse the SOAP envelope received as a response to the call.
+///
+/// Unsuccessful HTTP response without SOAP envelope results in `Error::HttpStatus`.
+fn parse_call_response(response: http::Response) -> Result<rpser::Response> {
+    trace!("[response xml] {}", response.body);
+
+    match rpser::Response::from_xml(&response.body) {
+        Err(RpcError::MalformedXml { .. }) | Err(RpcError::UnexpectedElement { .. })
+            if !response.status.is_success() =>
+        {
+            Err(Error::HttpStatus(response.status))
         }
+        result => Ok(result?),
+    }
+}
 
-        let envelope = method.as_xml(url);
+/// Extract the token from `login` response.
+fn login_token(response: rpser::Response) -> Result<String> {
+    match response.body.descend(&["loginReturn"])?.text {
+        Some(token) => Ok(token),
+        _ => Err(Error::ReceivedNoLoginToken),
+    }
+}
 
-        // do now show password in logs
-        if method.name != "login" {
-            trace!("[method xml] {}", envelope);
+/// Extract the success flag from `logout` response.
+fn logout_result(response: rpser::Response) -> Result<bool> {
+    Ok(match response.body.descend(&["logoutReturn"])?.text {
+        Some(ref v) if v == "true" => {
+            debug!("logged out successfully");
+            true
         }
+        _ => {
+            debug!("log out failed (maybe expired token, maybe not loged in)");
+            false
+        }
+    })
+}
+
+/// Convert `UpdatePage` to the children of `page` argument element.
+fn page_elements(page: UpdatePage) -> Vec<Element> {
+    let mut element_items = vec![
+        Element::node("space").with_text(page.space),
+        Element::node("title").with_text(page.title),
+        Element::node("content").with_text(page.content),
+    ];
+
+    if let Some(id) = page.id {
+        element_items.push(Element::node("id").with_text(id.to_string()));
+    }
+
+    if let Some(version) = page.version {
+        element_items.push(Element::node("version").with_text(version.to_string()));
+    }
+
+    if let Some(parent_id) = page.parent_id {
+        element_items.push(Element::node("parentId").with_text(parent_id.to_string()));
+    }
+
+    element_items
+}
+
+fn user_elements(user: UpdateUser) -> Vec<Element> {
+    vec![
+        Element::node("name").with_text(user.name),
+        Element::node("fullname").with_text(user.full_name),
+        Element::node("email").with_text(user.email),
+    ]
+}
+
+fn content_permission_elements(permission: &ContentPermission) -> Vec<Element> {
+    let mut element_items =
+        vec![Element::node("type").with_text(permission.permission_type.as_str())];
+
+    if let Some(ref user_name) = permission.user_name {
+        element_items.push(Element::node("userName").with_text(user_name.as_str()));
+    }
+
+    if let Some(ref group_name) = permission.group_name {
+        element_items.push(Element::node("groupName").with_text(group_name.as_str()));
+    }
+
+    element_items
+}
+
+/// Convert key-value pairs to the items of `apachesoap:Map` element.
+fn map_items(entries: Vec<(&str, &str)>) -> Vec<Element> {
+    entries
+        .into_iter()
+        .map(|(key, value)| {
+            Element::node("item").with_children(vec![
+                Element::node("key").with_text(key),
+                Element::node("value").with_text(value),
+            ])
+        })
+        .collect()
+}
+
+fn blog_entry_elements(entry: UpdateBlogEntry) -> Vec<Element> {
+    let mut element_items = vec![
+        Element::node("space").with_text(entry.space),
+        Element::node("title").with_text(entry.title),
+        Element::node("content").with_text(entry.content),
+    ];
 
-        let http_response = http::soap_action(url, &method.name, &envelope)?;
+    if let Some(id) = entry.id {
+        element_items.push(Element::node("id").with_text(id.to_string()));
+    }
+
+    if let Some(version) = entry.version {
+        element_items.push(Element::node("version").with_text(version.to_string()));
+    }
+
+    if let Some(publish_date) = entry.publish_date {
+        element_items.push(publish_date.to_element("publishDate"));
+    }
+
+    element_items
+}
+
+fn attachment_elements(attachment: UploadAttachment) -> Vec<Element> {
+    let mut element_items = vec![
+        Element::node("fileName").with_text(attachment.file_name.clone()),
+        Element::node("title").with_text(attachment.file_name),
+        Element::node("contentType").with_text(attachment.content_type),
+    ];
+
+    if let Some(comment) = attachment.comment {
+        element_items.push(Element::node("comment").with_text(comment));
+    }
+
+    element_items
+}
+
+fn space_elements(space: UpdateSpace) -> Vec<Element> {
+    let mut element_items = vec![
+        Element::node("key").with_text(space.key),
+        Element::node("name").with_text(space.name),
+    ];
+
+    if let Some(description) = space.description {
+        element_items.push(Element::node("description").with_text(description));
+    }
+
+    if let Some(home_page) = space.home_page {
+        element_items.push(Element::node("homePage").with_text(home_page.to_string()));
+    }
+
+    element_items
+}
+
+/// Turn remote faults about missing permissions into `Error::NotPermitted`.
+fn typed_fault(error: Error) -> Error {
+    let typed = match error {
+        Error::Rpc(ref e) => match (e.exception_name(), e.fault_message()) {
+            (Some("NotPermittedException"), Some(message)) => {
+                Some(Error::NotPermitted(message.into()))
+            }
+            _ => None,
+        },
+        _ => None,
+    };
+
+    typed.unwrap_or(error)
+}
 
-        trace!("[response xml] {}", http_response.body);
+/// Turn the remote fault into `Error::NotFound` if the lookup confirms that
+/// the target of the call is missing.
+///
+/// The remote API reports missing content with a generic `RemoteException`,
+/// so the fault alone does not tell why the call failed. A lookup that fails
+/// with a remote fault of its own also counts as confirmation, unless the fault
+/// is about permissions or the session. The fault is returned unchanged if the
+/// lookup finds the target or can not be completed.
+fn confirm_missing<F>(error: Error, is_missing: F) -> Error
+where
+    F: FnOnce() -> Result<bool>,
+{
+    let fault = match typed_fault(error) {
+        Error::Rpc(fault) => fault,
+        other => return other,
+    };
+    let message = match fault.fault_message() {
+        Some(message) => message.to_string(),
+        None => return Error::Rpc(fault),
+    };
+
+    match is_missing() {
+        Ok(true) => Error::NotFound(message),
+        Err(Error::Rpc(ref lookup_fault)) if !is_access_fault(lookup_fault) => {
+            Error::NotFound(message)
+        }
+        _ => Error::Rpc(fault),
+    }
+}
 
-        Ok(rpser::Response::from_xml(&http_response.body)?)
+/// Whether the fault is caused by missing permissions or an invalid session
+/// rather than by the content the call refers to.
+fn is_access_fault(fault: &RpcError) -> bool {
+    match fault.exception_name() {
+        Some(name) => [
+            "NotPermittedException",
+            "InvalidSessionException",
+            "AuthenticationFailedException",
+        ]
+        .contains(&name),
+        None => false,
     }
 }
 
@@ -473,9 +2576,17 @@ impl Session {
 pub enum Error {
     MethodNotFoundInWsdl(String),
     ReceivedNoLoginToken,
+    NoCredentials,
+    PageVersionNotFound(i64, i32),
+    NotFound(String),
+    NotPermitted(String),
+    UnexpectedRequest(String),
+    InvalidMethod(wsdl::ValidationError),
+    HttpStatus(http::StatusCode),
     Io(IoError),
     Http(HttpError),
     Rpc(Box<RpcError>),
+    Base64(base64::DecodeError),
 }
 
 impl From<HttpError> for Error {
@@ -496,6 +2607,18 @@ impl From<rpser::xml::Error> for Error {
     }
 }
 
+impl From<wsdl::ValidationError> for Error {
+    fn from(other: wsdl::ValidationError) -> Error {
+        Error::InvalidMethod(other)
+    }
+}
+
+impl From<base64::DecodeError> for Error {
+    fn from(other: base64::DecodeError) -> Error {
+        Error::Base64(other)
+    }
+}
+
 impl From<IoError> for Error {
     fn from(other: IoError) -> Error {
         Error::Io(other)
@@ -49,6 +49,46 @@ pub struct PageSummary {
     pub url: String,
 }
 
+/// Summary of a historical version of a page.
+#[derive(Debug)]
+pub struct PageHistorySummary {
+    /// The id of the historical version, usable with `Session::get_page_by_id`
+    pub id: i64,
+    /// The version number
+    pub version: i32,
+    /// Username of the modifier of this version
+    pub modifier: String,
+    /// Timestamp this version was created
+    pub modified: DateTime<Utc>,
+    /// Comment of the update, if the server provides it
+    pub version_comment: Option<String>,
+}
+
+/// Where to move a page, relative to the target page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Position {
+    /// Sibling of the target, ordered right before it
+    Above,
+    /// Sibling of the target, ordered right after it
+    Below,
+    /// Last child of the target
+    Append,
+    /// Top level page of the space of the target
+    TopLevel,
+}
+
+impl Position {
+    /// Position name used by the remote API.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Position::Above => "above",
+            Position::Below => "below",
+            Position::Append => "append",
+            Position::TopLevel => "topLevel",
+        }
+    }
+}
+
 /// Page Object for creating a Page.
 #[derive(Debug)]
 pub struct UpdatePage {
@@ -62,7 +102,7 @@ pub struct UpdatePage {
     pub content: String,
     /// The version number of this page
     pub version: Option<i32>,
-    /// The id of the parent page
+    /// The id of the parent page, see `Session::move_page` to move a page without storing it
     pub parent_id: Option<i64>,
 }
 
@@ -125,3 +165,22 @@ impl From<Page> for UpdatePage {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn positions_use_remote_names() {
+        let names = [
+            Position::Above,
+            Position::Below,
+            Position::Append,
+            Position::TopLevel,
+        ]
+        .iter()
+        .map(|position| position.as_str())
+        .collect::<Vec<_>>();
+        assert_eq!(names, vec!["above", "below", "append", "topLevel"]);
+    }
+}
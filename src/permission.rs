@@ -0,0 +1,201 @@
+/// Permission of a user or group in a space.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Permission {
+    /// View the space and its content
+    View,
+    /// Create and edit pages
+    EditPage,
+    /// Remove pages
+    RemovePage,
+    /// Create and edit blog posts
+    EditBlog,
+    /// Remove blog posts
+    RemoveBlog,
+    /// Add comments
+    Comment,
+    /// Remove comments
+    RemoveComment,
+    /// Add attachments
+    CreateAttachment,
+    /// Remove attachments
+    RemoveAttachment,
+    /// Remove mail archived in the space
+    RemoveMail,
+    /// Export the space
+    Export,
+    /// Restrict viewing and editing of pages
+    SetPagePermissions,
+    /// Administer the space, including its permissions
+    Administer,
+    /// Permission not known to this library
+    Other(String),
+}
+
+impl Permission {
+    /// Permission name used by the remote API.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Permission::View => "VIEWSPACE",
+            Permission::EditPage => "EDITSPACE",
+            Permission::RemovePage => "REMOVEPAGE",
+            Permission::EditBlog => "EDITBLOG",
+            Permission::RemoveBlog => "REMOVEBLOG",
+            Permission::Comment => "COMMENT",
+            Permission::RemoveComment => "REMOVECOMMENT",
+            Permission::CreateAttachment => "CREATEATTACHMENT",
+            Permission::RemoveAttachment => "REMOVEATTACHMENT",
+            Permission::RemoveMail => "REMOVEMAIL",
+            Permission::Export => "EXPORTSPACE",
+            Permission::SetPagePermissions => "SETPAGEPERMISSIONS",
+            Permission::Administer => "SETSPACEPERMISSIONS",
+            Permission::Other(ref name) => name,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Permission {
+    fn from(other: &'a str) -> Permission {
+        match other {
+            "VIEWSPACE" => Permission::View,
+            "EDITSPACE" => Permission::EditPage,
+            "REMOVEPAGE" => Permission::RemovePage,
+            "EDITBLOG" => Permission::EditBlog,
+            "REMOVEBLOG" => Permission::RemoveBlog,
+            "COMMENT" => Permission::Comment,
+            "REMOVECOMMENT" => Permission::RemoveComment,
+            "CREATEATTACHMENT" => Permission::CreateAttachment,
+            "REMOVEATTACHMENT" => Permission::RemoveAttachment,
+            "REMOVEMAIL" => Permission::RemoveMail,
+            "EXPORTSPACE" => Permission::Export,
+            "SETPAGEPERMISSIONS" => Permission::SetPagePermissions,
+            "SETSPACEPERMISSIONS" => Permission::Administer,
+            other => Permission::Other(other.into()),
+        }
+    }
+}
+
+/// Kind of restriction of a page or blog post.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContentPermissionType {
+    /// Only the listed users and groups can view the content
+    View,
+    /// Only the listed users and groups can edit the content
+    Edit,
+    /// Type not known to this library
+    Other(String),
+}
+
+impl ContentPermissionType {
+    /// Type name used by the remote API.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContentPermissionType::View => "View",
+            ContentPermissionType::Edit => "Edit",
+            ContentPermissionType::Other(ref name) => name,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ContentPermissionType {
+    fn from(other: &'a str) -> ContentPermissionType {
+        match other {
+            "View" => ContentPermissionType::View,
+            "Edit" => ContentPermissionType::Edit,
+            other => ContentPermissionType::Other(other.into()),
+        }
+    }
+}
+
+/// Restriction of a page or blog post to a user or group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContentPermission {
+    /// Kind of the restriction
+    pub permission_type: ContentPermissionType,
+    /// Username of the user allowed by this restriction
+    pub user_name: Option<String>,
+    /// Name of the group allowed by this restriction
+    pub group_name: Option<String>,
+}
+
+impl ContentPermission {
+    /// Restriction to the user.
+    pub fn user(permission_type: ContentPermissionType, user_name: &str) -> ContentPermission {
+        ContentPermission {
+            permission_type,
+            user_name: Some(user_name.into()),
+            group_name: None,
+        }
+    }
+
+    /// Restriction to the members of the group.
+    pub fn group(permission_type: ContentPermissionType, group_name: &str) -> ContentPermission {
+        ContentPermission {
+            permission_type,
+            user_name: None,
+            group_name: Some(group_name.into()),
+        }
+    }
+}
+
+/// All restrictions of one kind of a page or blog post.
+#[derive(Debug)]
+pub struct ContentPermissionSet {
+    /// Kind of the restrictions
+    pub permission_type: ContentPermissionType,
+    /// Users and groups allowed by the restrictions
+    pub permissions: Vec<ContentPermission>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn permission_round_trips_through_its_name() {
+        let permissions = vec![
+            Permission::View,
+            Permission::EditPage,
+            Permission::RemovePage,
+            Permission::EditBlog,
+            Permission::RemoveBlog,
+            Permission::Comment,
+            Permission::RemoveComment,
+            Permission::CreateAttachment,
+            Permission::RemoveAttachment,
+            Permission::RemoveMail,
+            Permission::Export,
+            Permission::SetPagePermissions,
+            Permission::Administer,
+            Permission::Other("CUSTOMPERMISSION".into()),
+        ];
+        for permission in permissions {
+            assert_eq!(Permission::from(permission.as_str()), permission);
+        }
+        assert_eq!(Permission::Administer.as_str(), "SETSPACEPERMISSIONS");
+        assert_eq!(Permission::EditPage.as_str(), "EDITSPACE");
+    }
+
+    #[test]
+    fn content_permission_type_round_trips_through_its_name() {
+        let types = vec![
+            ContentPermissionType::View,
+            ContentPermissionType::Edit,
+            ContentPermissionType::Other("Share".into()),
+        ];
+        for permission_type in types {
+            assert_eq!(ContentPermissionType::from(permission_type.as_str()), permission_type);
+        }
+        assert_eq!(
+            ContentPermissionType::from("edit"),
+            ContentPermissionType::Other("edit".into())
+        );
+    }
+
+    #[test]
+    fn content_permission_is_for_user_or_group() {
+        let user = ContentPermission::user(ContentPermissionType::View, "bot");
+        assert_eq!((user.user_name, user.group_name), (Some("bot".into()), None));
+        let group = ContentPermission::group(ContentPermissionType::Edit, "writers");
+        assert_eq!((group.user_name, group.group_name), (None, Some("writers".into())));
+    }
+}
@@ -0,0 +1,62 @@
+/**
+Options of the server-side rendering of content.
+
+By default, content is rendered as a whole HTML page, like it is displayed online.
+
+## Example
+
+```no_run
+use confluence::RenderOptions;
+
+# let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+let html = session.render_content(
+    "DOCS",
+    65601,
+    "<p>Preview of the <strong>new</strong> content</p>",
+    &RenderOptions::new().with_clean_style(),
+).unwrap();
+```
+*/
+#[derive(Debug, Clone, Default)]
+pub struct RenderOptions {
+    /// Render only the content, without the surrounding page (`style=clean`)
+    pub clean: bool,
+}
+
+impl RenderOptions {
+    /// Options that render the whole page.
+    pub fn new() -> RenderOptions {
+        RenderOptions::default()
+    }
+
+    /// Render only the content, without the surrounding page.
+    pub fn with_clean_style(mut self) -> Self {
+        self.clean = true;
+        self
+    }
+
+    /// Entries of the parameter map sent to the remote API.
+    pub(crate) fn entries(&self) -> Vec<(&str, &str)> {
+        let mut entries = vec![];
+        if self.clean {
+            entries.push(("style", "clean"));
+        }
+        entries
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn whole_page_is_rendered_by_default() {
+        assert!(RenderOptions::new().entries().is_empty());
+    }
+
+    #[test]
+    fn clean_style_is_sent_as_map_entry() {
+        let options = RenderOptions::new().with_clean_style();
+        assert_eq!(options.entries(), vec![("style", "clean")]);
+    }
+}
@@ -0,0 +1,223 @@
+use crate::http::StatusCode;
+use crate::Error;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// Prefixes of method names that only read data, so they are safe to retry.
+const READ_METHOD_PREFIXES: &[&str] = &["get", "has", "is", "search", "render", "convert"];
+
+/**
+Policy for retrying calls that failed with a transient error.
+
+Connection failures and timeouts are always considered transient. In addition,
+the policy lists HTTP statuses of non-SOAP responses (such as `503` from a proxy)
+and SOAP faults that should be retried.
+
+By default, only read methods (`get*`, `has*`, `is*`, `search*`, ...) and `login`
+are retried. Writes like `storePage` are retried only if added with `with_write`.
+
+## Example
+
+```no_run
+use std::time::Duration;
+use confluence::RetryPolicy;
+
+let session = confluence::SessionBuilder::new()
+    .retry_policy(
+        RetryPolicy::default()
+            .with_max_attempts(5)
+            .with_initial_backoff(Duration::from_secs(1))
+            .with_write("storePage")
+    )
+    .login("https://confluence", "user", "pass")
+    .unwrap();
+```
+*/
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first one
+    pub max_attempts: u32,
+    /// Delay before the first retry
+    pub initial_backoff: Duration,
+    /// Upper bound of the delay between attempts
+    pub max_backoff: Duration,
+    /// Factor by which the delay grows after every attempt
+    pub multiplier: f64,
+    /// Fraction of the delay that is randomized, from `0.0` to `1.0`
+    pub jitter: f64,
+    /// Statuses of HTTP responses without SOAP envelope that are transient
+    pub transient_statuses: Vec<StatusCode>,
+    /// SOAP fault codes or remote exception names that are transient
+    pub transient_faults: Vec<String>,
+    /// Write methods that are retried too
+    pub retried_writes: Vec<String>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(10),
+            multiplier: 2.0,
+            jitter: 0.2,
+            transient_statuses: vec![
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+            transient_faults: vec![],
+            retried_writes: vec![],
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Policy that never retries.
+    pub fn none() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 1,
+            ..RetryPolicy::default()
+        }
+    }
+
+    /// Set maximum number of attempts, including the first one.
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Set delay before the first retry.
+    pub fn with_initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Set upper bound of the delay between attempts.
+    pub fn with_max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Set fraction of the delay that is randomized.
+    pub fn with_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Consider HTTP status transient.
+    pub fn with_transient_status(mut self, status: StatusCode) -> Self {
+        self.transient_statuses.push(status);
+        self
+    }
+
+    /// Consider SOAP fault code (e.g. `soapenv:Server`) or exception name transient.
+    pub fn with_transient_fault<S: Into<String>>(mut self, fault: S) -> Self {
+        self.transient_faults.push(fault.into());
+        self
+    }
+
+    /// Retry specified write method (e.g. `storePage`) too.
+    pub fn with_write<S: Into<String>>(mut self, method: S) -> Self {
+        self.retried_writes.push(method.into());
+        self
+    }
+
+    /// Check if the method can be retried by this policy.
+    pub fn retries_method(&self, method: &str) -> bool {
+        method == "login"
+            || READ_METHOD_PREFIXES
+                .iter()
+                .any(|prefix| method.starts_with(prefix))
+            || self.retried_writes.iter().any(|write| write == method)
+    }
+
+    /// Check if the error is transient.
+    pub fn is_transient(&self, error: &Error) -> bool {
+        match *error {
+            Error::Http(ref e) => e.is_timeout() || e.is_connect() || e.is_request() || e.is_body(),
+            Error::Io(_) => true,
+            Error::HttpStatus(status) => self.transient_statuses.contains(&status),
+            Error::Rpc(ref e) => match **e {
+                crate::rpser::RpcError::Fault { ref fault_code, .. } => {
+                    self.transient_faults.iter().any(|fault| {
+                        fault == fault_code || Some(fault.as_str()) == e.exception_name()
+                    })
+                }
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Delay before the retry that follows the failed `attempt` (starting from 1).
+    ///
+    /// A negative or not-a-number delay, caused by a negative `multiplier` or
+    /// a not-a-number `jitter`, means retrying without delay.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let delay = (self.initial_backoff.as_secs_f64() * self.multiplier.powi(exponent))
+            .min(self.max_backoff.as_secs_f64());
+        let jitter = self.jitter.clamp(0.0, 1.0) * random_fraction();
+        let delay = delay * (1.0 - jitter);
+
+        if delay.is_finite() && delay > 0.0 {
+            Duration::from_secs_f64(delay)
+        } else {
+            Duration::from_secs(0)
+        }
+    }
+}
+
+/// Random number from `0.0` to `1.0`, good enough for jitter.
+fn random_fraction() -> f64 {
+    let random = RandomState::new().build_hasher().finish();
+    (random >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn only_reads_are_retried_by_default() {
+        let policy = RetryPolicy::default();
+        assert!(policy.retries_method("getPage"));
+        assert!(policy.retries_method("login"));
+        assert!(!policy.retries_method("storePage"));
+        assert!(policy.with_write("storePage").retries_method("storePage"));
+    }
+
+    #[test]
+    fn backoff_grows_up_to_max() {
+        let policy = RetryPolicy::default()
+            .with_initial_backoff(Duration::from_millis(100))
+            .with_max_backoff(Duration::from_millis(400))
+            .with_jitter(0.0);
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+        assert_eq!(policy.backoff(4), Duration::from_millis(400));
+    }
+
+    #[test]
+    fn invalid_factors_do_not_panic() {
+        let negative = RetryPolicy {
+            multiplier: -2.0,
+            ..RetryPolicy::default()
+        };
+        assert_eq!(negative.backoff(2), Duration::from_secs(0));
+
+        let not_a_number = RetryPolicy {
+            multiplier: f64::NAN,
+            ..RetryPolicy::default()
+        };
+        assert!(not_a_number.backoff(2) <= not_a_number.max_backoff);
+
+        let jitter = RetryPolicy::default().with_jitter(f64::NAN);
+        assert_eq!(jitter.backoff(1), Duration::from_secs(0));
+    }
+}
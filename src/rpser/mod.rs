@@ -61,7 +61,14 @@ impl Response {
     /// Parse response from XML.
     pub fn from_xml(xml: &str) -> Result<Response> {
         let mut bytes = xml.as_bytes();
-        let mut element = Element::parse(&mut bytes).unwrap();
+        let mut element = match Element::parse(&mut bytes) {
+            Ok(element) => element,
+            Err(e) => {
+                return Err(RpcError::MalformedXml {
+                    message: e.to_string(),
+                })
+            }
+        };
 
         if element.name != "Envelope" {
             return Err(RpcError::UnexpectedElement { tag: element.name });
@@ -102,6 +109,9 @@ pub enum RpcError {
     XmlError {
         error: self::xml::Error,
     },
+    MalformedXml {
+        message: String,
+    },
     ExpectedElementText {
         tag: String,
     },
@@ -116,6 +126,49 @@ pub enum RpcError {
     },
 }
 
+impl RpcError {
+    /// Short name of the remote exception that caused the fault, if any.
+    ///
+    /// For example, `InvalidSessionException` for
+    /// `com.atlassian.confluence.rpc.InvalidSessionException`.
+    pub fn exception_name(&self) -> Option<&str> {
+        match *self {
+            RpcError::Fault {
+                ref fault_string,
+                ref fault_detail,
+                ..
+            } => {
+                let class = fault_detail
+                    .children
+                    .iter()
+                    .map(|child| child.name.as_str())
+                    .find(|name| name.starts_with("com.atlassian."))
+                    .or_else(|| fault_string.split(':').next())?;
+                class.rsplit('.').next().filter(|name| !name.is_empty())
+            }
+            _ => None,
+        }
+    }
+
+    /// Message of the remote exception that caused the fault, if any.
+    ///
+    /// For example, `incorrect password` for
+    /// `com.atlassian.confluence.rpc.AuthenticationFailedException: incorrect password`.
+    pub fn fault_message(&self) -> Option<&str> {
+        match *self {
+            RpcError::Fault {
+                ref fault_string, ..
+            } => Some(match fault_string.find(": ") {
+                Some(index) if fault_string[..index].starts_with("com.atlassian.") => {
+                    &fault_string[index + 2..]
+                }
+                _ => fault_string,
+            }),
+            _ => None,
+        }
+    }
+}
+
 impl From<self::xml::Error> for RpcError {
     fn from(other: self::xml::Error) -> RpcError {
         RpcError::XmlError { error: other }
@@ -127,7 +180,7 @@ pub type Result<T> = result::Result<T, RpcError>;
 #[cfg(test)]
 mod test {
     use super::*;
-    use rpser::xml::BuildElement;
+    use crate::rpser::xml::BuildElement;
 
     #[test]
     fn can_deal_with_fault() {
@@ -147,13 +200,15 @@ mod test {
         "#;
 
         match Response::from_xml(faulty_response) {
-            Err(RpcError::Fault {
-                fault_code,
-                fault_string,
+            Err(ref e @ RpcError::Fault {
+                ref fault_code,
+                ref fault_string,
                 ..
             }) => {
                 assert_eq!(fault_code, "soapenv:Server.userException");
                 assert_eq!(fault_string, "com.atlassian.confluence.rpc.AuthenticationFailedException: Attempt to log in user 'ADUser' failed - incorrect username/password combination.");
+                assert_eq!(e.exception_name(), Some("AuthenticationFailedException"));
+                assert_eq!(e.fault_message(), Some("Attempt to log in user 'ADUser' failed - incorrect username/password combination."));
             }
             other => panic!(
                 "expected to receive fault in this test, received {:?}",
@@ -299,7 +299,7 @@ impl BuildElement for Element {
 
 fn get_typed_string(element: &Element, value_type: &str) -> Result<String, Error> {
     Ok(match (element.attributes.get("type"), &element.text) {
-        (Some(value), &Some(ref text)) if value.ends_with(value_type) => text.clone(),
+        (Some(value), Some(text)) if value.ends_with(value_type) => text.clone(),
         (other_type, _) => {
             return Err(Error::ExpectedElementWithType {
                 name: element.name.clone(),
@@ -0,0 +1,232 @@
+/// Content found by search or by label.
+#[derive(Debug)]
+pub struct SearchResult {
+    /// The id of the content
+    pub id: i64,
+    /// The title of the content
+    pub title: String,
+    /// The type of the content, eg. `page`, `blogpost`, `comment` or `attachment`
+    pub content_type: String,
+    /// The url to view this content online
+    pub url: String,
+    /// Short excerpt of the content, if any
+    pub excerpt: Option<String>,
+}
+
+/// Type of content to search for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchType {
+    Page,
+    BlogPost,
+    Comment,
+    Attachment,
+    /// Space descriptions
+    Space,
+    /// User profiles
+    User,
+    /// Type not known to this library
+    Other(String),
+}
+
+impl SearchType {
+    /// Type name used by the remote API.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SearchType::Page => "page",
+            SearchType::BlogPost => "blogpost",
+            SearchType::Comment => "comment",
+            SearchType::Attachment => "attachment",
+            SearchType::Space => "spacedesc",
+            SearchType::User => "userinfo",
+            SearchType::Other(ref name) => name,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SearchType {
+    fn from(other: &'a str) -> SearchType {
+        match other {
+            "page" => SearchType::Page,
+            "blogpost" => SearchType::BlogPost,
+            "comment" => SearchType::Comment,
+            "attachment" => SearchType::Attachment,
+            "spacedesc" => SearchType::Space,
+            "userinfo" => SearchType::User,
+            other => SearchType::Other(other.into()),
+        }
+    }
+}
+
+/// Time window of the last modification of found content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModifiedWithin {
+    Today,
+    Yesterday,
+    LastWeek,
+    LastMonth,
+}
+
+impl ModifiedWithin {
+    /// Window name used by the remote API.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ModifiedWithin::Today => "TODAY",
+            ModifiedWithin::Yesterday => "YESTERDAY",
+            ModifiedWithin::LastWeek => "LASTWEEK",
+            ModifiedWithin::LastMonth => "LASTMONTH",
+        }
+    }
+}
+
+/**
+Parameters of the full-text search.
+
+By default, all content is searched and at most 100 results are returned.
+
+## Example
+
+```no_run
+use confluence::{ModifiedWithin, SearchParams, SearchType};
+
+# let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+let results = session.search(
+    "release notes",
+    &SearchParams::new()
+        .with_space_key("DOCS")
+        .with_type(SearchType::Page)
+        .with_modified(ModifiedWithin::LastWeek)
+        .with_max_results(20)
+).unwrap();
+```
+*/
+#[derive(Debug, Clone)]
+pub struct SearchParams {
+    /// Key of the space to search in
+    pub space_key: Option<String>,
+    /// Type of content to search for
+    pub content_type: Option<SearchType>,
+    /// Time window of the last modification
+    pub modified: Option<ModifiedWithin>,
+    /// Username of a contributor of the content
+    pub contributor: Option<String>,
+    /// Maximum number of results
+    pub max_results: i32,
+}
+
+impl Default for SearchParams {
+    fn default() -> SearchParams {
+        SearchParams {
+            space_key: None,
+            content_type: None,
+            modified: None,
+            contributor: None,
+            max_results: 100,
+        }
+    }
+}
+
+impl SearchParams {
+    /// Parameters that search all content.
+    pub fn new() -> SearchParams {
+        SearchParams::default()
+    }
+
+    /// Search only in the space.
+    pub fn with_space_key<S: Into<String>>(mut self, space_key: S) -> Self {
+        self.space_key = Some(space_key.into());
+        self
+    }
+
+    /// Search only for content of the type.
+    pub fn with_type(mut self, content_type: SearchType) -> Self {
+        self.content_type = Some(content_type);
+        self
+    }
+
+    /// Search only for content modified within the window.
+    pub fn with_modified(mut self, modified: ModifiedWithin) -> Self {
+        self.modified = Some(modified);
+        self
+    }
+
+    /// Search only for content the user contributed to.
+    pub fn with_contributor<S: Into<String>>(mut self, contributor: S) -> Self {
+        self.contributor = Some(contributor.into());
+        self
+    }
+
+    /// Set maximum number of results.
+    pub fn with_max_results(mut self, max_results: i32) -> Self {
+        self.max_results = max_results;
+        self
+    }
+
+    /// Entries of the parameter map sent to the remote API.
+    pub(crate) fn entries(&self) -> Vec<(&str, &str)> {
+        let mut entries = vec![];
+        if let Some(ref space_key) = self.space_key {
+            entries.push(("spaceKey", space_key.as_str()));
+        }
+        if let Some(ref content_type) = self.content_type {
+            entries.push(("type", content_type.as_str()));
+        }
+        if let Some(ref modified) = self.modified {
+            entries.push(("modified", modified.as_str()));
+        }
+        if let Some(ref contributor) = self.contributor {
+            entries.push(("contributor", contributor.as_str()));
+        }
+        entries
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn search_type_round_trips_through_its_name() {
+        let types = vec![
+            SearchType::Page,
+            SearchType::BlogPost,
+            SearchType::Comment,
+            SearchType::Attachment,
+            SearchType::Space,
+            SearchType::User,
+            SearchType::Other("mail".into()),
+        ];
+        for content_type in types {
+            assert_eq!(SearchType::from(content_type.as_str()), content_type);
+        }
+        assert_eq!(SearchType::Space.as_str(), "spacedesc");
+        assert_eq!(SearchType::User.as_str(), "userinfo");
+    }
+
+    #[test]
+    fn default_params_have_no_entries() {
+        let params = SearchParams::new();
+        assert!(params.entries().is_empty());
+        assert_eq!(params.max_results, 100);
+    }
+
+    #[test]
+    fn params_are_sent_as_map_entries() {
+        let params = SearchParams::new()
+            .with_space_key("DOCS")
+            .with_type(SearchType::BlogPost)
+            .with_modified(ModifiedWithin::LastWeek)
+            .with_contributor("user")
+            .with_max_results(5);
+
+        assert_eq!(
+            params.entries(),
+            vec![
+                ("spaceKey", "DOCS"),
+                ("type", "blogpost"),
+                ("modified", "LASTWEEK"),
+                ("contributor", "user"),
+            ]
+        );
+        assert_eq!(params.max_results, 5);
+    }
+}
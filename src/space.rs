@@ -16,3 +16,101 @@ pub struct Space {
     /// The HTML rendered space description
     pub description: Option<String>,
 }
+
+/// Space Summary.
+#[derive(Debug)]
+pub struct SpaceSummary {
+    /// The space key
+    pub key: String,
+    /// The name of the space
+    pub name: String,
+    /// Space type
+    pub space_type: String,
+    /// The url to view this space online
+    pub url: String,
+}
+
+/// Space Object for creating or editing a Space.
+#[derive(Debug)]
+pub struct UpdateSpace {
+    /// The space key
+    pub key: String,
+    /// The name of the space
+    pub name: String,
+    /// The space description
+    pub description: Option<String>,
+    /// The id of the space homepage, kept when editing the space
+    pub home_page: Option<i64>,
+}
+
+impl UpdateSpace {
+    pub fn with_create_fields(key: &str, name: &str, description: Option<&str>) -> UpdateSpace {
+        UpdateSpace {
+            key: key.into(),
+            name: name.into(),
+            description: description.map(String::from),
+            home_page: None,
+        }
+    }
+}
+
+impl From<Space> for UpdateSpace {
+    fn from(other: Space) -> UpdateSpace {
+        UpdateSpace {
+            key: other.key,
+            name: other.name,
+            description: other.description,
+            home_page: Some(other.home_page),
+        }
+    }
+}
+
+/// Status of a space.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpaceStatus {
+    /// The space is in use
+    Current,
+    /// The space is archived and hidden from most listings
+    Archived,
+    /// Status not known to this library
+    Other(String),
+}
+
+impl SpaceStatus {
+    /// Status name used by the remote API.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SpaceStatus::Current => "CURRENT",
+            SpaceStatus::Archived => "ARCHIVED",
+            SpaceStatus::Other(ref status) => status,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SpaceStatus {
+    fn from(other: &'a str) -> SpaceStatus {
+        match other {
+            "CURRENT" => SpaceStatus::Current,
+            "ARCHIVED" => SpaceStatus::Archived,
+            other => SpaceStatus::Other(other.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn space_status_round_trips_through_its_name() {
+        let statuses = vec![
+            SpaceStatus::Current,
+            SpaceStatus::Archived,
+            SpaceStatus::Other("DRAFT".into()),
+        ];
+        for status in statuses {
+            assert_eq!(SpaceStatus::from(status.as_str()), status);
+        }
+        assert_eq!(SpaceStatus::from("current"), SpaceStatus::Other("current".into()));
+    }
+}
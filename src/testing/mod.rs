@@ -0,0 +1,1554 @@
+/*!
+In-memory fake of the Confluence SOAP service, for offline tests.
+
+`FakeServer` binds to a localhost port and implements a stateful subset of the
+`confluenceservice-v2` endpoint, so a `Session` can run realistic end-to-end
+scenarios against it.
+
+Enable the `testing` cargo feature to use this module.
+
+## Example
+
+```
+use confluence::testing::FakeServer;
+use confluence::UpdatePage;
+
+let server = FakeServer::start().unwrap();
+server.add_user("user", "pass");
+server.add_space("KEY", "Some Space");
+
+let session = confluence::Session::login(server.url(), "user", "pass").unwrap();
+let page = session
+    .store_page(UpdatePage::with_create_fields(None, "KEY", "Title", "<p>Hello</p>"))
+    .unwrap();
+
+assert_eq!(server.page(page.id).unwrap().content, "<p>Hello</p>");
+```
+*/
+
+mod service;
+
+pub use self::service::{
+    FakeAttachment, FakeBlogEntry, FakeComment, FakeContentPermission, FakePage, FakePageVersion,
+    FakeSpace, FakeUser,
+};
+
+use self::service::{Fault, State, ENDPOINT_PATH, OPERATIONS};
+use crate::http::StatusCode;
+use crate::rpser::xml::BuildElement;
+use crate::transport::wsdl_with_operations;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+use xmltree::Element;
+
+/// Fake Confluence server listening on localhost.
+///
+/// The server stops when this value is dropped.
+pub struct FakeServer {
+    url: String,
+    state: Arc<Mutex<State>>,
+    shutdown: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl FakeServer {
+    /// Start the server on a free localhost port.
+    pub fn start() -> io::Result<FakeServer> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let url = format!("http://{}", listener.local_addr()?);
+
+        let state = Arc::new(Mutex::new(State::new(url.clone())));
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        let thread = {
+            let state = state.clone();
+            let shutdown = shutdown.clone();
+            thread::spawn(move || serve(&listener, &state, &shutdown))
+        };
+
+        debug!("fake confluence listening at {}", url);
+
+        Ok(FakeServer {
+            url,
+            state,
+            shutdown,
+            thread: Some(thread),
+        })
+    }
+
+    /// Base url of the server, to be passed to `Session::login`.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Allow user to log in with specified password.
+    pub fn add_user(&self, user: &str, pass: &str) {
+        let email = format!("{}@example.com", user);
+        self.state().add_user(user, user, &email, pass);
+    }
+
+    /// Create space together with its home page.
+    pub fn add_space(&self, key: &str, name: &str) {
+        self.state().add_space(key, name);
+    }
+
+    /// Add label to the space, as the remote API can only do with a space-specific operation.
+    ///
+    /// Panics if the space does not exist.
+    pub fn add_space_label(&self, key: &str, label: &str) {
+        let mut state = self.state();
+        let space = state.spaces.get_mut(key).expect("space exists");
+        space.labels.push(label.to_lowercase());
+    }
+
+    /// Take permission in the space from the user, eg. `REMOVEPAGE`.
+    ///
+    /// Panics if the space does not exist.
+    pub fn deny_permission(&self, user: &str, key: &str, permission: &str) {
+        let mut state = self.state();
+        let space = state.spaces.get_mut(key).expect("space exists");
+        space
+            .denied_permissions
+            .push((user.into(), permission.into()));
+    }
+
+    /// Create page and return its id.
+    ///
+    /// Panics if the space does not exist.
+    pub fn add_page(&self, space: &str, parent_id: Option<i64>, title: &str, content: &str) -> i64 {
+        let mut state = self.state();
+        assert!(
+            state.spaces.contains_key(space),
+            "space {:?} does not exist",
+            space
+        );
+        state
+            .create_page(space, parent_id.unwrap_or(0), title, content, "admin")
+            .id
+    }
+
+    /// Get a copy of the stored user.
+    pub fn user(&self, name: &str) -> Option<FakeUser> {
+        self.state().users.get(name).cloned()
+    }
+
+    /// Get a copy of the stored space.
+    pub fn space(&self, key: &str) -> Option<FakeSpace> {
+        self.state().spaces.get(key).cloned()
+    }
+
+    /// Get a copy of the stored page.
+    pub fn page(&self, id: i64) -> Option<FakePage> {
+        self.state().pages.get(&id).cloned()
+    }
+
+    /// Get a copy of the stored attachment by page id and file name.
+    pub fn attachment(&self, page_id: i64, file_name: &str) -> Option<FakeAttachment> {
+        self.state()
+            .attachments
+            .values()
+            .find(|attachment| attachment.page_id == page_id && attachment.file_name == file_name)
+            .cloned()
+    }
+
+    /// Get a copy of the stored blog entry.
+    pub fn blog_entry(&self, id: i64) -> Option<FakeBlogEntry> {
+        self.state().blog_entries.get(&id).cloned()
+    }
+
+    /// Names of the labels of the page or blog entry.
+    pub fn labels(&self, id: i64) -> Vec<String> {
+        self.state().labels.get(&id).cloned().unwrap_or_default()
+    }
+
+    /// Restrictions of the page or blog entry.
+    pub fn content_permissions(&self, id: i64) -> Vec<FakeContentPermission> {
+        self.state()
+            .content_permissions
+            .get(&id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Get a copy of the stored comment.
+    pub fn comment(&self, id: i64) -> Option<FakeComment> {
+        self.state().comments.get(&id).cloned()
+    }
+
+    /// Invalidate all session tokens, as if they had timed out.
+    pub fn expire_sessions(&self) {
+        self.state().expire_sessions();
+    }
+
+    /// Reply to the next `count` SOAP calls with specified HTTP status and no envelope,
+    /// like an overloaded proxy would.
+    pub fn fail_next_calls(&self, count: usize, status: StatusCode) {
+        let mut state = self.state();
+        state.failures.clear();
+        state.failures.extend((0..count).map(|_| status));
+    }
+
+    /// Reply to the next SOAP call with a `RemoteException` fault with the message,
+    /// whatever the call is.
+    pub fn fault_next_call(&self, message: &str) {
+        self.state().faults.push_back(message.into());
+    }
+
+    /// Change build id reported by `getServerInfo`, as if the server was upgraded.
+    pub fn set_build_id(&self, build_id: &str) {
+        self.state().build_id = build_id.into();
+    }
+
+    /// Set how long to wait for a client to send the rest of its request (5 seconds by default).
+    ///
+    /// A request that is not received in time is answered with `400 Bad Request`.
+    pub fn set_read_timeout(&self, timeout: Duration) {
+        self.state().read_timeout = timeout;
+    }
+
+    /// Number of times the WSDL was requested.
+    pub fn wsdl_requests(&self) -> usize {
+        self.state().wsdl_requests
+    }
+
+    /// Names of the operations called so far, in order.
+    pub fn operations(&self) -> Vec<String> {
+        self.state().operations.clone()
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap()
+    }
+}
+
+impl Drop for FakeServer {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        // wake up the accept loop
+        let _ = TcpStream::connect(&self.url["http://".len()..]);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Simplified HTTP request representation.
+struct Request {
+    method: String,
+    path: String,
+    body: String,
+}
+
+fn serve(listener: &TcpListener, state: &Mutex<State>, shutdown: &AtomicBool) {
+    for stream in listener.incoming() {
+        if shutdown.load(Ordering::SeqCst) {
+            break;
+        }
+        let result = stream.and_then(|stream| handle_connection(&stream, state));
+        if let Err(e) = result {
+            warn!("fake confluence connection failed: {}", e);
+        }
+    }
+}
+
+fn handle_connection(stream: &TcpStream, state: &Mutex<State>) -> io::Result<()> {
+    let read_timeout = state.lock().unwrap().read_timeout;
+    stream.set_read_timeout(Some(read_timeout))?;
+
+    // connections are served one at a time, so a stalled client must not block the rest
+    let (status, body) = match read_request(stream) {
+        Ok(request) => respond(&request, &mut state.lock().unwrap()),
+        Err(e) => {
+            warn!("fake confluence could not read request: {}", e);
+            (StatusCode::BAD_REQUEST, String::new())
+        }
+    };
+
+    let mut stream = stream;
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: text/xml; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        status.as_u16(),
+        status.canonical_reason().unwrap_or_default(),
+        body.len()
+    )?;
+    stream.write_all(body.as_bytes())?;
+    stream.flush()
+}
+
+fn read_request(stream: &TcpStream) -> io::Result<Request> {
+    let mut reader = BufReader::new(stream);
+
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let mut parts = line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let path = parts.next().unwrap_or_default().to_string();
+
+    let mut content_length = 0;
+    let mut chunked = false;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 {
+            break;
+        }
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let mut name_value = header.splitn(2, ':');
+        if let (Some(name), Some(value)) = (name_value.next(), name_value.next()) {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap_or(0);
+            } else if name.eq_ignore_ascii_case("transfer-encoding") {
+                chunked = value.trim().eq_ignore_ascii_case("chunked");
+            }
+        }
+    }
+
+    let body = if chunked {
+        read_chunked_body(&mut reader)?
+    } else {
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body)?;
+        body
+    };
+
+    Ok(Request {
+        method,
+        path,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
+
+/// Read body sent with `Transfer-Encoding: chunked`, as streamed uploads are.
+fn read_chunked_body<R: BufRead>(reader: &mut R) -> io::Result<Vec<u8>> {
+    let mut body = vec![];
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        let size = line.split(';').next().unwrap_or_default().trim();
+        let size = usize::from_str_radix(size, 16)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "invalid chunk size"))?;
+
+        if size == 0 {
+            // skip trailers up to the final empty line
+            loop {
+                line.clear();
+                if reader.read_line(&mut line)? == 0 || line.trim_end().is_empty() {
+                    return Ok(body);
+                }
+            }
+        }
+
+        let start = body.len();
+        body.resize(start + size, 0);
+        reader.read_exact(&mut body[start..])?;
+        reader.read_line(&mut line)?;
+    }
+}
+
+fn respond(request: &Request, state: &mut State) -> (StatusCode, String) {
+    let wsdl_path = [ENDPOINT_PATH, "?wsdl"].concat();
+
+    match (request.method.as_str(), request.path.as_str()) {
+        ("GET", path) if path == wsdl_path => {
+            state.wsdl_requests += 1;
+            (StatusCode::OK, wsdl_with_operations(&state.endpoint(), OPERATIONS))
+        }
+        ("POST", ENDPOINT_PATH) => {
+            if let Some(status) = state.failures.pop_front() {
+                return (status, String::new());
+            }
+            if let Some(message) = state.faults.pop_front() {
+                let fault = Fault::remote(message);
+                return (StatusCode::INTERNAL_SERVER_ERROR, fault_envelope(&fault));
+            }
+            match soap_call(&request.body, state) {
+                Ok(body) => (StatusCode::OK, body),
+                Err(fault) => (StatusCode::INTERNAL_SERVER_ERROR, fault_envelope(&fault)),
+            }
+        }
+        _ => (StatusCode::NOT_FOUND, String::new()),
+    }
+}
+
+fn soap_call(body: &str, state: &mut State) -> Result<String, Fault> {
+    let malformed = |message: String| Fault {
+        exception: "RemoteException",
+        message: format!("Malformed request: {}", message),
+    };
+
+    let mut bytes = body.as_bytes();
+    let request = Element::parse(&mut bytes).map_err(|e| malformed(e.to_string()))?;
+    let call = request
+        .descend(&["Body"])
+        .and_then(|body| body.descend_first())
+        .map_err(|e| malformed(format!("{:?}", e)))?;
+
+    let result = state.handle(&call.name, &call.children)?;
+
+    Ok(envelope(
+        Element::node(format!("ns1:{}Response", call.name))
+            .with_attr("xmlns:ns1", state.endpoint())
+            .with_attr(
+                "soapenv:encodingStyle",
+                "http://schemas.xmlsoap.org/soap/encoding/",
+            )
+            .with_child(result),
+    ))
+}
+
+fn fault_envelope(fault: &Fault) -> String {
+    envelope(Element::node("soapenv:Fault").with_children(vec![
+        Element::node("faultcode").with_text("soapenv:Server.userException"),
+        Element::node("faultstring").with_text(format!(
+            "com.atlassian.confluence.rpc.{}: {}",
+            fault.exception, fault.message
+        )),
+        Element::node("detail").with_children(vec![
+            Element::node(format!("com.atlassian.confluence.rpc.{}", fault.exception))
+                .with_attr("xsi:type", format!("ns1:{}", fault.exception))
+                .with_attr("xmlns:ns1", "http://rpc.confluence.atlassian.com"),
+            Element::node("ns2:hostname")
+                .with_attr("xmlns:ns2", "http://xml.apache.org/axis/")
+                .with_text("localhost"),
+        ]),
+    ]))
+}
+
+fn envelope(body: Element) -> String {
+    Element::node("soapenv:Envelope")
+        .with_attr("xmlns:soapenv", "http://schemas.xmlsoap.org/soap/envelope/")
+        .with_attr("xmlns:soapenc", "http://schemas.xmlsoap.org/soap/encoding/")
+        .with_attr("xmlns:xsd", "http://www.w3.org/2001/XMLSchema")
+        .with_attr("xmlns:xsi", "http://www.w3.org/2001/XMLSchema-instance")
+        .with_attr("xmlns:tns2", "http://beans.soap.rpc.confluence.atlassian.com")
+        .with_child(Element::node("soapenv:Body").with_child(body))
+        .to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::generated;
+    use crate::rpser::RpcError;
+    use crate::wsdl::WsdlSource;
+    use crate::{
+        AttachmentVersion, ContentPermission, ContentPermissionType, Error, ModifiedWithin,
+        NewComment, PageSummary, PageUpdateOptions, Permission, Position, RenderOptions,
+        RetryPolicy, SearchParams, SearchType, Session, SessionBuilder, SpaceStatus,
+        UpdateBlogEntry, UpdatePage, UpdateSpace, UpdateUser, UploadAttachment,
+    };
+    use chrono::{Datelike, TimeZone, Utc};
+    use std::fs;
+
+    fn server() -> FakeServer {
+        let server = FakeServer::start().unwrap();
+        server.add_user("user", "pass");
+        server.add_space("KEY", "Some Space");
+        server
+    }
+
+    /// Assert that the call failed with a fault caused by the remote exception.
+    fn assert_fault<T: std::fmt::Debug>(result: crate::Result<T>, exception: &str) {
+        match result {
+            Err(Error::Rpc(ref e)) => assert_eq!(e.exception_name(), Some(exception)),
+            other => panic!("expected {}, received {:?}", exception, other),
+        }
+    }
+
+    #[test]
+    fn pages_can_be_created_updated_and_listed() {
+        let server = server();
+        let session = Session::login(server.url(), "user", "pass").unwrap();
+
+        let home_page = session.get_space("KEY").unwrap().home_page;
+        let parent = session
+            .store_page(UpdatePage::with_create_fields(Some(home_page), "KEY", "Parent", "a"))
+            .unwrap();
+        let child = session
+            .store_page(UpdatePage::with_create_fields(Some(parent.id), "KEY", "Child", "b"))
+            .unwrap();
+        assert_eq!(child.version, 1);
+        assert_eq!(child.creator, "user");
+
+        let mut page = session.get_page_by_title("KEY", "Child").unwrap();
+        page.content = "c".into();
+        let updated = session
+            .update_page(page.into(), PageUpdateOptions::new_minor_with_comment("edit"))
+            .unwrap();
+        assert_eq!(updated.version, 2);
+        assert_eq!(server.page(child.id).unwrap().version_comment, Some("edit".into()));
+        assert_eq!(session.get_page_by_id(child.id).unwrap().content, "c");
+
+        let children = session.get_children(parent.id).unwrap();
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].title, "Child");
+        assert_eq!(session.get_descendents(home_page).unwrap().len(), 2);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn async_session_calls_the_server() {
+        let server = server();
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+
+        runtime.block_on(async {
+            let session = crate::AsyncSession::login(server.url(), "user", "pass")
+                .await
+                .unwrap();
+            assert_eq!(session.server_info().unwrap().major_version, 6);
+
+            let parent = session
+                .store_page(UpdatePage::with_create_fields(None, "KEY", "Parent", "a"))
+                .await
+                .unwrap();
+            let child = session
+                .store_page(UpdatePage::with_create_fields(Some(parent.id), "KEY", "Child", "b"))
+                .await
+                .unwrap();
+            assert_eq!(server.page(child.id).unwrap().content, "b");
+
+            assert_eq!(session.get_page_by_id(child.id).await.unwrap().title, "Child");
+            let children = session.get_children(parent.id).await.unwrap();
+            assert_eq!(children.len(), 1);
+            assert_eq!(children[0].id, child.id);
+
+            match session.get_page_by_id(404).await {
+                Err(Error::Rpc(_)) => {}
+                other => panic!("expected fault, got {:?}", other),
+            }
+
+            assert!(session.logout().await.unwrap());
+        });
+
+        assert_eq!(server.operations().last().unwrap(), "logout");
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn async_login_uses_the_wsdl_source() {
+        let server = server();
+        let dir = std::env::temp_dir().join(format!(
+            "confluence-async-wsdl-cache-{}-{}",
+            std::process::id(),
+            server.url().rsplit(':').next().unwrap()
+        ));
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let login = |source: WsdlSource| {
+            runtime
+                .block_on(SessionBuilder::new().wsdl_source(source).login_async(
+                    server.url(),
+                    "user",
+                    "pass",
+                ))
+                .unwrap()
+        };
+
+        login(WsdlSource::Bundled);
+        assert_eq!(server.wsdl_requests(), 0);
+
+        login(WsdlSource::Cache(dir.clone()));
+        login(WsdlSource::Cache(dir.clone()));
+        assert_eq!(server.wsdl_requests(), 1);
+
+        server.set_build_id("8200");
+        login(WsdlSource::Cache(dir.clone()));
+        let session = login(WsdlSource::Cache(dir.clone()));
+        assert_eq!(server.wsdl_requests(), 2);
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+        assert!(runtime.block_on(session.get_server_info()).is_ok());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn generated_wrappers_call_the_server() {
+        let server = server();
+        let parent_id = server.add_page("KEY", None, "Parent", "a");
+        let session = Session::login(server.url(), "user", "pass").unwrap();
+
+        let page = generated::store_page(
+            &session,
+            &generated::RemotePage {
+                space: Some("KEY".into()),
+                title: Some("Child".into()),
+                content: Some("b".into()),
+                parent_id,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(page.version, 1);
+
+        let by_title =
+            generated::get_page_by_space_key_and_page_title(&session, "KEY", "Child").unwrap();
+        assert_eq!(by_title.id, page.id);
+        assert_eq!(by_title.content, Some("b".into()));
+
+        let children = generated::get_children(&session, parent_id).unwrap();
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].title, Some("Child".into()));
+        assert_eq!(generated::get_space(&session, "KEY").unwrap().name, Some("Some Space".into()));
+    }
+
+    #[test]
+    fn bundled_wsdl_is_used_without_fetching() {
+        let server = server();
+        let page_id = server.add_page("KEY", None, "Page", "a");
+
+        let session = SessionBuilder::new()
+            .wsdl_source(WsdlSource::Bundled)
+            .login(server.url(), "user", "pass")
+            .unwrap();
+
+        assert_eq!(session.get_page_by_id(page_id).unwrap().title, "Page");
+        assert_eq!(server.wsdl_requests(), 0);
+    }
+
+    #[test]
+    fn cached_wsdl_is_fetched_again_when_build_changes() {
+        let server = server();
+        let dir = std::env::temp_dir().join(format!(
+            "confluence-wsdl-cache-{}-{}",
+            std::process::id(),
+            server.url().rsplit(':').next().unwrap()
+        ));
+        let login = || {
+            SessionBuilder::new()
+                .wsdl_source(WsdlSource::Cache(dir.clone()))
+                .login(server.url(), "user", "pass")
+                .unwrap()
+        };
+
+        login();
+        login();
+        assert_eq!(server.wsdl_requests(), 1);
+
+        server.set_build_id("8200");
+        login();
+        let session = login();
+        assert_eq!(server.wsdl_requests(), 2);
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+        assert!(session.get_space("KEY").is_ok());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn spaces_can_be_managed() {
+        let server = server();
+        let session = Session::login(server.url(), "user", "pass").unwrap();
+
+        let docs = session
+            .add_space(UpdateSpace::with_create_fields("DOCS", "Docs", None))
+            .unwrap();
+        assert_eq!(docs.space_type, "global");
+        let personal = session
+            .add_personal_space(UpdateSpace::with_create_fields("", "Mine", None), "user")
+            .unwrap();
+        assert_eq!(personal.key, "~user");
+
+        let keys = session
+            .get_spaces()
+            .unwrap()
+            .into_iter()
+            .map(|space| space.key)
+            .collect::<Vec<_>>();
+        assert_eq!(keys, vec!["DOCS", "KEY", "~user"]);
+
+        let mut edited: UpdateSpace = docs.into();
+        edited.description = Some("Generated".into());
+        session.store_space(edited).unwrap();
+        assert_eq!(session.get_space("DOCS").unwrap().description, Some("Generated".into()));
+
+        assert_eq!(session.get_space_status("DOCS").unwrap(), SpaceStatus::Current);
+        assert!(session.set_space_status("DOCS", &SpaceStatus::Archived).unwrap());
+        assert_eq!(session.get_space_status("DOCS").unwrap(), SpaceStatus::Archived);
+
+        assert!(session.remove_space("DOCS").unwrap());
+        assert!(server.space("DOCS").is_none());
+
+        assert_fault(session.get_space("DOCS"), "RemoteException");
+        assert_fault(session.remove_space("DOCS"), "RemoteException");
+        assert_fault(
+            session.add_space(UpdateSpace::with_create_fields("KEY", "Again", None)),
+            "AlreadyExistsException",
+        );
+    }
+
+    #[test]
+    fn attachments_can_be_managed() {
+        let server = server();
+        let page_id = server.add_page("KEY", None, "Page", "a");
+        let other_id = server.add_page("KEY", None, "Other", "b");
+        let session = Session::login(server.url(), "user", "pass").unwrap();
+
+        let first = session
+            .add_attachment(page_id, UploadAttachment::new("a.bin", "application/octet-stream"), &[0, 1, 2])
+            .unwrap();
+        assert_eq!(first.file_size, 3);
+        assert_eq!(first.comment, None);
+        let second = session
+            .add_attachment(
+                page_id,
+                UploadAttachment::new("a.bin", "application/octet-stream").with_comment("Second"),
+                &[255; 100],
+            )
+            .unwrap();
+        assert_eq!(second.id, first.id);
+        assert_eq!(second.comment, Some("Second".into()));
+
+        let attachments = session.get_attachments(page_id).unwrap();
+        assert_eq!(attachments.len(), 1);
+        assert_eq!(attachments[0].file_size, 100);
+        assert_eq!(
+            session.get_attachment_data(page_id, "a.bin", AttachmentVersion::Latest).unwrap(),
+            vec![255; 100]
+        );
+        assert_eq!(
+            session.get_attachment_data(page_id, "a.bin", AttachmentVersion::Number(1)).unwrap(),
+            vec![0, 1, 2]
+        );
+        assert_eq!(
+            session
+                .get_attachment(page_id, "a.bin", AttachmentVersion::Number(1))
+                .unwrap()
+                .file_size,
+            3
+        );
+        assert_fault(
+            session.get_attachment(page_id, "a.bin", AttachmentVersion::Number(3)),
+            "RemoteException",
+        );
+        assert_fault(
+            session.add_attachment(404, UploadAttachment::new("a.bin", "text/plain"), &[0]),
+            "RemoteException",
+        );
+
+        assert!(session.move_attachment(page_id, "a.bin", page_id, "b.bin").unwrap());
+        assert!(session.move_attachment(page_id, "b.bin", other_id, "c.bin").unwrap());
+        assert!(session.get_attachments(page_id).unwrap().is_empty());
+        assert_eq!(server.attachment(other_id, "c.bin").unwrap().versions.len(), 2);
+
+        assert!(session.remove_attachment(other_id, "c.bin").unwrap());
+        assert!(server.attachment(other_id, "c.bin").is_none());
+    }
+
+    #[test]
+    fn attachments_can_be_streamed() {
+        let server = server();
+        let page_id = server.add_page("KEY", None, "Page", "a");
+        let session = Session::login(server.url(), "user", "pass").unwrap();
+        let data = (0..300_000u32).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let attachment = session
+            .upload_attachment(
+                page_id,
+                UploadAttachment::new("big.bin", "application/octet-stream"),
+                std::io::Cursor::new(data.clone()),
+                move |sent| sender.send(sent).unwrap(),
+            )
+            .unwrap();
+        assert_eq!(attachment.file_size, data.len() as i64);
+        assert_eq!(receiver.try_iter().last(), Some(data.len() as u64));
+        assert_eq!(server.attachment(page_id, "big.bin").unwrap().versions, vec![data.clone()]);
+
+        server.expire_sessions();
+        let mut output = vec![];
+        let mut received = 0;
+        let written = session
+            .download_attachment(
+                page_id,
+                "big.bin",
+                AttachmentVersion::Latest,
+                &mut output,
+                |n| received = n,
+            )
+            .unwrap();
+        assert_eq!(written, data.len() as u64);
+        assert_eq!(received, data.len() as u64);
+        assert_eq!(output, data);
+
+        let missing =
+            session.download_attachment(page_id, "x.bin", AttachmentVersion::Latest, &mut output, |_| {});
+        match missing {
+            Err(Error::Rpc(ref e)) => assert_eq!(e.exception_name(), Some("RemoteException")),
+            other => panic!("expected fault, received {:?}", other),
+        }
+    }
+
+    #[test]
+    fn comments_can_be_threaded() {
+        let server = server();
+        let page_id = server.add_page("KEY", None, "Page", "a");
+        let session = Session::login(server.url(), "user", "pass").unwrap();
+
+        let finding = session
+            .add_comment(NewComment::new(page_id, "<p>Broken link</p>"))
+            .unwrap();
+        assert_eq!(finding.parent_id, 0);
+        assert_eq!(finding.creator, "user");
+        let reply = session
+            .add_comment(NewComment::new(page_id, "<p>Fixed</p>").with_parent(finding.id))
+            .unwrap();
+        assert_eq!(reply.parent_id, finding.id);
+
+        let replies = session
+            .get_comments(page_id)
+            .unwrap()
+            .into_iter()
+            .filter(|comment| comment.parent_id == finding.id)
+            .map(|comment| comment.content)
+            .collect::<Vec<_>>();
+        assert_eq!(replies, vec!["<p>Fixed</p>"]);
+
+        let edited = session.edit_comment(reply.id, "<p>Fixed, thanks</p>").unwrap();
+        assert_eq!(edited.content, "<p>Fixed, thanks</p>");
+        assert_eq!(session.get_comment(reply.id).unwrap().content, "<p>Fixed, thanks</p>");
+
+        assert!(session.remove_comment(finding.id).unwrap());
+        assert!(server.comment(reply.id).is_none());
+        assert!(session.get_comments(page_id).unwrap().is_empty());
+        assert_fault(session.get_comment(reply.id), "RemoteException");
+        assert_fault(session.edit_comment(reply.id, "<p>Gone</p>"), "RemoteException");
+        assert_fault(session.remove_comment(reply.id), "RemoteException");
+        assert_fault(session.add_comment(NewComment::new(404, "<p>Lost</p>")), "RemoteException");
+    }
+
+    #[test]
+    fn blog_entries_can_be_published() {
+        let server = server();
+        let session = Session::login(server.url(), "user", "pass").unwrap();
+
+        let mut announcement =
+            UpdateBlogEntry::with_create_fields("KEY", "Release 1.2", "<p>Out</p>");
+        announcement.publish_date = Utc.with_ymd_and_hms(2020, 3, 14, 9, 0, 0).single();
+        let entry = session.store_blog_entry(announcement).unwrap();
+        assert_eq!(entry.version, 1);
+        assert_eq!(entry.creator, "user");
+
+        let summaries = session.get_blog_entries("KEY").unwrap();
+        assert_eq!(summaries.len(), 1);
+        assert_eq!(summaries[0].id, entry.id);
+        assert_eq!(summaries[0].author, "user");
+        assert_eq!(summaries[0].publish_date.day(), 14);
+
+        let found = session
+            .get_blog_entry_by_day_and_title("KEY", 14, "Release 1.2")
+            .unwrap();
+        assert_eq!(found.id, entry.id);
+
+        let mut update: UpdateBlogEntry = session.get_blog_entry(entry.id).unwrap().into();
+        update.content = "<p>Out now</p>".into();
+        let updated = session.store_blog_entry(update).unwrap();
+        assert_eq!(updated.version, 2);
+        assert_eq!(server.blog_entry(entry.id).unwrap().content, "<p>Out now</p>");
+
+        // blog entries can be commented on like pages
+        session
+            .add_comment(NewComment::new(entry.id, "<p>Nice</p>"))
+            .unwrap();
+        assert_eq!(session.get_comments(entry.id).unwrap().len(), 1);
+        let mut outdated: UpdateBlogEntry = entry.into();
+        outdated.content = "<p>Stale</p>".into();
+        assert_fault(session.store_blog_entry(outdated), "RemoteException");
+        assert_eq!(server.blog_entry(updated.id).unwrap().content, "<p>Out now</p>");
+        assert_fault(session.get_blog_entry(404), "RemoteException");
+        assert_fault(
+            session.store_blog_entry(UpdateBlogEntry::with_create_fields("NOPE", "News", "")),
+            "RemoteException",
+        );
+    }
+
+    #[test]
+    fn content_can_be_labelled() {
+        let server = server();
+        server.add_space("DOCS", "Docs");
+        server.add_space_label("DOCS", "handbook");
+        let first = server.add_page("KEY", None, "First", "a");
+        let second = server.add_page("KEY", None, "Second", "b");
+        let session = Session::login(server.url(), "user", "pass").unwrap();
+
+        assert!(session.add_label(first, "generated nightly").unwrap());
+        assert!(session.add_label(second, "Generated").unwrap());
+        assert_eq!(server.labels(first), vec!["generated", "nightly"]);
+
+        let names = session
+            .get_labels(first)
+            .unwrap()
+            .into_iter()
+            .map(|label| label.name)
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["generated", "nightly"]);
+
+        let content = session.get_label_content("generated").unwrap();
+        assert_eq!(content.iter().map(|result| result.id).collect::<Vec<_>>(), vec![first, second]);
+        assert_eq!(content[0].content_type, "page");
+        assert_eq!(content[0].excerpt, None);
+
+        let popular = session.get_most_popular_labels_in_space("KEY", 1).unwrap();
+        assert_eq!(popular.len(), 1);
+        assert_eq!(popular[0].name, "generated");
+        assert_eq!(popular[0].namespace, "global");
+
+        let spaces = session.get_spaces_with_label("handbook").unwrap();
+        assert_eq!(spaces.iter().map(|space| space.key.as_str()).collect::<Vec<_>>(), vec!["DOCS"]);
+
+        assert!(session.remove_label(first, "generated").unwrap());
+        assert_eq!(server.labels(first), vec!["nightly"]);
+        assert_fault(session.add_label(404, "generated"), "RemoteException");
+        assert_fault(session.get_labels(404), "RemoteException");
+        assert!(session.get_label_content("missing").unwrap().is_empty());
+    }
+
+    #[test]
+    fn search_filters_and_limits_results() {
+        let server = server();
+        server.add_space("DOCS", "Docs");
+        for n in 0..7 {
+            server.add_page("KEY", None, &format!("Runbook {}", n), "deployment steps");
+        }
+        server.add_page("DOCS", None, "Guide", "deployment overview");
+        let session = Session::login(server.url(), "user", "pass").unwrap();
+        session
+            .store_blog_entry(UpdateBlogEntry::with_create_fields("DOCS", "News", "new deployment"))
+            .unwrap();
+
+        let all = session.search("Deployment", &SearchParams::new()).unwrap();
+        assert_eq!(all.len(), 9);
+        assert_eq!(all[0].excerpt, Some("deployment steps".into()));
+
+        let docs = session
+            .search("deployment", &SearchParams::new().with_space_key("DOCS"))
+            .unwrap();
+        assert_eq!(docs.len(), 2);
+        let blog = SearchParams::new()
+            .with_type(SearchType::BlogPost)
+            .with_contributor("user")
+            .with_modified(ModifiedWithin::Today);
+        let found = session.search("deployment", &blog).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].content_type, "blogpost");
+        assert!(session
+            .search("deployment", &SearchParams::new().with_contributor("nobody"))
+            .unwrap()
+            .is_empty());
+
+        let limited = session
+            .search("runbook", &SearchParams::new().with_max_results(6))
+            .unwrap();
+        assert_eq!(
+            limited.into_iter().map(|result| result.title).collect::<Vec<_>>(),
+            (0..6).map(|n| format!("Runbook {}", n)).collect::<Vec<_>>()
+        );
+        assert!(session
+            .search("deployment", &SearchParams::new().with_space_key("NOPE"))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn pages_can_be_reverted() {
+        let server = server();
+        let page_id = server.add_page("KEY", None, "Draft", "first");
+        let session = Session::login(server.url(), "user", "pass").unwrap();
+
+        let mut page: UpdatePage = session.get_page_by_id(page_id).unwrap().into();
+        page.title = "Final".into();
+        page.content = "second".into();
+        let page = session
+            .update_page(page, PageUpdateOptions::new_minor_with_comment("Edited"))
+            .unwrap();
+        let mut page: UpdatePage = page.into();
+        page.content = "third".into();
+        session.store_page(page).unwrap();
+
+        let history = session.get_page_history(page_id).unwrap();
+        assert_eq!(history.iter().map(|h| h.version).collect::<Vec<_>>(), vec![2, 1]);
+        assert_eq!(history[0].version_comment, Some("Edited".into()));
+        assert_eq!(history[1].modifier, "admin");
+
+        let first = session.get_page_version(page_id, 1).unwrap();
+        assert_eq!((first.title.as_str(), first.content.as_str()), ("Draft", "first"));
+        assert!(!first.current);
+        assert_eq!(session.get_page_version(page_id, 3).unwrap().content, "third");
+        match session.get_page_version(page_id, 7) {
+            Err(Error::PageVersionNotFound(id, 7)) => assert_eq!(id, page_id),
+            other => panic!("expected missing version, received {:?}", other),
+        }
+
+        let reverted = session.revert_to_version(page_id, 1).unwrap();
+        assert_eq!(reverted.version, 4);
+        assert_eq!((reverted.title.as_str(), reverted.content.as_str()), ("Draft", "first"));
+        assert_eq!(
+            server.page(page_id).unwrap().version_comment,
+            Some("Reverted to version 1".into())
+        );
+    }
+
+    #[test]
+    fn pages_can_be_moved() {
+        let server = server();
+        server.add_space("OTHER", "Other Space");
+        let parent_id = server.add_page("KEY", None, "Parent", "");
+        let a = server.add_page("KEY", Some(parent_id), "A", "");
+        let b = server.add_page("KEY", Some(parent_id), "B", "");
+        let c = server.add_page("KEY", Some(parent_id), "C", "");
+        let session = Session::login(server.url(), "user", "pass").unwrap();
+        let children = |id| {
+            session
+                .get_children(id)
+                .unwrap()
+                .into_iter()
+                .map(|page| page.id)
+                .collect::<Vec<_>>()
+        };
+
+        assert!(session.move_page(c, a, Position::Above).unwrap());
+        assert_eq!(children(parent_id), vec![c, a, b]);
+        session.move_page(c, b, Position::Below).unwrap();
+        assert_eq!(children(parent_id), vec![a, b, c]);
+        session.move_page(b, a, Position::Append).unwrap();
+        assert_eq!(children(parent_id), vec![a, c]);
+        assert_eq!(children(a), vec![b]);
+        assert_eq!(server.page(b).unwrap().version, 1);
+
+        match session.move_page(a, b, Position::Append) {
+            Err(Error::Rpc(_)) => {}
+            other => panic!("expected fault, received {:?}", other),
+        }
+
+        session.move_page(c, a, Position::TopLevel).unwrap();
+        assert_eq!(server.page(c).unwrap().parent_id, 0);
+        assert!(session.move_page_to_top_level(a, "OTHER").unwrap());
+        let (moved, child) = (server.page(a).unwrap(), server.page(b).unwrap());
+        assert_eq!((moved.space.as_str(), moved.parent_id), ("OTHER", 0));
+        assert_eq!((child.space.as_str(), child.parent_id), ("OTHER", a));
+        assert_eq!(children(parent_id), Vec::<i64>::new());
+        assert_fault(session.move_page(c, 404, Position::Below), "RemoteException");
+        assert_fault(session.move_page(404, c, Position::Append), "RemoteException");
+        assert_fault(session.move_page_to_top_level(c, "NOPE"), "RemoteException");
+        assert_eq!(server.page(c).unwrap().space, "KEY");
+    }
+
+    #[test]
+    fn permissions_can_be_changed() {
+        let server = server();
+        server.add_user("bot", "pass");
+        let page_id = server.add_page("KEY", None, "Generated", "a");
+        let session = Session::login(server.url(), "user", "pass").unwrap();
+
+        assert!(session.get_permissions("KEY").unwrap().contains(&Permission::Administer));
+        assert!(session
+            .remove_permission_from_space(&Permission::EditPage, "bot", "KEY")
+            .unwrap());
+        let permissions = session.get_permissions_for_user("KEY", "bot").unwrap();
+        assert!(permissions.contains(&Permission::View));
+        assert!(!permissions.contains(&Permission::EditPage));
+        session
+            .add_permission_to_space(&Permission::EditPage, "bot", "KEY")
+            .unwrap();
+        assert!(session
+            .get_permissions_for_user("KEY", "bot")
+            .unwrap()
+            .contains(&Permission::EditPage));
+
+        let edit = ContentPermissionType::Edit;
+        assert!(session
+            .set_content_permissions(page_id, edit.clone(), &[ContentPermission::user(edit, "bot")])
+            .unwrap());
+        let sets = session.get_content_permission_sets(page_id).unwrap();
+        assert_eq!(sets.len(), 2);
+        assert_eq!(sets[0].permission_type, ContentPermissionType::View);
+        assert!(sets[0].permissions.is_empty());
+        assert_eq!(
+            sets[1].permissions,
+            vec![ContentPermission::user(ContentPermissionType::Edit, "bot")]
+        );
+        assert_eq!(server.content_permissions(page_id)[0].user_name, Some("bot".into()));
+
+        let mut page: UpdatePage = session.get_page_by_id(page_id).unwrap().into();
+        page.content = "b".into();
+        match session.store_page(page) {
+            Err(Error::Rpc(ref e)) => assert_eq!(e.exception_name(), Some("NotPermittedException")),
+            other => panic!("expected missing permission, received {:?}", other),
+        }
+        let bot = Session::login(server.url(), "bot", "pass").unwrap();
+        let mut page: UpdatePage = bot.get_page_by_id(page_id).unwrap().into();
+        page.content = "b".into();
+        assert_eq!(bot.store_page(page).unwrap().version, 2);
+        server.deny_permission("bot", "KEY", "SETSPACEPERMISSIONS");
+        server.deny_permission("bot", "KEY", "SETPAGEPERMISSIONS");
+        assert_fault(
+            bot.add_permission_to_space(&Permission::Administer, "bot", "KEY"),
+            "NotPermittedException",
+        );
+        assert_fault(
+            bot.set_content_permissions(page_id, ContentPermissionType::View, &[]),
+            "NotPermittedException",
+        );
+        assert_fault(
+            session.add_permission_to_space(&Permission::Other("FLY".into()), "bot", "KEY"),
+            "RemoteException",
+        );
+        assert_fault(session.get_content_permission_sets(404), "RemoteException");
+    }
+
+    #[test]
+    fn users_and_groups_can_be_managed() {
+        let server = server();
+        let session = Session::login(server.url(), "user", "pass").unwrap();
+
+        assert!(!session.has_user("jdoe").unwrap());
+        session
+            .add_user(UpdateUser::new("jdoe", "Jane Doe", "jdoe@example.com"), "secret")
+            .unwrap();
+        assert!(session.has_user("jdoe").unwrap());
+        let user = session.get_user("jdoe").unwrap();
+        assert_eq!(user.full_name, "Jane Doe");
+        assert_eq!(user.email, "jdoe@example.com");
+        assert!(user.url.ends_with("/display/~jdoe"));
+        let information = session.get_user_information("jdoe").unwrap();
+        assert_eq!((information.username.as_str(), information.version), ("jdoe", 1));
+        assert_eq!(information.content, "");
+
+        let mut user: UpdateUser = user.into();
+        user.full_name = "Jane Roe".into();
+        assert!(session.edit_user(user).unwrap());
+        assert_eq!(server.user("jdoe").unwrap().full_name, "Jane Roe");
+
+        assert!(session.add_group("writers").unwrap());
+        assert!(session.get_groups().unwrap().iter().any(|group| group.name == "writers"));
+        session.add_user_to_group("jdoe", "writers").unwrap();
+        let groups = session.get_user_groups("jdoe").unwrap();
+        assert_eq!(
+            groups.iter().map(|group| group.name.as_str()).collect::<Vec<_>>(),
+            vec!["confluence-users", "writers"]
+        );
+        session.remove_user_from_group("jdoe", "writers").unwrap();
+        assert_eq!(session.get_user_groups("jdoe").unwrap().len(), 1);
+        match session.add_user_to_group("jdoe", "missing") {
+            Err(Error::Rpc(_)) => {}
+            other => panic!("expected fault, received {:?}", other),
+        }
+
+        let jdoe = Session::login(server.url(), "jdoe", "secret").unwrap();
+        assert!(session.deactivate_user("jdoe").unwrap());
+        assert!(jdoe.get_space("KEY").is_err());
+        assert!(Session::login(server.url(), "jdoe", "secret").is_err());
+        assert!(session.reactivate_user("jdoe").unwrap());
+        assert!(Session::login(server.url(), "jdoe", "secret").is_ok());
+        assert_fault(session.get_user("nobody"), "RemoteException");
+        assert_fault(session.add_user_to_group("jdoe", "nobodies"), "RemoteException");
+        assert_fault(
+            session.add_user(UpdateUser::new("jdoe", "Jane Doe", "jdoe@example.com"), "secret"),
+            "AlreadyExistsException",
+        );
+    }
+
+    #[test]
+    fn content_can_be_watched() {
+        let server = server();
+        server.add_user("owner", "pass");
+        let page_id = server.add_page("KEY", None, "Generated", "");
+        let session = Session::login(server.url(), "user", "pass").unwrap();
+
+        assert!(session.watch_page(page_id).unwrap());
+        assert!(session.watch_space("KEY").unwrap());
+        assert!(session.is_watching_page(page_id, "user").unwrap());
+        assert!(session.is_watching_space("KEY", "user").unwrap());
+        assert!(!session.is_watching_page(page_id, "owner").unwrap());
+
+        match session.watch_page_for_user(page_id, "owner") {
+            Err(Error::Rpc(ref e)) => assert_eq!(e.exception_name(), Some("NotPermittedException")),
+            other => panic!("expected missing permission, received {:?}", other),
+        }
+        session.add_user_to_group("user", "confluence-administrators").unwrap();
+        assert!(session.watch_page_for_user(page_id, "owner").unwrap());
+        let watchers = session.get_watchers_for_page(page_id).unwrap();
+        assert_eq!(
+            watchers.iter().map(|user| user.name.as_str()).collect::<Vec<_>>(),
+            vec!["owner", "user"]
+        );
+        assert_eq!(watchers[0].email, "owner@example.com");
+
+        assert!(session.remove_page_watch(page_id).unwrap());
+        assert!(session.remove_page_watch_for_user(page_id, "owner").unwrap());
+        assert!(session.get_watchers_for_page(page_id).unwrap().is_empty());
+        assert_eq!(session.get_watchers_for_space("KEY").unwrap().len(), 1);
+        assert!(session.remove_space_watch("KEY").unwrap());
+        assert!(!session.is_watching_space("KEY", "user").unwrap());
+        assert_fault(session.watch_page(404), "RemoteException");
+        assert_fault(session.watch_space("NOPE"), "RemoteException");
+        assert_fault(session.watch_page_for_user(page_id, "nobody"), "RemoteException");
+        assert_fault(session.get_watchers_for_page(404), "RemoteException");
+    }
+
+    #[test]
+    fn page_tree_can_be_listed() {
+        let server = server();
+        let home_page = server.space("KEY").unwrap().home_page;
+        let parent_id = server.add_page("KEY", None, "Parent", "");
+        let page_id = server.add_page("KEY", Some(parent_id), "Page", "");
+        let child_id = server.add_page("KEY", Some(page_id), "Child", "");
+        server.add_space("OTHER", "Other Space");
+        let session = Session::login(server.url(), "user", "pass").unwrap();
+        let ids = |pages: Vec<PageSummary>| {
+            pages.into_iter().map(|page| page.id).collect::<Vec<_>>()
+        };
+
+        assert_eq!(ids(session.get_ancestors(child_id).unwrap()), vec![parent_id, page_id]);
+        assert!(session.get_ancestors(parent_id).unwrap().is_empty());
+        assert_eq!(
+            ids(session.get_pages("KEY").unwrap()),
+            vec![home_page, parent_id, page_id, child_id]
+        );
+        assert_eq!(ids(session.get_top_level_pages("KEY").unwrap()), vec![home_page, parent_id]);
+
+        let summary = session.get_page_summary(child_id).unwrap();
+        assert_eq!((summary.title.as_str(), summary.parent_id), ("Child", page_id));
+        assert_eq!(summary.space, "KEY");
+        assert_fault(session.get_ancestors(404), "RemoteException");
+        assert_fault(session.get_pages("NOPE"), "RemoteException");
+        assert_fault(session.get_top_level_pages("NOPE"), "RemoteException");
+        assert_fault(session.get_page_summary(404), "RemoteException");
+    }
+
+    #[test]
+    fn content_can_be_rendered() {
+        let server = server();
+        let page_id = server.add_page("KEY", None, "Preview", "<p>stored</p>");
+        let session = Session::login(server.url(), "user", "pass").unwrap();
+
+        let storage = session
+            .convert_wiki_to_storage_format("h1. Title & more\n\nSome *text*")
+            .unwrap();
+        assert_eq!(storage, "<h1>Title &amp; more</h1><p>Some *text*</p>");
+
+        let clean = RenderOptions::new().with_clean_style();
+        let html = session.render_content("KEY", page_id, &storage, &clean).unwrap();
+        assert_eq!(
+            html,
+            format!(r#"<div id="main-content" class="wiki-content">{}</div>"#, storage)
+        );
+        let html = session
+            .render_content("KEY", page_id, "", &RenderOptions::new())
+            .unwrap();
+        assert!(html.starts_with("<html><head><title>Preview</title>"));
+        assert!(html.contains("<p>stored</p>"));
+        assert_eq!(server.page(page_id).unwrap().version, 1);
+        assert_fault(
+            session.render_content("KEY", 404, "", &RenderOptions::new()),
+            "RemoteException",
+        );
+    }
+
+    #[test]
+    fn removed_pages_go_to_the_trash() {
+        let server = server();
+        let parent_id = server.add_page("KEY", None, "Parent", "");
+        let page_id = server.add_page("KEY", Some(parent_id), "Page", "a");
+        let child_id = server.add_page("KEY", Some(page_id), "Child", "");
+        let other_id = server.add_page("KEY", None, "Other", "");
+        let session = Session::login(server.url(), "user", "pass").unwrap();
+
+        let mut page: UpdatePage = session.get_page_by_id(page_id).unwrap().into();
+        page.content = "b".into();
+        session.store_page(page).unwrap();
+        assert!(session.remove_page_version(page_id, 1).unwrap());
+        assert!(session.get_page_history(page_id).unwrap().is_empty());
+        match session.remove_page_version(page_id, 1) {
+            Err(Error::NotFound(_)) => {}
+            other => panic!("expected missing version, received {:?}", other),
+        }
+
+        assert!(session.remove_page(page_id).unwrap());
+        assert!(session.remove_page(other_id).unwrap());
+        assert!(server.page(page_id).is_none());
+        assert_eq!(server.page(child_id).unwrap().parent_id, parent_id);
+        let trashed = session.get_page_by_id(page_id).unwrap();
+        assert_eq!(trashed.content_status, "deleted");
+        assert!(!trashed.current);
+        match session.remove_page(page_id) {
+            Err(Error::NotFound(_)) => {}
+            other => panic!("expected missing page, received {:?}", other),
+        }
+
+        let trash = session.get_trash_contents("KEY", 1, 10).unwrap();
+        assert_eq!((trash.offset, trash.total_available), (1, 2));
+        assert_eq!(trash.content.len(), 1);
+        assert_eq!(trash.content[0].id, other_id);
+        assert_eq!(trash.content[0].content_type, "page");
+        assert_eq!(trash.content[0].status, "deleted");
+
+        server.add_user("reader", "pass");
+        server.deny_permission("reader", "KEY", "REMOVEPAGE");
+        server.deny_permission("reader", "KEY", "SETSPACEPERMISSIONS");
+        let reader = Session::login(server.url(), "reader", "pass").unwrap();
+        match reader.remove_page(child_id) {
+            Err(Error::NotPermitted(_)) => {}
+            other => panic!("expected missing permission, received {:?}", other),
+        }
+        match reader.empty_trash("KEY") {
+            Err(Error::NotPermitted(_)) => {}
+            other => panic!("expected missing permission, received {:?}", other),
+        }
+
+        assert!(session.purge_from_trash("KEY", page_id).unwrap());
+        match session.get_page_by_id(page_id) {
+            Err(Error::Rpc(_)) => {}
+            other => panic!("expected missing page, received {:?}", other),
+        }
+        match session.purge_from_trash("KEY", page_id) {
+            Err(Error::NotFound(_)) => {}
+            other => panic!("expected missing page, received {:?}", other),
+        }
+        assert!(session.empty_trash("KEY").unwrap());
+        let trash = session.get_trash_contents("KEY", 0, 10).unwrap();
+        assert_eq!(trash.total_available, 0);
+        assert!(trash.content.is_empty());
+    }
+
+    #[test]
+    fn faults_are_not_reported_as_missing_content_unless_confirmed() {
+        let server = server();
+        let page_id = server.add_page("KEY", None, "Page", "a");
+        let session = Session::login(server.url(), "user", "pass").unwrap();
+
+        server.fault_next_call("Search index entry not found");
+        match session.remove_page(page_id) {
+            Err(Error::Rpc(ref e)) => {
+                assert_eq!(e.fault_message(), Some("Search index entry not found"))
+            }
+            other => panic!("expected unchanged fault, received {:?}", other),
+        }
+        assert!(server.page(page_id).is_some());
+
+        server.fault_next_call("No such version found");
+        match session.remove_page_version(page_id, 1) {
+            Err(Error::Rpc(_)) => {}
+            other => panic!("expected unchanged fault, received {:?}", other),
+        }
+
+        server.fault_next_call("Trash not found");
+        match session.purge_from_trash("KEY", page_id) {
+            Err(Error::NotFound(_)) => {}
+            other => panic!("expected missing content, received {:?}", other),
+        }
+
+        match session.remove_page(404) {
+            Err(Error::NotFound(_)) => {}
+            other => panic!("expected missing page, received {:?}", other),
+        }
+        match session.remove_page_version(404, 1) {
+            Err(Error::NotFound(_)) => {}
+            other => panic!("expected missing page, received {:?}", other),
+        }
+    }
+
+    #[test]
+    fn outdated_version_is_a_fault() {
+        let server = server();
+        let page_id = server.add_page("KEY", None, "Page", "a");
+        let session = Session::login(server.url(), "user", "pass").unwrap();
+
+        let mut page: UpdatePage = session.get_page_by_id(page_id).unwrap().into();
+        page.version = Some(0);
+
+        match session.store_page(page) {
+            Err(Error::Rpc(ref e)) => match **e {
+                RpcError::Fault { ref fault_string, .. } => {
+                    assert!(fault_string.contains("outdated version"))
+                }
+                ref other => panic!("expected fault, received {:?}", other),
+            },
+            other => panic!("expected fault, received {:?}", other),
+        }
+    }
+
+    #[test]
+    fn wrong_password_is_a_fault() {
+        let server = server();
+
+        match Session::login(server.url(), "user", "wrong") {
+            Err(Error::Rpc(ref e)) => match **e {
+                RpcError::Fault { ref fault_string, .. } => {
+                    assert!(fault_string.contains("AuthenticationFailedException"))
+                }
+                ref other => panic!("expected fault, received {:?}", other),
+            },
+            Err(other) => panic!("expected fault, received {:?}", other),
+            Ok(_) => panic!("expected fault, received session"),
+        }
+    }
+
+    #[test]
+    fn expired_token_is_renewed() {
+        let server = server();
+        let page_id = server.add_page("KEY", None, "Page", "a");
+        let session = Session::login(server.url(), "user", "pass").unwrap();
+
+        server.expire_sessions();
+
+        assert_eq!(session.get_page_by_id(page_id).unwrap().title, "Page");
+        assert_eq!(
+            server.operations(),
+            vec!["login", "getServerInfo", "getPage", "login", "getPage"]
+        );
+    }
+
+    #[test]
+    fn expired_token_is_an_error_without_auto_relogin() {
+        let server = server();
+        let page_id = server.add_page("KEY", None, "Page", "a");
+        let mut session = Session::login(server.url(), "user", "pass").unwrap();
+        session.set_auto_relogin(false);
+
+        server.expire_sessions();
+
+        match session.get_page_by_id(page_id) {
+            Err(Error::Rpc(ref e)) => {
+                assert_eq!(e.exception_name(), Some("InvalidSessionException"))
+            }
+            other => panic!("expected fault, received {:?}", other),
+        }
+    }
+
+    #[test]
+    fn transient_failures_of_reads_are_retried() {
+        let server = server();
+        let page_id = server.add_page("KEY", None, "Page", "a");
+        let session = SessionBuilder::new()
+            .retry_policy(RetryPolicy::default().with_initial_backoff(Duration::from_millis(1)))
+            .login(server.url(), "user", "pass")
+            .unwrap();
+
+        server.fail_next_calls(2, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(session.get_page_by_id(page_id).unwrap().title, "Page");
+
+        server.fail_next_calls(3, StatusCode::SERVICE_UNAVAILABLE);
+        match session.get_page_by_id(page_id) {
+            Err(Error::HttpStatus(StatusCode::SERVICE_UNAVAILABLE)) => {}
+            other => panic!("expected http status error, received {:?}", other),
+        }
+    }
+
+    #[test]
+    fn transient_failures_of_writes_are_not_retried_by_default() {
+        let server = server();
+        let session = SessionBuilder::new()
+            .retry_policy(RetryPolicy::default().with_initial_backoff(Duration::from_millis(1)))
+            .login(server.url(), "user", "pass")
+            .unwrap();
+
+        server.fail_next_calls(1, StatusCode::BAD_GATEWAY);
+        match session.store_page(UpdatePage::with_create_fields(None, "KEY", "Page", "a")) {
+            Err(Error::HttpStatus(StatusCode::BAD_GATEWAY)) => {}
+            other => panic!("expected http status error, received {:?}", other),
+        }
+    }
+
+    #[test]
+    fn session_can_be_resumed_from_token() {
+        let server = server();
+        let page_id = server.add_page("KEY", None, "Page", "a");
+
+        let token = Session::login(server.url(), "user", "pass")
+            .unwrap()
+            .into_token();
+        {
+            let session = Session::from_token(server.url(), &token).unwrap();
+            assert_eq!(session.token(), token);
+            assert_eq!(session.get_page_by_id(page_id).unwrap().title, "Page");
+        }
+
+        assert_eq!(
+            server.operations(),
+            vec!["login", "getServerInfo", "getServerInfo", "getPage", "logout"]
+        );
+    }
+
+    #[test]
+    fn kept_alive_session_does_not_log_out() {
+        let server = server();
+        {
+            let _session = SessionBuilder::new()
+                .keep_alive(true)
+                .login(server.url(), "user", "pass")
+                .unwrap();
+        }
+
+        assert_eq!(server.operations(), vec!["login", "getServerInfo"]);
+    }
+
+    #[test]
+    fn stalled_request_is_rejected_without_blocking_the_server() {
+        let server = server();
+        server.set_read_timeout(Duration::from_millis(100));
+
+        let mut stream = TcpStream::connect(&server.url()["http://".len()..]).unwrap();
+        write!(
+            stream,
+            "POST {} HTTP/1.1\r\nContent-Length: 100\r\n\r\n<soapenv:Envelope",
+            ENDPOINT_PATH
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 400"), "{}", response);
+
+        let session = Session::login(server.url(), "user", "pass").unwrap();
+        assert_eq!(session.get_space("KEY").unwrap().name, "Some Space");
+    }
+
+    #[test]
+    fn failed_logout_on_drop_does_not_panic() {
+        let server = server();
+        let session = Session::login(server.url(), "user", "pass").unwrap();
+
+        drop(server);
+        drop(session);
+    }
+
+    #[test]
+    fn poisoned_token_lock_does_not_panic() {
+        let server = server();
+        let session = Session::login(server.url(), "user", "pass").unwrap();
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _token = session.token.write().unwrap();
+            panic!("poison the token lock");
+        }));
+        assert!(result.is_err());
+
+        assert!(!session.token().is_empty());
+        drop(session);
+        assert_eq!(server.operations().last().unwrap(), "logout");
+    }
+
+    #[test]
+    fn session_logs_out_on_drop() {
+        let server = server();
+        {
+            let _session = Session::login(server.url(), "user", "pass").unwrap();
+        }
+
+        assert_eq!(
+            server.operations(),
+            vec!["login", "getServerInfo", "logout"]
+        );
+    }
+}
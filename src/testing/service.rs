@@ -0,0 +1,2533 @@
+//! State and operations of the fake Confluence SOAP service.
+
+use crate::http::StatusCode;
+use crate::rpser::xml::BuildElement;
+use chrono::offset::Utc;
+use chrono::{DateTime, Datelike, Duration, SecondsFormat};
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+use xmltree::Element;
+
+/// Path of the SOAP endpoint, relative to server url.
+pub(super) const ENDPOINT_PATH: &str = "/rpc/soap-axis/confluenceservice-v2";
+
+/// Operations implemented by the fake service.
+pub(super) const OPERATIONS: &[&str] = &[
+    "login",
+    "logout",
+    "getServerInfo",
+    "getSpace",
+    "getSpaces",
+    "addSpace",
+    "addPersonalSpace",
+    "storeSpace",
+    "removeSpace",
+    "getSpaceStatus",
+    "setSpaceStatus",
+    "getPermissions",
+    "getPermissionsForUser",
+    "addPermissionToSpace",
+    "removePermissionFromSpace",
+    "getPage",
+    "storePage",
+    "updatePage",
+    "getPageHistory",
+    "removePage",
+    "removePageVersionByVersion",
+    "getTrashContents",
+    "purgeFromTrash",
+    "emptyTrash",
+    "movePage",
+    "movePageToTopLevel",
+    "getChildren",
+    "getDescendents",
+    "getAncestors",
+    "getPages",
+    "getTopLevelPages",
+    "getPageSummary",
+    "getAttachments",
+    "getAttachment",
+    "getAttachmentData",
+    "addAttachment",
+    "moveAttachment",
+    "removeAttachment",
+    "getComments",
+    "getComment",
+    "addComment",
+    "editComment",
+    "removeComment",
+    "getBlogEntries",
+    "getBlogEntry",
+    "getBlogEntryByDayAndTitle",
+    "storeBlogEntry",
+    "getLabelsById",
+    "addLabelByName",
+    "removeLabelByName",
+    "getLabelContentByName",
+    "getMostPopularLabelsInSpace",
+    "getSpacesWithLabel",
+    "getContentPermissionSets",
+    "setContentPermissions",
+    "getUser",
+    "hasUser",
+    "getUserInformation",
+    "addUser",
+    "editUser",
+    "deactivateUser",
+    "reactivateUser",
+    "getGroups",
+    "addGroup",
+    "addUserToGroup",
+    "removeUserFromGroup",
+    "getUserGroups",
+    "watchPage",
+    "watchPageForUser",
+    "watchSpace",
+    "removePageWatch",
+    "removePageWatchForUser",
+    "removeSpaceWatch",
+    "isWatchingPage",
+    "isWatchingSpace",
+    "getWatchersForPage",
+    "getWatchersForSpace",
+    "search",
+    "renderContent",
+    "convertWikiToStorageFormat",
+];
+
+/// User account stored in the fake server.
+#[derive(Debug, Clone)]
+pub struct FakeUser {
+    /// The id of the user profile
+    pub id: i64,
+    /// The username
+    pub name: String,
+    /// The full name of the user
+    pub full_name: String,
+    /// The email address of the user
+    pub email: String,
+    /// The password of the user
+    pub password: String,
+    /// Whether the user can log in
+    pub active: bool,
+    /// Names of the groups of the user
+    pub groups: Vec<String>,
+    /// Timestamp user was created
+    pub created: DateTime<Utc>,
+}
+
+/// Space stored in the fake server.
+#[derive(Debug, Clone)]
+pub struct FakeSpace {
+    /// The space key
+    pub key: String,
+    /// The name of the space
+    pub name: String,
+    /// The space description
+    pub description: Option<String>,
+    /// The id of the space homepage
+    pub home_page: i64,
+    /// Space type, `global` or `personal`
+    pub space_type: String,
+    /// Space status, `CURRENT` or `ARCHIVED`
+    pub status: String,
+    /// Names of the labels of the space
+    pub labels: Vec<String>,
+    /// Permissions taken from users, as `(username, permission)`
+    pub denied_permissions: Vec<(String, String)>,
+}
+
+/// Page stored in the fake server.
+#[derive(Debug, Clone)]
+pub struct FakePage {
+    /// The id of the page
+    pub id: i64,
+    /// The key of the space that this page belongs to
+    pub space: String,
+    /// The id of the parent page, `0` for top level pages
+    pub parent_id: i64,
+    /// The title of the page
+    pub title: String,
+    /// The page content
+    pub content: String,
+    /// The version number of this page
+    pub version: i32,
+    /// Comment of the last update
+    pub version_comment: Option<String>,
+    /// Timestamp page was created
+    pub created: DateTime<Utc>,
+    /// Username of the creator
+    pub creator: String,
+    /// Timestamp page was modified
+    pub modified: DateTime<Utc>,
+    /// Username of the page's last modifier
+    pub modifier: String,
+    /// Sort key among the siblings of the page
+    pub position: i64,
+}
+
+/// Historical version of a page stored in the fake server.
+#[derive(Debug, Clone)]
+pub struct FakePageVersion {
+    /// The id of the historical version
+    pub id: i64,
+    /// The page as it was in this version, with the id of the current page
+    pub page: FakePage,
+}
+
+/// Blog entry stored in the fake server.
+#[derive(Debug, Clone)]
+pub struct FakeBlogEntry {
+    /// The id of the blog entry
+    pub id: i64,
+    /// The key of the space that this blog entry belongs to
+    pub space: String,
+    /// The title of the blog entry
+    pub title: String,
+    /// The blog entry content
+    pub content: String,
+    /// The version number of this blog entry
+    pub version: i32,
+    /// Timestamp blog entry is published at
+    pub publish_date: DateTime<Utc>,
+    /// Timestamp blog entry was created
+    pub created: DateTime<Utc>,
+    /// Username of the creator
+    pub creator: String,
+    /// Timestamp blog entry was modified
+    pub modified: DateTime<Utc>,
+    /// Username of the blog entry's last modifier
+    pub modifier: String,
+}
+
+/// Attachment stored in the fake server.
+#[derive(Debug, Clone)]
+pub struct FakeAttachment {
+    /// The id of the attachment
+    pub id: i64,
+    /// The id of the page this attachment belongs to
+    pub page_id: i64,
+    /// The file name of the attachment
+    pub file_name: String,
+    /// The mime type of the attachment
+    pub content_type: String,
+    /// Comment of the attachment
+    pub comment: Option<String>,
+    /// Content of every version, oldest first
+    pub versions: Vec<Vec<u8>>,
+    /// Timestamp attachment was created
+    pub created: DateTime<Utc>,
+    /// Username of the creator
+    pub creator: String,
+}
+
+/// Comment stored in the fake server.
+#[derive(Debug, Clone)]
+pub struct FakeComment {
+    /// The id of the comment
+    pub id: i64,
+    /// The id of the page this comment belongs to
+    pub page_id: i64,
+    /// The id of the parent comment, `0` for top level comments
+    pub parent_id: i64,
+    /// The comment content
+    pub content: String,
+    /// Timestamp comment was created
+    pub created: DateTime<Utc>,
+    /// Username of the creator
+    pub creator: String,
+    /// Timestamp comment was modified
+    pub modified: DateTime<Utc>,
+    /// Username of the comment's last modifier
+    pub modifier: String,
+}
+
+/// View or edit restriction of a page or blog entry stored in the fake server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FakeContentPermission {
+    /// `View` or `Edit`
+    pub permission_type: String,
+    /// Username of the allowed user
+    pub user_name: Option<String>,
+    /// Name of the allowed group
+    pub group_name: Option<String>,
+}
+
+/// Space permissions known to the fake server, every user has all of them unless denied.
+const SPACE_PERMISSIONS: &[&str] = &[
+    "VIEWSPACE",
+    "EDITSPACE",
+    "REMOVEPAGE",
+    "EDITBLOG",
+    "REMOVEBLOG",
+    "COMMENT",
+    "REMOVECOMMENT",
+    "CREATEATTACHMENT",
+    "REMOVEATTACHMENT",
+    "REMOVEMAIL",
+    "EXPORTSPACE",
+    "SETPAGEPERMISSIONS",
+    "SETSPACEPERMISSIONS",
+];
+
+/// SOAP fault raised by an operation.
+#[derive(Debug)]
+pub(super) struct Fault {
+    /// Short name of `com.atlassian.confluence.rpc` exception
+    pub exception: &'static str,
+    pub message: String,
+}
+
+impl Fault {
+    pub(super) fn remote<S: Into<String>>(message: S) -> Fault {
+        Fault {
+            exception: "RemoteException",
+            message: message.into(),
+        }
+    }
+}
+
+type OpResult = Result<Element, Fault>;
+
+/// Page or blog entry considered by search.
+struct SearchCandidate<'a> {
+    id: i64,
+    content_type: &'static str,
+    space: &'a str,
+    title: &'a str,
+    content: &'a str,
+    contributors: [&'a str; 2],
+    modified: DateTime<Utc>,
+    url: String,
+}
+
+/// Everything the fake server knows.
+#[derive(Debug, Default)]
+pub(super) struct State {
+    pub base_url: String,
+    pub users: BTreeMap<String, FakeUser>,
+    pub groups: BTreeSet<String>,
+    pub sessions: HashMap<String, String>,
+    pub spaces: BTreeMap<String, FakeSpace>,
+    pub pages: BTreeMap<i64, FakePage>,
+    pub page_versions: BTreeMap<i64, FakePageVersion>,
+    /// Removed pages, until purged from the trash
+    pub trash: BTreeMap<i64, FakePage>,
+    pub blog_entries: BTreeMap<i64, FakeBlogEntry>,
+    pub attachments: BTreeMap<i64, FakeAttachment>,
+    pub comments: BTreeMap<i64, FakeComment>,
+    /// Names of the labels of pages and blog entries, by content id
+    pub labels: BTreeMap<i64, Vec<String>>,
+    /// Restrictions of pages and blog entries, by content id
+    pub content_permissions: BTreeMap<i64, Vec<FakeContentPermission>>,
+    /// Usernames of the watchers of pages and blog entries, by content id
+    pub page_watches: BTreeMap<i64, BTreeSet<String>>,
+    /// Usernames of the watchers of spaces, by space key
+    pub space_watches: BTreeMap<String, BTreeSet<String>>,
+    label_ids: BTreeMap<String, i64>,
+    pub operations: Vec<String>,
+    pub failures: VecDeque<StatusCode>,
+    /// Messages of `RemoteException` faults to reply with to the next calls
+    pub faults: VecDeque<String>,
+    pub build_id: String,
+    pub wsdl_requests: usize,
+    /// How long to wait for the client to send the rest of the request
+    pub read_timeout: std::time::Duration,
+    last_session: u64,
+    last_id: i64,
+}
+
+impl State {
+    pub fn new(base_url: String) -> State {
+        State {
+            base_url,
+            build_id: "8100".into(),
+            read_timeout: std::time::Duration::from_secs(5),
+            last_id: 65535,
+            groups: vec!["confluence-administrators", "confluence-users"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            ..State::default()
+        }
+    }
+
+    pub fn add_user(&mut self, name: &str, full_name: &str, email: &str, password: &str) {
+        self.last_id += 1;
+        let user = FakeUser {
+            id: self.last_id,
+            name: name.into(),
+            full_name: full_name.into(),
+            email: email.into(),
+            password: password.into(),
+            active: true,
+            groups: vec!["confluence-users".into()],
+            created: Utc::now(),
+        };
+        self.users.insert(name.into(), user);
+    }
+
+    pub fn endpoint(&self) -> String {
+        [&self.base_url, ENDPOINT_PATH].concat()
+    }
+
+    pub fn add_space(&mut self, key: &str, name: &str) {
+        self.insert_space(key, name, None, "global");
+    }
+
+    fn insert_space(
+        &mut self,
+        key: &str,
+        name: &str,
+        description: Option<String>,
+        space_type: &str,
+    ) -> &FakeSpace {
+        let home_page = self
+            .create_page(key, 0, &format!("{} Home", name), "", "admin")
+            .id;
+        self.spaces.insert(
+            key.into(),
+            FakeSpace {
+                key: key.into(),
+                name: name.into(),
+                description,
+                home_page,
+                space_type: space_type.into(),
+                status: "CURRENT".into(),
+                labels: vec![],
+                denied_permissions: vec![],
+            },
+        );
+        &self.spaces[key]
+    }
+
+    pub fn create_page(
+        &mut self,
+        space: &str,
+        parent_id: i64,
+        title: &str,
+        content: &str,
+        creator: &str,
+    ) -> &FakePage {
+        self.last_id += 1;
+        let now = Utc::now();
+        let page = FakePage {
+            id: self.last_id,
+            space: space.into(),
+            parent_id,
+            title: title.into(),
+            content: content.into(),
+            version: 1,
+            version_comment: None,
+            created: now,
+            creator: creator.into(),
+            modified: now,
+            modifier: creator.into(),
+            position: self.last_id,
+        };
+        self.pages.entry(page.id).or_insert(page)
+    }
+
+    pub fn expire_sessions(&mut self) {
+        self.sessions.clear();
+    }
+
+    /// Run operation with positional arguments and return its `*Return` element.
+    pub fn handle(&mut self, operation: &str, args: &[Element]) -> OpResult {
+        self.operations.push(operation.into());
+
+        let result = match operation {
+            "login" => self.login(args),
+            "logout" => self.logout(args),
+            "getServerInfo" => self.get_server_info(args),
+            "getSpace" => self.get_space(args),
+            "getSpaces" => self.get_spaces(args),
+            "addSpace" => self.add_space_operation(args, None),
+            "addPersonalSpace" => {
+                let user_name = text_arg(args, 2)?;
+                self.add_space_operation(args, Some(user_name))
+            }
+            "storeSpace" => self.store_space(args),
+            "removeSpace" => self.remove_space(args),
+            "getSpaceStatus" => self.get_space_status(args),
+            "setSpaceStatus" => self.set_space_status(args),
+            "getPermissions" => {
+                let user = self.authenticate(args)?;
+                self.get_permissions(args, user)
+            }
+            "getPermissionsForUser" => {
+                let user = text_arg(args, 2)?;
+                self.get_permissions(args, user)
+            }
+            "addPermissionToSpace" => self.change_space_permission(args, true),
+            "removePermissionFromSpace" => self.change_space_permission(args, false),
+            "getPage" => self.get_page(args),
+            "storePage" => self.store_page(args, None),
+            "updatePage" => {
+                let comment = arg(args, 2)
+                    .ok()
+                    .and_then(|options| child_text(options, "versionComment"));
+                self.store_page(args, Some(comment))
+            }
+            "getPageHistory" => self.get_page_history(args),
+            "removePage" => self.remove_page(args),
+            "removePageVersionByVersion" => self.remove_page_version_by_version(args),
+            "getTrashContents" => self.get_trash_contents(args),
+            "purgeFromTrash" => self.purge_from_trash(args),
+            "emptyTrash" => self.empty_trash(args),
+            "movePage" => self.move_page(args),
+            "movePageToTopLevel" => self.move_page_to_top_level(args),
+            "getChildren" => self.get_children(args),
+            "getDescendents" => self.get_descendents(args),
+            "getAncestors" => self.get_ancestors(args),
+            "getPages" => self.get_pages(args, false),
+            "getTopLevelPages" => self.get_pages(args, true),
+            "getPageSummary" => self.get_page_summary(args),
+            "getBlogEntries" => self.get_blog_entries(args),
+            "getBlogEntry" => self.get_blog_entry(args),
+            "getBlogEntryByDayAndTitle" => self.get_blog_entry_by_day_and_title(args),
+            "storeBlogEntry" => self.store_blog_entry(args),
+            "getLabelsById" => self.get_labels_by_id(args),
+            "addLabelByName" => self.add_label_by_name(args),
+            "removeLabelByName" => self.remove_label_by_name(args),
+            "getLabelContentByName" => self.get_label_content_by_name(args),
+            "getMostPopularLabelsInSpace" => self.get_most_popular_labels_in_space(args),
+            "getSpacesWithLabel" => self.get_spaces_with_label(args),
+            "getContentPermissionSets" => self.get_content_permission_sets(args),
+            "setContentPermissions" => self.set_content_permissions(args),
+            "getUser" => self.get_user(args),
+            "hasUser" => self.has_user(args),
+            "getUserInformation" => self.get_user_information(args),
+            "addUser" => self.add_user_operation(args),
+            "editUser" => self.edit_user(args),
+            "deactivateUser" => self.set_user_active(args, false),
+            "reactivateUser" => self.set_user_active(args, true),
+            "getGroups" => self.get_groups(args),
+            "addGroup" => self.add_group(args),
+            "addUserToGroup" => self.change_group_membership(args, true),
+            "removeUserFromGroup" => self.change_group_membership(args, false),
+            "getUserGroups" => self.get_user_groups(args),
+            "watchPage" | "removePageWatch" => {
+                let user = self.authenticate(args)?;
+                self.change_page_watch(args, user, operation == "watchPage")
+            }
+            "watchPageForUser" | "removePageWatchForUser" => {
+                self.authenticate_admin(args)?;
+                let watcher = text_arg(args, 2)?;
+                self.find_user(&watcher)?;
+                self.change_page_watch(args, watcher, operation == "watchPageForUser")
+            }
+            "watchSpace" | "removeSpaceWatch" => {
+                self.change_space_watch(args, operation == "watchSpace")
+            }
+            "isWatchingPage" => self.is_watching_page(args),
+            "isWatchingSpace" => self.is_watching_space(args),
+            "getWatchersForPage" => self.get_watchers_for_page(args),
+            "getWatchersForSpace" => self.get_watchers_for_space(args),
+            "search" => self.search(args),
+            "renderContent" => self.render_content(args),
+            "convertWikiToStorageFormat" => self.convert_wiki_to_storage_format(args),
+            "getAttachments" => self.get_attachments(args),
+            "getAttachment" => self.get_attachment(args),
+            "getAttachmentData" => self.get_attachment_data(args),
+            "addAttachment" => self.add_attachment(args),
+            "moveAttachment" => self.move_attachment(args),
+            "removeAttachment" => self.remove_attachment(args),
+            "getComments" => self.get_comments(args),
+            "getComment" => self.get_comment(args),
+            "addComment" => self.add_comment(args),
+            "editComment" => self.edit_comment(args),
+            "removeComment" => self.remove_comment(args),
+            other => Err(Fault::remote(format!("No such operation '{}'", other))),
+        }?;
+
+        Ok(result.with_name(format!("{}Return", operation)))
+    }
+
+    fn authenticate(&self, args: &[Element]) -> Result<String, Fault> {
+        let token = text_arg(args, 0)?;
+        match self.sessions.get(&token) {
+            Some(user) => Ok(user.clone()),
+            None => Err(Fault {
+                exception: "InvalidSessionException",
+                message: "User not authenticated or session expired. Call login() to open a new session".into(),
+            }),
+        }
+    }
+
+    fn login(&mut self, args: &[Element]) -> OpResult {
+        let user = text_arg(args, 0)?;
+        let pass = text_arg(args, 1)?;
+
+        let valid = matches!(
+            self.users.get(&user),
+            Some(stored) if stored.active && stored.password == pass
+        );
+        if !valid {
+            return Err(Fault {
+                exception: "AuthenticationFailedException",
+                message: format!(
+                    "Attempt to log in user '{}' failed - incorrect username/password combination.",
+                    user
+                ),
+            });
+        }
+
+        self.last_session += 1;
+        let token = format!("{:010x}", self.last_session);
+        self.sessions.insert(token.clone(), user);
+
+        Ok(typed("return", "string", token))
+    }
+
+    fn logout(&mut self, args: &[Element]) -> OpResult {
+        let token = text_arg(args, 0)?;
+        let removed = self.sessions.remove(&token).is_some();
+
+        Ok(typed("return", "boolean", removed.to_string()))
+    }
+
+    fn get_server_info(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+
+        Ok(Element::node("return").with_children(vec![
+            typed("baseUrl", "string", self.base_url.clone()),
+            typed("buildId", "string", self.build_id.clone()),
+            typed("developmentBuild", "boolean", "false"),
+            typed("majorVersion", "int", "6"),
+            typed("minorVersion", "int", "15"),
+            typed("patchLevel", "int", "0"),
+        ]))
+    }
+
+    fn get_space(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let key = text_arg(args, 1)?;
+
+        Ok(self.space_element(self.find_space(&key)?))
+    }
+
+    fn get_spaces(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+
+        let items = self
+            .spaces
+            .values()
+            .map(|space| {
+                Element::node("item")
+                    .with_attr("xsi:type", "tns2:RemoteSpaceSummary")
+                    .with_children(vec![
+                        typed("key", "string", space.key.clone()),
+                        typed("name", "string", space.name.clone()),
+                        typed("type", "string", space.space_type.clone()),
+                        typed("url", "string", self.space_url(space)),
+                    ])
+            })
+            .collect();
+
+        Ok(array("RemoteSpaceSummary", items))
+    }
+
+    fn add_space_operation(&mut self, args: &[Element], user_name: Option<String>) -> OpResult {
+        self.authenticate(args)?;
+        let space = arg(args, 1)?;
+
+        let name = required_child_text(space, "name")?;
+        let description = child_text(space, "description");
+        let (key, space_type) = match user_name {
+            Some(user_name) => {
+                if !self.users.contains_key(&user_name) {
+                    return Err(Fault::remote(format!("No user with username: {}", user_name)));
+                }
+                (format!("~{}", user_name), "personal")
+            }
+            None => (required_child_text(space, "key")?, "global"),
+        };
+
+        if self.spaces.contains_key(&key) {
+            return Err(Fault {
+                exception: "AlreadyExistsException",
+                message: format!("A space already exists with key {}", key),
+            });
+        }
+
+        let space = self.insert_space(&key, &name, description, space_type).clone();
+        Ok(self.space_element(&space))
+    }
+
+    fn store_space(&mut self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let space = arg(args, 1)?;
+
+        let key = required_child_text(space, "key")?;
+        self.find_space(&key)?;
+
+        let stored = self.spaces.get_mut(&key).expect("space exists");
+        if let Some(name) = child_text(space, "name") {
+            stored.name = name;
+        }
+        if let Some(description) = child_text(space, "description") {
+            stored.description = Some(description);
+        }
+        if let Some(home_page) = child_long(space, "homePage")?.filter(|&id| id != 0) {
+            stored.home_page = home_page;
+        }
+
+        let stored = stored.clone();
+        Ok(self.space_element(&stored))
+    }
+
+    fn remove_space(&mut self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let key = text_arg(args, 1)?;
+
+        self.find_space(&key)?;
+        self.spaces.remove(&key);
+        self.pages.retain(|_, page| page.space != key);
+        self.blog_entries.retain(|_, entry| entry.space != key);
+        self.page_versions.retain(|_, version| version.page.space != key);
+        self.trash.retain(|_, page| page.space != key);
+        let (pages, blog_entries, trash) = (&self.pages, &self.blog_entries, &self.trash);
+        let exists = |id: &i64| {
+            pages.contains_key(id) || blog_entries.contains_key(id) || trash.contains_key(id)
+        };
+        self.attachments.retain(|_, attachment| exists(&attachment.page_id));
+        self.comments.retain(|_, comment| exists(&comment.page_id));
+        self.labels.retain(|id, _| exists(id));
+        self.content_permissions.retain(|id, _| exists(id));
+        self.page_watches.retain(|id, _| exists(id));
+        self.space_watches.remove(&key);
+
+        Ok(typed("return", "boolean", "true"))
+    }
+
+    fn get_space_status(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let key = text_arg(args, 1)?;
+
+        Ok(typed("return", "string", self.find_space(&key)?.status.clone()))
+    }
+
+    fn set_space_status(&mut self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let key = text_arg(args, 1)?;
+        let status = text_arg(args, 2)?;
+
+        if status != "CURRENT" && status != "ARCHIVED" {
+            return Err(Fault::remote(format!("Invalid space status: {}", status)));
+        }
+        self.find_space(&key)?;
+        self.spaces.get_mut(&key).expect("space exists").status = status;
+
+        Ok(typed("return", "boolean", "true"))
+    }
+
+    fn get_permissions(&self, args: &[Element], user: String) -> OpResult {
+        self.authenticate(args)?;
+        let key = text_arg(args, 1)?;
+        let space = self.find_space(&key)?;
+        if !self.users.contains_key(&user) {
+            return Err(Fault::remote(format!("No user with username: {}", user)));
+        }
+
+        let permissions = SPACE_PERMISSIONS.iter().filter(|&&permission| {
+            !space
+                .denied_permissions
+                .iter()
+                .any(|denied| denied.0 == user && denied.1 == permission)
+        });
+
+        Ok(string_array(permissions))
+    }
+
+    fn change_space_permission(&mut self, args: &[Element], grant: bool) -> OpResult {
+        let user = self.authenticate(args)?;
+        let permission = text_arg(args, 1)?;
+        let entity = text_arg(args, 2)?;
+        let key = text_arg(args, 3)?;
+        self.check_permission(&user, &key, "SETSPACEPERMISSIONS")?;
+        if !SPACE_PERMISSIONS.contains(&permission.as_str()) {
+            return Err(Fault::remote(format!("Unknown permission: {}", permission)));
+        }
+
+        let space = self.spaces.get_mut(&key).expect("space exists");
+        space
+            .denied_permissions
+            .retain(|denied| denied.0 != entity || denied.1 != permission);
+        if !grant {
+            space.denied_permissions.push((entity, permission));
+        }
+
+        Ok(typed("return", "boolean", "true"))
+    }
+
+    fn find_space(&self, key: &str) -> Result<&FakeSpace, Fault> {
+        self.spaces
+            .get(key)
+            .ok_or_else(|| Fault::remote(format!("No space found for space key: {}", key)))
+    }
+
+    fn space_url(&self, space: &FakeSpace) -> String {
+        format!("{}/display/{}", self.base_url, space.key)
+    }
+
+    fn space_element(&self, space: &FakeSpace) -> Element {
+        let mut children = vec![
+            typed("homePage", "long", space.home_page.to_string()),
+            typed("key", "string", space.key.clone()),
+            typed("name", "string", space.name.clone()),
+            typed("type", "string", space.space_type.clone()),
+            typed("url", "string", self.space_url(space)),
+        ];
+        if let Some(ref description) = space.description {
+            children.push(typed("description", "string", description.clone()));
+        } else {
+            children.push(Element::node("description").with_attr("xsi:nil", "true"));
+        }
+
+        Element::node("return").with_children(children)
+    }
+
+    fn get_page(&self, args: &[Element]) -> OpResult {
+        let user = self.authenticate(args)?;
+
+        let page = if args.len() > 2 {
+            let space = text_arg(args, 1)?;
+            let title = text_arg(args, 2)?;
+            self.pages
+                .values()
+                .find(|page| page.space == space && page.title == title)
+        } else {
+            let id = long_arg(args, 1)?;
+            if let Some(version) = self.page_versions.get(&id) {
+                return Ok(self.historical_page_element(version));
+            }
+            if let Some(page) = self.trash.get(&id) {
+                return Ok(self.trashed_page_element(page));
+            }
+            self.pages.get(&id)
+        };
+
+        match page {
+            Some(page) => {
+                self.check_content_permission(&user, page.id, "View")?;
+                Ok(self.page_element(page))
+            }
+            None => Err(Fault::remote(
+                "The page you are trying to access does not exist",
+            )),
+        }
+    }
+
+    fn store_page(&mut self, args: &[Element], update: Option<Option<String>>) -> OpResult {
+        let user = self.authenticate(args)?;
+        let page = arg(args, 1)?;
+
+        let space = required_child_text(page, "space")?;
+        let title = required_child_text(page, "title")?;
+        let content = child_text(page, "content").unwrap_or_default();
+        // zero ids are sent for new pages and top level pages
+        let parent_id = child_long(page, "parentId")?.filter(|&id| id != 0);
+
+        if !self.spaces.contains_key(&space) {
+            return Err(Fault::remote(format!("No space found for space key: {}", space)));
+        }
+        if let Some(parent_id) = parent_id {
+            if !self.pages.contains_key(&parent_id) {
+                return Err(Fault::remote(format!("The parent page {} does not exist", parent_id)));
+            }
+        }
+        let duplicate = |id: Option<i64>| {
+            self.pages
+                .values()
+                .any(|page| page.space == space && page.title == title && Some(page.id) != id)
+        };
+
+        let id = match child_long(page, "id")?.filter(|&id| id != 0) {
+            Some(id) => {
+                if duplicate(Some(id)) {
+                    return Err(Fault::remote("A page with this title already exists in this space."));
+                }
+                let version = child_long(page, "version")?;
+                if self.pages.contains_key(&id) {
+                    self.check_content_permission(&user, id, "Edit")?;
+                }
+                let stored = match self.pages.get_mut(&id) {
+                    Some(stored) => stored,
+                    None => return Err(Fault::remote("The page you are trying to access does not exist")),
+                };
+                if version != Some(i64::from(stored.version)) {
+                    return Err(Fault::remote(
+                        "You're trying to edit an outdated version of that page.",
+                    ));
+                }
+                let previous = stored.clone();
+                stored.space = space;
+                stored.title = title;
+                stored.content = content;
+                if let Some(parent_id) = parent_id {
+                    stored.parent_id = parent_id;
+                }
+                stored.version += 1;
+                stored.version_comment = update.and_then(|comment| comment);
+                stored.modified = Utc::now();
+                stored.modifier = user;
+                self.archive_page_version(previous);
+                id
+            }
+            None if update.is_some() => {
+                return Err(Fault::remote("The page must have an id to be updated"));
+            }
+            None => {
+                if duplicate(None) {
+                    return Err(Fault::remote("The page you are trying to create already exists."));
+                }
+                self.create_page(&space, parent_id.unwrap_or(0), &title, &content, &user)
+                    .id
+            }
+        };
+
+        Ok(self.page_element(&self.pages[&id]))
+    }
+
+    fn get_page_history(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let page_id = self.existing_page_id(args)?;
+
+        let items = self
+            .page_versions
+            .values()
+            .rev()
+            .filter(|version| version.page.id == page_id)
+            .map(|version| {
+                let mut children = vec![
+                    typed("id", "long", version.id.to_string()),
+                    typed("modified", "dateTime", datetime(&version.page.modified)),
+                    typed("modifier", "string", version.page.modifier.clone()),
+                    typed("version", "int", version.page.version.to_string()),
+                ];
+                if let Some(ref comment) = version.page.version_comment {
+                    children.push(typed("versionComment", "string", comment.clone()));
+                }
+                Element::node("item")
+                    .with_attr("xsi:type", "tns2:RemotePageHistory")
+                    .with_children(children)
+            })
+            .collect();
+
+        Ok(array("RemotePageHistory", items))
+    }
+
+    /// Keep the page as it was before an update.
+    fn archive_page_version(&mut self, page: FakePage) {
+        self.last_id += 1;
+        let id = self.last_id;
+        self.page_versions.insert(id, FakePageVersion { id, page });
+    }
+
+    fn remove_page(&mut self, args: &[Element]) -> OpResult {
+        let user = self.authenticate(args)?;
+        let page_id = self.existing_page_id(args)?;
+        let space = self.pages[&page_id].space.clone();
+        self.check_permission(&user, &space, "REMOVEPAGE")?;
+
+        let page = self.pages.remove(&page_id).expect("page exists");
+        // children stay in place of the removed page
+        for child in self.pages.values_mut() {
+            if child.parent_id == page_id {
+                child.parent_id = page.parent_id;
+            }
+        }
+        self.trash.insert(page_id, page);
+
+        Ok(typed("return", "boolean", "true"))
+    }
+
+    fn remove_page_version_by_version(&mut self, args: &[Element]) -> OpResult {
+        let user = self.authenticate(args)?;
+        let page_id = self.existing_page_id(args)?;
+        let version = long_arg(args, 2)?;
+        let space = self.pages[&page_id].space.clone();
+        self.check_permission(&user, &space, "REMOVEPAGE")?;
+
+        let id = self
+            .page_versions
+            .values()
+            .find(|stored| stored.page.id == page_id && i64::from(stored.page.version) == version)
+            .map(|stored| stored.id)
+            .ok_or_else(|| {
+                Fault::remote(format!("Version {} of page {} does not exist", version, page_id))
+            })?;
+        self.page_versions.remove(&id);
+
+        Ok(typed("return", "boolean", "true"))
+    }
+
+    fn get_trash_contents(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let key = text_arg(args, 1)?;
+        let offset = long_arg(args, 2)?.max(0);
+        let count = long_arg(args, 3)?.max(0);
+        self.find_space(&key)?;
+
+        let trashed = self
+            .trash
+            .values()
+            .filter(|page| page.space == key)
+            .collect::<Vec<_>>();
+        let items = trashed
+            .iter()
+            .skip(offset as usize)
+            .take(count as usize)
+            .map(|page| {
+                Element::node("item")
+                    .with_attr("xsi:type", "tns2:RemoteContentSummary")
+                    .with_children(vec![
+                        typed("created", "dateTime", datetime(&page.created)),
+                        typed("creator", "string", page.creator.clone()),
+                        typed("id", "long", page.id.to_string()),
+                        typed("modified", "dateTime", datetime(&page.modified)),
+                        typed("modifier", "string", page.modifier.clone()),
+                        typed("space", "string", page.space.clone()),
+                        typed("status", "string", "deleted"),
+                        typed("title", "string", page.title.clone()),
+                        typed("type", "string", "page"),
+                    ])
+            })
+            .collect();
+
+        Ok(Element::node("return").with_children(vec![
+            array("RemoteContentSummary", items).with_name("content"),
+            typed("offset", "int", offset.to_string()),
+            typed("totalAvailable", "int", trashed.len().to_string()),
+        ]))
+    }
+
+    fn purge_from_trash(&mut self, args: &[Element]) -> OpResult {
+        let user = self.authenticate(args)?;
+        let key = text_arg(args, 1)?;
+        let id = long_arg(args, 2)?;
+        self.find_space(&key)?;
+        self.check_permission(&user, &key, "SETSPACEPERMISSIONS")?;
+
+        let in_trash = matches!(self.trash.get(&id), Some(page) if page.space == key);
+        if !in_trash {
+            return Err(Fault::remote(format!(
+                "Content {} does not exist in the trash of space {}",
+                id, key
+            )));
+        }
+        self.purge(&[id]);
+
+        Ok(typed("return", "boolean", "true"))
+    }
+
+    fn empty_trash(&mut self, args: &[Element]) -> OpResult {
+        let user = self.authenticate(args)?;
+        let key = text_arg(args, 1)?;
+        self.find_space(&key)?;
+        self.check_permission(&user, &key, "SETSPACEPERMISSIONS")?;
+
+        let ids = self
+            .trash
+            .values()
+            .filter(|page| page.space == key)
+            .map(|page| page.id)
+            .collect::<Vec<_>>();
+        self.purge(&ids);
+
+        Ok(typed("return", "boolean", "true"))
+    }
+
+    /// Remove trashed pages together with everything that belongs to them.
+    fn purge(&mut self, ids: &[i64]) {
+        for id in ids {
+            self.trash.remove(id);
+            self.labels.remove(id);
+            self.content_permissions.remove(id);
+            self.page_watches.remove(id);
+        }
+        self.page_versions.retain(|_, version| !ids.contains(&version.page.id));
+        self.attachments.retain(|_, attachment| !ids.contains(&attachment.page_id));
+        self.comments.retain(|_, comment| !ids.contains(&comment.page_id));
+    }
+
+    fn check_permission(&self, user: &str, key: &str, permission: &str) -> Result<(), Fault> {
+        let denied = self.find_space(key)?.denied_permissions.iter().any(|denied| {
+            denied.0 == user && denied.1 == permission
+        });
+        if denied {
+            return Err(Fault {
+                exception: "NotPermittedException",
+                message: format!("You do not have the {} permission in space {}", permission, key),
+            });
+        }
+        Ok(())
+    }
+
+    fn move_page(&mut self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let page_id = self.existing_page_id(args)?;
+        let target_id = long_arg(args, 2)?;
+        let position = text_arg(args, 3)?;
+
+        if target_id == page_id {
+            return Err(Fault::remote("A page can not be moved relative to itself"));
+        }
+        let target = match self.pages.get(&target_id) {
+            Some(target) => target,
+            None => {
+                return Err(Fault::remote(format!(
+                    "The target page {} does not exist",
+                    target_id
+                )))
+            }
+        };
+        let space = target.space.clone();
+        let (parent_id, index) = match position.as_str() {
+            "above" | "below" => {
+                let index = self
+                    .child_pages(target.parent_id, &space)
+                    .iter()
+                    .filter(|page| page.id != page_id)
+                    .position(|page| page.id == target_id)
+                    .unwrap_or(0);
+                let index = if position == "below" { index + 1 } else { index };
+                (target.parent_id, Some(index))
+            }
+            "append" => (target_id, None),
+            "topLevel" => (0, None),
+            other => return Err(Fault::remote(format!("Invalid position: {}", other))),
+        };
+
+        self.place_page(page_id, &space, parent_id, index)?;
+
+        Ok(typed("return", "boolean", "true"))
+    }
+
+    fn move_page_to_top_level(&mut self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let page_id = self.existing_page_id(args)?;
+        let key = text_arg(args, 2)?;
+        self.find_space(&key)?;
+
+        self.place_page(page_id, &key, 0, None)?;
+
+        Ok(typed("return", "boolean", "true"))
+    }
+
+    /// Move page with its descendants under the parent, at the index among the siblings.
+    fn place_page(
+        &mut self,
+        page_id: i64,
+        space: &str,
+        parent_id: i64,
+        index: Option<usize>,
+    ) -> Result<(), Fault> {
+        let mut moved = vec![page_id];
+        let mut parents = vec![page_id];
+        while let Some(id) = parents.pop() {
+            for page in self.pages.values().filter(|page| page.parent_id == id) {
+                moved.push(page.id);
+                parents.push(page.id);
+            }
+        }
+        if moved.contains(&parent_id) {
+            return Err(Fault::remote("A page can not be moved below itself"));
+        }
+        let clash = self.pages.values().any(|page| {
+            page.space == space
+                && !moved.contains(&page.id)
+                && moved.iter().any(|id| self.pages[id].title == page.title)
+        });
+        if clash {
+            return Err(Fault::remote("A page with this title already exists in this space."));
+        }
+
+        let mut siblings = self
+            .child_pages(parent_id, space)
+            .iter()
+            .map(|page| page.id)
+            .filter(|&id| id != page_id)
+            .collect::<Vec<_>>();
+        siblings.insert(index.unwrap_or(siblings.len()).min(siblings.len()), page_id);
+
+        for id in moved {
+            self.pages.get_mut(&id).expect("page exists").space = space.into();
+        }
+        self.pages.get_mut(&page_id).expect("page exists").parent_id = parent_id;
+        for (position, id) in siblings.into_iter().enumerate() {
+            self.pages.get_mut(&id).expect("page exists").position = position as i64;
+        }
+        Ok(())
+    }
+
+    /// Children of the page, or top level pages of the space for `0`, in their order.
+    fn child_pages(&self, parent_id: i64, space: &str) -> Vec<&FakePage> {
+        let mut children = self
+            .pages
+            .values()
+            .filter(|page| page.parent_id == parent_id && page.space == space)
+            .collect::<Vec<_>>();
+        children.sort_by_key(|page| page.position);
+        children
+    }
+
+    fn get_children(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let page_id = self.existing_page_id(args)?;
+        let space = &self.pages[&page_id].space;
+
+        let items = self
+            .child_pages(page_id, space)
+            .into_iter()
+            .map(|page| self.page_summary_element(page))
+            .collect::<Vec<_>>();
+
+        Ok(array("RemotePageSummary", items))
+    }
+
+    fn get_descendents(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let page_id = self.existing_page_id(args)?;
+
+        let mut items = vec![];
+        let mut parents = vec![page_id];
+        while let Some(parent_id) = parents.pop() {
+            let space = &self.pages[&parent_id].space;
+            for page in self.child_pages(parent_id, space) {
+                items.push(self.page_summary_element(page));
+                parents.push(page.id);
+            }
+        }
+
+        Ok(array("RemotePageSummary", items))
+    }
+
+    fn get_ancestors(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let page_id = self.existing_page_id(args)?;
+
+        let mut items = vec![];
+        let mut parent_id = self.pages[&page_id].parent_id;
+        while let Some(parent) = self.pages.get(&parent_id) {
+            items.insert(0, self.page_summary_element(parent));
+            parent_id = parent.parent_id;
+        }
+
+        Ok(array("RemotePageSummary", items))
+    }
+
+    fn get_pages(&self, args: &[Element], top_level: bool) -> OpResult {
+        self.authenticate(args)?;
+        let key = text_arg(args, 1)?;
+        self.find_space(&key)?;
+
+        let items = if top_level {
+            self.child_pages(0, &key)
+        } else {
+            self.pages.values().filter(|page| page.space == key).collect()
+        }
+        .into_iter()
+        .map(|page| self.page_summary_element(page))
+        .collect();
+
+        Ok(array("RemotePageSummary", items))
+    }
+
+    fn get_page_summary(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let page_id = self.existing_page_id(args)?;
+
+        Ok(self
+            .page_summary_element(&self.pages[&page_id])
+            .with_name("return"))
+    }
+
+    fn get_blog_entries(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let key = text_arg(args, 1)?;
+        self.find_space(&key)?;
+
+        let items = self
+            .blog_entries
+            .values()
+            .filter(|entry| entry.space == key)
+            .map(|entry| {
+                Element::node("item")
+                    .with_attr("xsi:type", "tns2:RemoteBlogEntrySummary")
+                    .with_children(vec![
+                        typed("author", "string", entry.creator.clone()),
+                        typed("id", "long", entry.id.to_string()),
+                        typed("permissions", "int", "0"),
+                        typed("publishDate", "dateTime", datetime(&entry.publish_date)),
+                        typed("space", "string", entry.space.clone()),
+                        typed("title", "string", entry.title.clone()),
+                        typed("url", "string", self.blog_entry_url(entry)),
+                    ])
+            })
+            .collect();
+
+        Ok(array("RemoteBlogEntrySummary", items))
+    }
+
+    fn get_blog_entry(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let id = long_arg(args, 1)?;
+
+        match self.blog_entries.get(&id) {
+            Some(entry) => Ok(self.blog_entry_element(entry)),
+            None => Err(Fault::remote(format!("No blog entry found with id: {}", id))),
+        }
+    }
+
+    fn get_blog_entry_by_day_and_title(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let key = text_arg(args, 1)?;
+        let day = long_arg(args, 2)?;
+        let title = text_arg(args, 3)?;
+
+        let entry = self.blog_entries.values().find(|entry| {
+            entry.space == key
+                && entry.title == title
+                && i64::from(entry.publish_date.day()) == day
+        });
+        match entry {
+            Some(entry) => Ok(self.blog_entry_element(entry)),
+            None => Err(Fault::remote(format!(
+                "No blog entry found in space {} on day {} with title: {}",
+                key, day, title
+            ))),
+        }
+    }
+
+    fn store_blog_entry(&mut self, args: &[Element]) -> OpResult {
+        let user = self.authenticate(args)?;
+        let entry = arg(args, 1)?;
+
+        let space = required_child_text(entry, "space")?;
+        let title = required_child_text(entry, "title")?;
+        let content = child_text(entry, "content").unwrap_or_default();
+        let publish_date = match child_text(entry, "publishDate") {
+            Some(text) => DateTime::parse_from_rfc3339(text.trim())
+                .map(|date| date.with_timezone(&Utc))
+                .map_err(|_| Fault::remote(format!("Invalid publish date: {}", text)))?,
+            None => Utc::now(),
+        };
+        self.find_space(&space)?;
+
+        let id = match child_long(entry, "id")?.filter(|&id| id != 0) {
+            Some(id) => {
+                let version = child_long(entry, "version")?;
+                let stored = match self.blog_entries.get_mut(&id) {
+                    Some(stored) => stored,
+                    None => {
+                        return Err(Fault::remote(format!("No blog entry found with id: {}", id)))
+                    }
+                };
+                if version != Some(i64::from(stored.version)) {
+                    return Err(Fault::remote(
+                        "You're trying to edit an outdated version of that blog post.",
+                    ));
+                }
+                stored.space = space;
+                stored.title = title;
+                stored.content = content;
+                stored.publish_date = publish_date;
+                stored.version += 1;
+                stored.modified = Utc::now();
+                stored.modifier = user;
+                id
+            }
+            None => {
+                self.last_id += 1;
+                let now = Utc::now();
+                let stored = FakeBlogEntry {
+                    id: self.last_id,
+                    space,
+                    title,
+                    content,
+                    version: 1,
+                    publish_date,
+                    created: now,
+                    creator: user.clone(),
+                    modified: now,
+                    modifier: user,
+                };
+                self.blog_entries.insert(stored.id, stored);
+                self.last_id
+            }
+        };
+
+        Ok(self.blog_entry_element(&self.blog_entries[&id]))
+    }
+
+    fn blog_entry_url(&self, entry: &FakeBlogEntry) -> String {
+        format!("{}/pages/viewpage.action?pageId={}", self.base_url, entry.id)
+    }
+
+    fn blog_entry_element(&self, entry: &FakeBlogEntry) -> Element {
+        Element::node("return").with_children(vec![
+            typed("content", "string", entry.content.clone()),
+            typed("contentStatus", "string", "current"),
+            typed("created", "dateTime", datetime(&entry.created)),
+            typed("creator", "string", entry.creator.clone()),
+            typed("current", "boolean", "true"),
+            typed("homePage", "boolean", "false"),
+            typed("id", "long", entry.id.to_string()),
+            typed("modified", "dateTime", datetime(&entry.modified)),
+            typed("modifier", "string", entry.modifier.clone()),
+            typed("space", "string", entry.space.clone()),
+            typed("title", "string", entry.title.clone()),
+            typed("url", "string", self.blog_entry_url(entry)),
+            typed("version", "int", entry.version.to_string()),
+            typed("permissions", "int", "0"),
+            typed("publishDate", "dateTime", datetime(&entry.publish_date)),
+        ])
+    }
+
+    fn get_labels_by_id(&mut self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let id = self.existing_content_id(args)?;
+
+        let names = self.labels.get(&id).cloned().unwrap_or_default();
+        let items = names.iter().map(|name| self.label_element(name)).collect();
+
+        Ok(array("RemoteLabel", items))
+    }
+
+    fn add_label_by_name(&mut self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let names = label_names(&text_arg(args, 1)?)?;
+        let id = long_arg(args, 2)?;
+        if !self.content_exists(id) {
+            return Err(Fault::remote("The page you are trying to access does not exist"));
+        }
+
+        let labels = self.labels.entry(id).or_default();
+        for name in names {
+            if !labels.contains(&name) {
+                labels.push(name);
+            }
+        }
+
+        Ok(typed("return", "boolean", "true"))
+    }
+
+    fn remove_label_by_name(&mut self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let names = label_names(&text_arg(args, 1)?)?;
+        let id = long_arg(args, 2)?;
+        if !self.content_exists(id) {
+            return Err(Fault::remote("The page you are trying to access does not exist"));
+        }
+
+        if let Some(labels) = self.labels.get_mut(&id) {
+            labels.retain(|label| !names.contains(label));
+        }
+
+        Ok(typed("return", "boolean", "true"))
+    }
+
+    fn get_label_content_by_name(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let name = text_arg(args, 1)?.to_lowercase();
+
+        let items = self
+            .labels
+            .iter()
+            .filter(|(_, labels)| labels.contains(&name))
+            .filter_map(|(&id, _)| self.search_result_element(id))
+            .collect();
+
+        Ok(array("RemoteSearchResult", items))
+    }
+
+    fn get_most_popular_labels_in_space(&mut self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let key = text_arg(args, 1)?;
+        let max_count = long_arg(args, 2)?.max(0) as usize;
+        self.find_space(&key)?;
+
+        let mut counts = BTreeMap::<String, usize>::new();
+        for (id, labels) in &self.labels {
+            if self.content_space(*id) == Some(key.as_str()) {
+                for label in labels {
+                    *counts.entry(label.clone()).or_default() += 1;
+                }
+            }
+        }
+        let mut popular = counts.into_iter().collect::<Vec<_>>();
+        popular.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        let items = popular
+            .iter()
+            .take(max_count)
+            .map(|(name, _)| self.label_element(name))
+            .collect();
+
+        Ok(array("RemoteLabel", items))
+    }
+
+    fn get_spaces_with_label(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let name = text_arg(args, 1)?.to_lowercase();
+
+        let items = self
+            .spaces
+            .values()
+            .filter(|space| space.labels.contains(&name))
+            .map(|space| {
+                self.space_element(space)
+                    .with_name("item")
+                    .with_attr("xsi:type", "tns2:RemoteSpace")
+            })
+            .collect();
+
+        Ok(array("RemoteSpace", items))
+    }
+
+    fn get_content_permission_sets(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let id = self.existing_content_id(args)?;
+
+        let permissions = self.content_permissions.get(&id).cloned().unwrap_or_default();
+        let items = ["View", "Edit"]
+            .iter()
+            .map(|&permission_type| {
+                let items = permissions
+                    .iter()
+                    .filter(|permission| permission.permission_type == permission_type)
+                    .map(|permission| {
+                        let optional = |name: &str, value: &Option<String>| match *value {
+                            Some(ref value) => typed(name, "string", value.clone()),
+                            None => Element::node(name).with_attr("xsi:nil", "true"),
+                        };
+                        Element::node("item")
+                            .with_attr("xsi:type", "tns2:RemoteContentPermission")
+                            .with_children(vec![
+                                optional("groupName", &permission.group_name),
+                                typed("type", "string", permission_type),
+                                optional("userName", &permission.user_name),
+                            ])
+                    })
+                    .collect();
+                Element::node("item")
+                    .with_attr("xsi:type", "tns2:RemoteContentPermissionSet")
+                    .with_children(vec![
+                        array("RemoteContentPermission", items).with_name("contentPermissions"),
+                        typed("type", "string", permission_type),
+                    ])
+            })
+            .collect();
+
+        Ok(array("RemoteContentPermissionSet", items))
+    }
+
+    fn set_content_permissions(&mut self, args: &[Element]) -> OpResult {
+        let user = self.authenticate(args)?;
+        let id = self.existing_content_id(args)?;
+        let permission_type = text_arg(args, 2)?;
+        let space = match self.pages.get(&id) {
+            Some(page) => page.space.clone(),
+            None => self.blog_entries[&id].space.clone(),
+        };
+        self.check_permission(&user, &space, "SETPAGEPERMISSIONS")?;
+        if permission_type != "View" && permission_type != "Edit" {
+            return Err(Fault::remote(format!("Invalid permission type: {}", permission_type)));
+        }
+
+        let added = arg(args, 3)?
+            .children
+            .iter()
+            .map(|item| FakeContentPermission {
+                permission_type: permission_type.clone(),
+                user_name: child_text(item, "userName"),
+                group_name: child_text(item, "groupName"),
+            })
+            .collect::<Vec<_>>();
+        let permissions = self.content_permissions.entry(id).or_default();
+        permissions.retain(|permission| permission.permission_type != permission_type);
+        permissions.extend(added);
+
+        Ok(typed("return", "boolean", "true"))
+    }
+
+    /// Fail unless the user is allowed by the restrictions of the type, if there are any.
+    fn check_content_permission(
+        &self,
+        user: &str,
+        id: i64,
+        permission_type: &str,
+    ) -> Result<(), Fault> {
+        let mut restrictions = self
+            .content_permissions
+            .get(&id)
+            .into_iter()
+            .flatten()
+            .filter(|permission| permission.permission_type == permission_type)
+            .peekable();
+        if restrictions.peek().is_none()
+            || restrictions.any(|permission| {
+                permission.user_name.as_deref() == Some(user)
+                    || matches!(
+                        (&permission.group_name, self.users.get(user)),
+                        (Some(group), Some(stored)) if stored.groups.contains(group)
+                    )
+            })
+        {
+            return Ok(());
+        }
+        Err(Fault {
+            exception: "NotPermittedException",
+            message: format!(
+                "You're not allowed to {} this content",
+                permission_type.to_lowercase()
+            ),
+        })
+    }
+
+    fn get_user(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let user = self.find_user(&text_arg(args, 1)?)?;
+
+        Ok(self.user_element(user))
+    }
+
+    fn user_element(&self, user: &FakeUser) -> Element {
+        Element::node("return").with_children(vec![
+            typed("email", "string", user.email.clone()),
+            typed("fullname", "string", user.full_name.clone()),
+            typed("name", "string", user.name.clone()),
+            typed("url", "string", format!("{}/display/~{}", self.base_url, user.name)),
+        ])
+    }
+
+    fn has_user(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let name = text_arg(args, 1)?;
+
+        Ok(typed("return", "boolean", self.users.contains_key(&name).to_string()))
+    }
+
+    fn get_user_information(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let user = self.find_user(&text_arg(args, 1)?)?;
+
+        Ok(Element::node("return").with_children(vec![
+            typed("content", "string", ""),
+            typed("creationDate", "dateTime", datetime(&user.created)),
+            typed("creatorName", "string", "admin"),
+            typed("id", "long", user.id.to_string()),
+            typed("lastModificationDate", "dateTime", datetime(&user.created)),
+            typed("lastModifierName", "string", "admin"),
+            typed("username", "string", user.name.clone()),
+            typed("version", "int", "1"),
+        ]))
+    }
+
+    fn add_user_operation(&mut self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let user = arg(args, 1)?;
+        let password = text_arg(args, 2)?;
+
+        let name = required_child_text(user, "name")?;
+        if self.users.contains_key(&name) {
+            return Err(Fault {
+                exception: "AlreadyExistsException",
+                message: format!("A user with the username {} already exists", name),
+            });
+        }
+        let full_name = child_text(user, "fullname").unwrap_or_default();
+        let email = child_text(user, "email").unwrap_or_default();
+        self.add_user(&name, &full_name, &email, &password);
+
+        Ok(Element::node("return"))
+    }
+
+    fn edit_user(&mut self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let user = arg(args, 1)?;
+
+        let name = required_child_text(user, "name")?;
+        self.find_user(&name)?;
+        let stored = self.users.get_mut(&name).expect("user exists");
+        if let Some(full_name) = child_text(user, "fullname") {
+            stored.full_name = full_name;
+        }
+        if let Some(email) = child_text(user, "email") {
+            stored.email = email;
+        }
+
+        Ok(typed("return", "boolean", "true"))
+    }
+
+    fn set_user_active(&mut self, args: &[Element], active: bool) -> OpResult {
+        self.authenticate(args)?;
+        let name = text_arg(args, 1)?;
+
+        self.find_user(&name)?;
+        self.users.get_mut(&name).expect("user exists").active = active;
+        if !active {
+            self.sessions.retain(|_, user| *user != name);
+        }
+
+        Ok(typed("return", "boolean", "true"))
+    }
+
+    fn find_user(&self, name: &str) -> Result<&FakeUser, Fault> {
+        self.users
+            .get(name)
+            .ok_or_else(|| Fault::remote(format!("No user with username: {}", name)))
+    }
+
+    fn get_groups(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+
+        Ok(string_array(&self.groups))
+    }
+
+    fn add_group(&mut self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let name = text_arg(args, 1)?;
+
+        if !self.groups.insert(name.clone()) {
+            return Err(Fault {
+                exception: "AlreadyExistsException",
+                message: format!("A group with the name {} already exists", name),
+            });
+        }
+
+        Ok(typed("return", "boolean", "true"))
+    }
+
+    fn change_group_membership(&mut self, args: &[Element], member: bool) -> OpResult {
+        self.authenticate(args)?;
+        let name = text_arg(args, 1)?;
+        let group = text_arg(args, 2)?;
+
+        self.find_user(&name)?;
+        if !self.groups.contains(&group) {
+            return Err(Fault::remote(format!("No group with name: {}", group)));
+        }
+        let groups = &mut self.users.get_mut(&name).expect("user exists").groups;
+        groups.retain(|stored| *stored != group);
+        if member {
+            groups.push(group);
+        }
+
+        Ok(typed("return", "boolean", "true"))
+    }
+
+    fn get_user_groups(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let user = self.find_user(&text_arg(args, 1)?)?;
+
+        Ok(string_array(&user.groups))
+    }
+
+    /// Authenticate user that is a member of `confluence-administrators`.
+    fn authenticate_admin(&self, args: &[Element]) -> Result<String, Fault> {
+        let user = self.authenticate(args)?;
+        let admin = matches!(
+            self.users.get(&user),
+            Some(stored) if stored.groups.iter().any(|group| group == "confluence-administrators")
+        );
+        if !admin {
+            return Err(Fault {
+                exception: "NotPermittedException",
+                message: "Only administrators can manage watches of other users".into(),
+            });
+        }
+        Ok(user)
+    }
+
+    fn change_page_watch(&mut self, args: &[Element], watcher: String, watch: bool) -> OpResult {
+        let id = self.existing_content_id(args)?;
+
+        let watchers = self.page_watches.entry(id).or_default();
+        if watch {
+            watchers.insert(watcher);
+        } else {
+            watchers.remove(&watcher);
+        }
+
+        Ok(typed("return", "boolean", "true"))
+    }
+
+    fn change_space_watch(&mut self, args: &[Element], watch: bool) -> OpResult {
+        let user = self.authenticate(args)?;
+        let key = text_arg(args, 1)?;
+        self.find_space(&key)?;
+
+        let watchers = self.space_watches.entry(key).or_default();
+        if watch {
+            watchers.insert(user);
+        } else {
+            watchers.remove(&user);
+        }
+
+        Ok(typed("return", "boolean", "true"))
+    }
+
+    fn is_watching_page(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let id = self.existing_content_id(args)?;
+        let user = text_arg(args, 2)?;
+
+        let watching = matches!(
+            self.page_watches.get(&id),
+            Some(watchers) if watchers.contains(&user)
+        );
+
+        Ok(typed("return", "boolean", watching.to_string()))
+    }
+
+    fn is_watching_space(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let key = text_arg(args, 1)?;
+        let user = text_arg(args, 2)?;
+        self.find_space(&key)?;
+
+        let watching = matches!(
+            self.space_watches.get(&key),
+            Some(watchers) if watchers.contains(&user)
+        );
+
+        Ok(typed("return", "boolean", watching.to_string()))
+    }
+
+    fn get_watchers_for_page(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let id = self.existing_content_id(args)?;
+
+        Ok(self.watchers_element(self.page_watches.get(&id)))
+    }
+
+    fn get_watchers_for_space(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let key = text_arg(args, 1)?;
+        self.find_space(&key)?;
+
+        Ok(self.watchers_element(self.space_watches.get(&key)))
+    }
+
+    fn watchers_element(&self, watchers: Option<&BTreeSet<String>>) -> Element {
+        let items = watchers
+            .into_iter()
+            .flatten()
+            .filter_map(|name| self.users.get(name))
+            .map(|user| {
+                self.user_element(user)
+                    .with_name("item")
+                    .with_attr("xsi:type", "tns2:RemoteUser")
+            })
+            .collect();
+
+        array("RemoteUser", items)
+    }
+
+    fn render_content(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let key = text_arg(args, 1)?;
+        let page_id = long_arg(args, 2)?;
+        let content = arg(args, 3)?.text.clone().unwrap_or_default();
+        let parameters = if args.len() > 4 {
+            map_arg(args, 4)?
+        } else {
+            HashMap::new()
+        };
+        let space = self.find_space(&key)?;
+
+        let (title, stored) = if page_id == 0 {
+            (space.name.as_str(), "")
+        } else {
+            let page = self.pages.get(&page_id).ok_or_else(|| {
+                Fault::remote("The page you are trying to access does not exist")
+            })?;
+            (page.title.as_str(), page.content.as_str())
+        };
+        let content = if content.is_empty() { stored } else { content.as_str() };
+        let body = format!(r#"<div id="main-content" class="wiki-content">{}</div>"#, content);
+
+        let html = match parameters.get("style").map(String::as_str) {
+            Some("clean") => body,
+            _ => format!(
+                "<html><head><title>{}</title></head><body>{}</body></html>",
+                escape(title),
+                body
+            ),
+        };
+
+        Ok(typed("return", "string", html))
+    }
+
+    fn convert_wiki_to_storage_format(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let wiki = arg(args, 1)?.text.clone().unwrap_or_default();
+
+        let storage = wiki
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| match heading_level(line) {
+                Some(level) => format!("<h{0}>{1}</h{0}>", level, escape(line[3..].trim())),
+                None => format!("<p>{}</p>", escape(line)),
+            })
+            .collect::<String>();
+
+        Ok(typed("return", "string", storage))
+    }
+
+    fn search(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let query = text_arg(args, 1)?.to_lowercase();
+        let (parameters, max_results) = if args.len() > 3 {
+            (map_arg(args, 2)?, long_arg(args, 3)?)
+        } else {
+            (HashMap::new(), long_arg(args, 2)?)
+        };
+        let terms = query.split_whitespace().collect::<Vec<_>>();
+        let now = Utc::now();
+        let since = match parameters.get("modified").map(String::as_str) {
+            None => None,
+            Some("TODAY") => Some(start_of_day(now)),
+            Some("YESTERDAY") => Some(start_of_day(now - Duration::days(1))),
+            Some("LASTWEEK") => Some(now - Duration::weeks(1)),
+            Some("LASTMONTH") => Some(now - Duration::days(30)),
+            Some(other) => {
+                return Err(Fault::remote(format!("Invalid modified window: {}", other)))
+            }
+        };
+
+        let pages = self.pages.values().map(|page| SearchCandidate {
+            id: page.id,
+            content_type: "page",
+            space: &page.space,
+            title: &page.title,
+            content: &page.content,
+            contributors: [&page.creator, &page.modifier],
+            modified: page.modified,
+            url: self.page_url(page),
+        });
+        let blog_entries = self.blog_entries.values().map(|entry| SearchCandidate {
+            id: entry.id,
+            content_type: "blogpost",
+            space: &entry.space,
+            title: &entry.title,
+            content: &entry.content,
+            contributors: [&entry.creator, &entry.modifier],
+            modified: entry.modified,
+            url: self.blog_entry_url(entry),
+        });
+
+        let items = pages
+            .chain(blog_entries)
+            .filter(|found| {
+                let text = format!("{} {}", found.title, found.content).to_lowercase();
+                terms.iter().all(|term| text.contains(term))
+                    && parameters.get("spaceKey").into_iter().all(|key| key == found.space)
+                    && parameters.get("type").into_iter().all(|kind| kind == found.content_type)
+                    && parameters
+                        .get("contributor")
+                        .into_iter()
+                        .all(|user| found.contributors.contains(&user.as_str()))
+                    && since.into_iter().all(|since| found.modified >= since)
+            })
+            .take(max_results.max(0) as usize)
+            .map(|found| {
+                Element::node("item")
+                    .with_attr("xsi:type", "tns2:RemoteSearchResult")
+                    .with_children(vec![
+                        typed("excerpt", "string", excerpt(found.content)),
+                        typed("id", "long", found.id.to_string()),
+                        typed("title", "string", found.title),
+                        typed("type", "string", found.content_type),
+                        typed("url", "string", found.url),
+                    ])
+            })
+            .collect();
+
+        Ok(array("RemoteSearchResult", items))
+    }
+
+    /// Id of the label, assigned on first use.
+    fn label_id(&mut self, name: &str) -> i64 {
+        if let Some(&id) = self.label_ids.get(name) {
+            return id;
+        }
+        self.last_id += 1;
+        self.label_ids.insert(name.into(), self.last_id);
+        self.last_id
+    }
+
+    fn label_element(&mut self, name: &str) -> Element {
+        Element::node("item")
+            .with_attr("xsi:type", "tns2:RemoteLabel")
+            .with_children(vec![
+                typed("id", "long", self.label_id(name).to_string()),
+                typed("name", "string", name),
+                typed("namespace", "string", "global"),
+                Element::node("owner").with_attr("xsi:nil", "true"),
+            ])
+    }
+
+    fn content_space(&self, id: i64) -> Option<&str> {
+        match (self.pages.get(&id), self.blog_entries.get(&id)) {
+            (Some(page), _) => Some(&page.space),
+            (_, Some(entry)) => Some(&entry.space),
+            _ => None,
+        }
+    }
+
+    fn search_result_element(&self, id: i64) -> Option<Element> {
+        let (title, content_type, url) = match (self.pages.get(&id), self.blog_entries.get(&id)) {
+            (Some(page), _) => (page.title.clone(), "page", self.page_url(page)),
+            (_, Some(entry)) => (entry.title.clone(), "blogpost", self.blog_entry_url(entry)),
+            _ => return None,
+        };
+
+        Some(
+            Element::node("item")
+                .with_attr("xsi:type", "tns2:RemoteSearchResult")
+                .with_children(vec![
+                    Element::node("excerpt").with_attr("xsi:nil", "true"),
+                    typed("id", "long", id.to_string()),
+                    typed("title", "string", title),
+                    typed("type", "string", content_type),
+                    typed("url", "string", url),
+                ]),
+        )
+    }
+
+    fn get_attachments(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let page_id = self.existing_content_id(args)?;
+
+        let items = self
+            .attachments
+            .values()
+            .filter(|attachment| attachment.page_id == page_id)
+            .map(|attachment| self.attachment_element(attachment, attachment.versions.len()))
+            .collect();
+
+        Ok(array("RemoteAttachment", items))
+    }
+
+    fn get_attachment(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let page_id = self.existing_content_id(args)?;
+        let file_name = text_arg(args, 2)?;
+        let attachment = self.find_attachment(page_id, &file_name)?;
+        let version = attachment_version(attachment, long_arg(args, 3)?)?;
+
+        Ok(self.attachment_element(attachment, version))
+    }
+
+    fn get_attachment_data(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let page_id = self.existing_content_id(args)?;
+        let file_name = text_arg(args, 2)?;
+        let attachment = self.find_attachment(page_id, &file_name)?;
+        let version = attachment_version(attachment, long_arg(args, 3)?)?;
+
+        Ok(typed(
+            "return",
+            "base64Binary",
+            base64::encode(&attachment.versions[version - 1]),
+        ))
+    }
+
+    fn add_attachment(&mut self, args: &[Element]) -> OpResult {
+        let user = self.authenticate(args)?;
+        let page_id = self.existing_content_id(args)?;
+        let attachment = arg(args, 2)?;
+        // The three argument overload carries the data inside the attachment.
+        let data = match args.get(3) {
+            Some(data) => data.text.clone().unwrap_or_default(),
+            None => child_text(attachment, "data").unwrap_or_default(),
+        };
+        let data = base64::decode(data.split_whitespace().collect::<String>())
+            .map_err(|err| Fault::remote(format!("Invalid attachment data: {}", err)))?;
+        let file_name = required_child_text(attachment, "fileName")?;
+        let content_type = required_child_text(attachment, "contentType")?;
+        let comment = child_text(attachment, "comment");
+
+        let existing = self
+            .attachments
+            .values()
+            .find(|stored| stored.page_id == page_id && stored.file_name == file_name)
+            .map(|stored| stored.id);
+        let id = match existing {
+            Some(id) => {
+                let stored = self.attachments.get_mut(&id).expect("attachment exists");
+                stored.content_type = content_type;
+                stored.comment = comment;
+                stored.versions.push(data);
+                id
+            }
+            None => {
+                self.last_id += 1;
+                let id = self.last_id;
+                self.attachments.insert(
+                    id,
+                    FakeAttachment {
+                        id,
+                        page_id,
+                        file_name,
+                        content_type,
+                        comment,
+                        versions: vec![data],
+                        created: Utc::now(),
+                        creator: user,
+                    },
+                );
+                id
+            }
+        };
+
+        let stored = &self.attachments[&id];
+        Ok(self.attachment_element(stored, stored.versions.len()))
+    }
+
+    fn move_attachment(&mut self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let page_id = self.existing_content_id(args)?;
+        let file_name = text_arg(args, 2)?;
+        let new_page_id = long_arg(args, 3)?;
+        let new_name = text_arg(args, 4)?;
+
+        if !self.content_exists(new_page_id) {
+            return Err(Fault::remote("The page you are trying to access does not exist"));
+        }
+        if self.find_attachment(new_page_id, &new_name).is_ok() {
+            return Err(Fault::remote(format!(
+                "Attachment '{}' already exists on the page",
+                new_name
+            )));
+        }
+        let id = self.find_attachment(page_id, &file_name)?.id;
+        let stored = self.attachments.get_mut(&id).expect("attachment exists");
+        stored.page_id = new_page_id;
+        stored.file_name = new_name;
+
+        Ok(typed("return", "boolean", "true"))
+    }
+
+    fn remove_attachment(&mut self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let page_id = self.existing_content_id(args)?;
+        let file_name = text_arg(args, 2)?;
+
+        let id = self.find_attachment(page_id, &file_name)?.id;
+        self.attachments.remove(&id);
+
+        Ok(typed("return", "boolean", "true"))
+    }
+
+    fn find_attachment(&self, page_id: i64, file_name: &str) -> Result<&FakeAttachment, Fault> {
+        self.attachments
+            .values()
+            .find(|attachment| attachment.page_id == page_id && attachment.file_name == file_name)
+            .ok_or_else(|| {
+                Fault::remote(format!(
+                    "Attachment '{}' does not exist on content {}",
+                    file_name, page_id
+                ))
+            })
+    }
+
+    fn attachment_element(&self, attachment: &FakeAttachment, version: usize) -> Element {
+        let mut children = vec![
+            typed("id", "long", attachment.id.to_string()),
+            typed("pageId", "long", attachment.page_id.to_string()),
+            typed("title", "string", attachment.file_name.clone()),
+            typed("fileName", "string", attachment.file_name.clone()),
+            typed(
+                "fileSize",
+                "long",
+                attachment.versions[version - 1].len().to_string(),
+            ),
+            typed("contentType", "string", attachment.content_type.clone()),
+            typed("created", "dateTime", datetime(&attachment.created)),
+            typed("creator", "string", attachment.creator.clone()),
+            typed(
+                "url",
+                "string",
+                format!(
+                    "{}/download/attachments/{}/{}?version={}",
+                    self.base_url, attachment.page_id, attachment.file_name, version
+                ),
+            ),
+        ];
+        if let Some(ref comment) = attachment.comment {
+            children.push(typed("comment", "string", comment.clone()));
+        } else {
+            children.push(Element::node("comment").with_attr("xsi:nil", "true"));
+        }
+
+        Element::node("item")
+            .with_attr("xsi:type", "tns2:RemoteAttachment")
+            .with_children(children)
+    }
+
+    fn get_comments(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let page_id = self.existing_content_id(args)?;
+
+        let items = self
+            .comments
+            .values()
+            .filter(|comment| comment.page_id == page_id)
+            .map(|comment| self.comment_element(comment))
+            .collect();
+
+        Ok(array("RemoteComment", items))
+    }
+
+    fn get_comment(&self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let id = long_arg(args, 1)?;
+
+        Ok(self.comment_element(self.find_comment(id)?))
+    }
+
+    fn add_comment(&mut self, args: &[Element]) -> OpResult {
+        let user = self.authenticate(args)?;
+        let comment = arg(args, 1)?;
+        let page_id = child_long(comment, "pageId")?.unwrap_or(0);
+        let parent_id = child_long(comment, "parentId")?.unwrap_or(0);
+        let content = required_child_text(comment, "content")?;
+
+        if !self.content_exists(page_id) {
+            return Err(Fault::remote("The page you are trying to access does not exist"));
+        }
+        if parent_id != 0 && self.find_comment(parent_id)?.page_id != page_id {
+            return Err(Fault::remote("The parent comment belongs to another page"));
+        }
+
+        self.last_id += 1;
+        let now = Utc::now();
+        let stored = FakeComment {
+            id: self.last_id,
+            page_id,
+            parent_id,
+            content,
+            created: now,
+            creator: user.clone(),
+            modified: now,
+            modifier: user,
+        };
+        self.comments.insert(stored.id, stored.clone());
+
+        Ok(self.comment_element(&stored))
+    }
+
+    fn edit_comment(&mut self, args: &[Element]) -> OpResult {
+        let user = self.authenticate(args)?;
+        let comment = arg(args, 1)?;
+        let id = child_long(comment, "id")?.unwrap_or(0);
+        let content = required_child_text(comment, "content")?;
+
+        self.find_comment(id)?;
+        let stored = self.comments.get_mut(&id).expect("comment exists");
+        stored.content = content;
+        stored.modified = Utc::now();
+        stored.modifier = user;
+
+        let stored = stored.clone();
+        Ok(self.comment_element(&stored))
+    }
+
+    fn remove_comment(&mut self, args: &[Element]) -> OpResult {
+        self.authenticate(args)?;
+        let id = long_arg(args, 1)?;
+
+        self.find_comment(id)?;
+        // replies go away together with the comment
+        let mut removed = vec![id];
+        while let Some(id) = removed.pop() {
+            self.comments.remove(&id);
+            removed.extend(
+                self.comments
+                    .values()
+                    .filter(|comment| comment.parent_id == id)
+                    .map(|comment| comment.id),
+            );
+        }
+
+        Ok(typed("return", "boolean", "true"))
+    }
+
+    fn find_comment(&self, id: i64) -> Result<&FakeComment, Fault> {
+        self.comments
+            .get(&id)
+            .ok_or_else(|| Fault::remote(format!("No comment found with id: {}", id)))
+    }
+
+    fn comment_element(&self, comment: &FakeComment) -> Element {
+        let title = self
+            .pages
+            .get(&comment.page_id)
+            .map(|page| format!("Re: {}", page.title))
+            .unwrap_or_default();
+
+        Element::node("item")
+            .with_attr("xsi:type", "tns2:RemoteComment")
+            .with_children(vec![
+                typed("content", "string", comment.content.clone()),
+                typed("created", "dateTime", datetime(&comment.created)),
+                typed("creator", "string", comment.creator.clone()),
+                typed("id", "long", comment.id.to_string()),
+                typed("modified", "dateTime", datetime(&comment.modified)),
+                typed("modifier", "string", comment.modifier.clone()),
+                typed("pageId", "long", comment.page_id.to_string()),
+                typed("parentId", "long", comment.parent_id.to_string()),
+                typed("title", "string", title),
+                typed(
+                    "url",
+                    "string",
+                    format!(
+                        "{}/pages/viewpage.action?pageId={}&focusedCommentId={}",
+                        self.base_url, comment.page_id, comment.id
+                    ),
+                ),
+            ])
+    }
+
+    /// Id of the page or blog entry in the first argument after token.
+    fn existing_content_id(&self, args: &[Element]) -> Result<i64, Fault> {
+        let id = long_arg(args, 1)?;
+        if self.content_exists(id) {
+            Ok(id)
+        } else {
+            Err(Fault::remote("The page you are trying to access does not exist"))
+        }
+    }
+
+    fn content_exists(&self, id: i64) -> bool {
+        self.pages.contains_key(&id) || self.blog_entries.contains_key(&id)
+    }
+
+    fn existing_page_id(&self, args: &[Element]) -> Result<i64, Fault> {
+        let page_id = long_arg(args, 1)?;
+        if self.pages.contains_key(&page_id) {
+            Ok(page_id)
+        } else {
+            Err(Fault::remote("The page you are trying to access does not exist"))
+        }
+    }
+
+    fn page_url(&self, page: &FakePage) -> String {
+        format!("{}/pages/viewpage.action?pageId={}", self.base_url, page.id)
+    }
+
+    fn page_summary_element(&self, page: &FakePage) -> Element {
+        Element::node("item")
+            .with_attr("xsi:type", "tns2:RemotePageSummary")
+            .with_children(vec![
+                typed("id", "long", page.id.to_string()),
+                typed("parentId", "long", page.parent_id.to_string()),
+                typed("permissions", "int", "0"),
+                typed("space", "string", page.space.clone()),
+                typed("title", "string", page.title.clone()),
+                typed("url", "string", self.page_url(page)),
+            ])
+    }
+
+    fn page_element(&self, page: &FakePage) -> Element {
+        let home_page = self
+            .spaces
+            .get(&page.space)
+            .map(|space| space.home_page == page.id)
+            .unwrap_or(false);
+
+        Element::node("return").with_children(vec![
+            typed("id", "long", page.id.to_string()),
+            typed("parentId", "long", page.parent_id.to_string()),
+            typed("permissions", "int", "0"),
+            typed("space", "string", page.space.clone()),
+            typed("title", "string", page.title.clone()),
+            typed("url", "string", self.page_url(page)),
+            typed("version", "int", page.version.to_string()),
+            typed("content", "string", page.content.clone()),
+            typed("contentStatus", "string", "current"),
+            typed("created", "dateTime", datetime(&page.created)),
+            typed("creator", "string", page.creator.clone()),
+            typed("current", "boolean", "true"),
+            typed("homePage", "boolean", home_page.to_string()),
+            typed("modified", "dateTime", datetime(&page.modified)),
+            typed("modifier", "string", page.modifier.clone()),
+        ])
+    }
+
+    fn historical_page_element(&self, version: &FakePageVersion) -> Element {
+        let mut element = self.page_element(&version.page);
+        for child in &mut element.children {
+            match child.name.as_str() {
+                "id" => child.text = Some(version.id.to_string()),
+                "current" => child.text = Some("false".into()),
+                "homePage" => child.text = Some("false".into()),
+                _ => {}
+            }
+        }
+        element
+    }
+
+    fn trashed_page_element(&self, page: &FakePage) -> Element {
+        let mut element = self.page_element(page);
+        for child in &mut element.children {
+            match child.name.as_str() {
+                "contentStatus" => child.text = Some("deleted".into()),
+                "current" => child.text = Some("false".into()),
+                _ => {}
+            }
+        }
+        element
+    }
+}
+
+/// Build element with text of specified `xsd` type.
+fn typed<S: Into<String>>(name: &str, xsd_type: &str, text: S) -> Element {
+    Element::node(name)
+        .with_attr("xsi:type", format!("xsd:{}", xsd_type))
+        .with_text(text)
+}
+
+/// Build SOAP encoded array of specified items.
+fn array(item_type: &str, items: Vec<Element>) -> Element {
+    Element::node("return")
+        .with_attr("xsi:type", "soapenc:Array")
+        .with_attr(
+            "soapenc:arrayType",
+            format!("tns2:{}[{}]", item_type, items.len()),
+        )
+        .with_children(items)
+}
+
+/// Build SOAP encoded array of strings.
+fn string_array<I>(items: I) -> Element
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let items = items
+        .into_iter()
+        .map(|item| typed("item", "string", item.as_ref()))
+        .collect::<Vec<_>>();
+
+    Element::node("return")
+        .with_attr("xsi:type", "soapenc:Array")
+        .with_attr("soapenc:arrayType", format!("xsd:string[{}]", items.len()))
+        .with_children(items)
+}
+
+/// Read `apachesoap:Map` argument of string keys and values.
+fn map_arg(args: &[Element], index: usize) -> Result<HashMap<String, String>, Fault> {
+    arg(args, index)?
+        .children
+        .iter()
+        .map(|item| {
+            Ok((
+                required_child_text(item, "key")?,
+                required_child_text(item, "value")?,
+            ))
+        })
+        .collect()
+}
+
+fn start_of_day(time: DateTime<Utc>) -> DateTime<Utc> {
+    let midnight = time.date_naive().and_hms_opt(0, 0, 0).expect("midnight exists");
+    DateTime::from_naive_utc_and_offset(midnight, Utc)
+}
+
+/// Level of wiki markup heading like `h2. Title`.
+fn heading_level(line: &str) -> Option<u32> {
+    let bytes = line.as_bytes();
+    match bytes {
+        [b'h', level @ b'1'..=b'6', b'.', ..] => Some(u32::from(level - b'0')),
+        _ => None,
+    }
+}
+
+/// Escape text for use in XHTML.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Beginning of the content shown in search results.
+fn excerpt(content: &str) -> String {
+    content.chars().take(100).collect()
+}
+
+/// Split label names separated by spaces or commas, like Confluence does.
+fn label_names(text: &str) -> Result<Vec<String>, Fault> {
+    let names = text
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|name| !name.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>();
+
+    if names.is_empty() {
+        Err(Fault::remote("Label name must not be empty"))
+    } else {
+        Ok(names)
+    }
+}
+
+/// Resolve requested version number, `0` meaning the latest one.
+fn attachment_version(attachment: &FakeAttachment, requested: i64) -> Result<usize, Fault> {
+    let latest = attachment.versions.len();
+    match requested {
+        0 => Ok(latest),
+        number if number > 0 && number as usize <= latest => Ok(number as usize),
+        number => Err(Fault::remote(format!(
+            "Version {} of attachment '{}' does not exist",
+            number, attachment.file_name
+        ))),
+    }
+}
+
+fn datetime(value: &DateTime<Utc>) -> String {
+    value.to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+fn arg(args: &[Element], index: usize) -> Result<&Element, Fault> {
+    args.get(index)
+        .ok_or_else(|| Fault::remote(format!("Missing argument in{}", index)))
+}
+
+fn text_arg(args: &[Element], index: usize) -> Result<String, Fault> {
+    Ok(arg(args, index)?.text.clone().unwrap_or_default())
+}
+
+fn long_arg(args: &[Element], index: usize) -> Result<i64, Fault> {
+    text_arg(args, index)?
+        .trim()
+        .parse()
+        .map_err(|_| Fault::remote(format!("Argument in{} is not a number", index)))
+}
+
+fn child_text(element: &Element, name: &str) -> Option<String> {
+    element
+        .children
+        .iter()
+        .find(|child| child.name == name)
+        .map(|child| child.text.clone().unwrap_or_default())
+}
+
+fn required_child_text(element: &Element, name: &str) -> Result<String, Fault> {
+    child_text(element, name).ok_or_else(|| Fault::remote(format!("The field '{}' is required", name)))
+}
+
+fn child_long(element: &Element, name: &str) -> Result<Option<i64>, Fault> {
+    match child_text(element, name) {
+        None => Ok(None),
+        Some(text) => text
+            .trim()
+            .parse()
+            .map(Some)
+            .map_err(|_| Fault::remote(format!("The field '{}' is not a number", name))),
+    }
+}
@@ -0,0 +1,338 @@
+//! Streaming of base64 encoded attachment data in and out of SOAP envelopes.
+//!
+//! Attachment content is never held in memory as a whole: the request envelope
+//! is read by the transport piece by piece while the data is being encoded, and
+//! the text of the response element is decoded as it arrives.
+
+use crate::http::{self, RequestBody, StreamedResponse};
+use crate::rpser::{self, RpcError};
+use crate::{parse_call_response, Error, Result};
+use std::io::{self, BufRead, BufReader, Cursor, Read, Write};
+
+/// Text put in place of the data while the envelope is built, replaced by the stream.
+pub(crate) const DATA_PLACEHOLDER: &str = "__attachment_data__";
+
+/// Size of raw data encoded at once, multiple of 3 so that only the last piece is padded.
+const ENCODE_CHUNK: usize = 48 * 1024;
+
+/// Size of the buffer the response is read with.
+const DECODE_CHUNK: usize = 64 * 1024;
+
+/// Build request body from envelope, with `DATA_PLACEHOLDER` replaced by encoded `data`.
+///
+/// `progress` is called with the number of raw bytes read from `data` so far.
+pub(crate) fn upload_body<R, F>(envelope: &str, data: R, progress: F) -> RequestBody
+where
+    R: Read + Send + 'static,
+    F: FnMut(u64) + Send + 'static,
+{
+    // the data is the last argument, so search from the end in case
+    // other arguments happen to contain the placeholder
+    let (prefix, suffix) = match envelope.rfind(DATA_PLACEHOLDER) {
+        Some(index) => (
+            &envelope[..index],
+            &envelope[index + DATA_PLACEHOLDER.len()..],
+        ),
+        None => (envelope, ""),
+    };
+
+    Box::new(
+        Cursor::new(prefix.as_bytes().to_vec())
+            .chain(EncodingReader::new(data, progress))
+            .chain(Cursor::new(suffix.as_bytes().to_vec())),
+    )
+}
+
+/// Read the whole streamed response and parse it as usual.
+pub(crate) fn read_response(response: StreamedResponse) -> Result<rpser::Response> {
+    let mut body = String::new();
+    let mut reader = response.body;
+    reader.read_to_string(&mut body)?;
+
+    parse_call_response(http::Response {
+        status: response.status,
+        body,
+    })
+}
+
+/// Decode the text of `element` in the streamed response into `output`.
+///
+/// Returns the number of decoded bytes. Response without such element, like
+/// a SOAP fault, is read as a whole and results in the same error as `Session::call`.
+pub(crate) fn decode_response<W, F>(
+    response: StreamedResponse,
+    element: &str,
+    output: &mut W,
+    mut progress: F,
+) -> Result<u64>
+where
+    W: Write + ?Sized,
+    F: FnMut(u64),
+{
+    let status = response.status;
+    let mut reader = BufReader::with_capacity(DECODE_CHUNK, response.body);
+    let mut head = vec![];
+
+    loop {
+        if reader.read_until(b'<', &mut head)? == 0 || head.last() != Some(&b'<') {
+            break;
+        }
+        let start = head.len();
+        reader.read_until(b'>', &mut head)?;
+
+        let self_closing = match tag_name(&head[start..]) {
+            Some(name) if name == element.as_bytes() => head.ends_with(b"/>"),
+            _ => continue,
+        };
+        let written = if self_closing {
+            0
+        } else {
+            decode_text(&mut reader, output, &mut progress)?
+        };
+
+        // read the rest, so the connection can be reused
+        io::copy(&mut reader, &mut io::sink())?;
+        return Ok(written);
+    }
+
+    parse_call_response(http::Response {
+        status,
+        body: String::from_utf8_lossy(&head).into_owned(),
+    })?;
+
+    Err(RpcError::ElementNotFound {
+        path: vec![element.into()],
+    }
+    .into())
+}
+
+/// Local name of the start tag, given the bytes after `<`.
+fn tag_name(tag: &[u8]) -> Option<&[u8]> {
+    let end = tag
+        .iter()
+        .position(|&b| b.is_ascii_whitespace() || b == b'/' || b == b'>')
+        .unwrap_or(tag.len());
+    let name = &tag[..end];
+    if name.is_empty() {
+        return None;
+    }
+
+    Some(match name.iter().rposition(|&b| b == b':') {
+        Some(colon) => &name[colon + 1..],
+        None => name,
+    })
+}
+
+/// Decode base64 text up to the next tag, skipping whitespace and character references.
+fn decode_text<R, W, F>(reader: &mut R, output: &mut W, progress: &mut F) -> Result<u64>
+where
+    R: BufRead,
+    W: Write + ?Sized,
+    F: FnMut(u64),
+{
+    let mut pending = Vec::with_capacity(DECODE_CHUNK);
+    let mut in_reference = false;
+    let mut written = 0;
+
+    loop {
+        let (consumed, finished) = {
+            let buffer = reader.fill_buf()?;
+            if buffer.is_empty() {
+                return Err(Error::Io(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "response ended inside of attachment data",
+                )));
+            }
+            let end = buffer.iter().position(|&b| b == b'<');
+            for &byte in &buffer[..end.unwrap_or(buffer.len())] {
+                match byte {
+                    // only whitespace is expected to be escaped in base64 text
+                    b'&' => in_reference = true,
+                    b';' if in_reference => in_reference = false,
+                    _ if in_reference || byte.is_ascii_whitespace() => {}
+                    _ => pending.push(byte),
+                }
+            }
+            (end.unwrap_or(buffer.len()), end.is_some())
+        };
+        reader.consume(consumed);
+
+        let usable = if finished {
+            pending.len()
+        } else {
+            pending.len() / 4 * 4
+        };
+        if usable > 0 {
+            let decoded = base64::decode(&pending[..usable])?;
+            output.write_all(&decoded)?;
+            written += decoded.len() as u64;
+            progress(written);
+            pending.drain(..usable);
+        }
+
+        if finished {
+            output.flush()?;
+            return Ok(written);
+        }
+    }
+}
+
+/// Reader that encodes data of the inner reader to base64.
+struct EncodingReader<R, F> {
+    inner: R,
+    progress: F,
+    raw: Vec<u8>,
+    encoded: Vec<u8>,
+    position: usize,
+    total: u64,
+    done: bool,
+}
+
+impl<R: Read, F: FnMut(u64)> EncodingReader<R, F> {
+    fn new(inner: R, progress: F) -> EncodingReader<R, F> {
+        EncodingReader {
+            inner,
+            progress,
+            raw: vec![0; ENCODE_CHUNK],
+            encoded: vec![],
+            position: 0,
+            total: 0,
+            done: false,
+        }
+    }
+
+    /// Encode next chunk of data, reading the inner reader until the chunk is full.
+    fn fill(&mut self) -> io::Result<()> {
+        let mut filled = 0;
+        while filled < self.raw.len() {
+            match self.inner.read(&mut self.raw[filled..]) {
+                Ok(0) => {
+                    self.done = true;
+                    break;
+                }
+                Ok(n) => filled += n,
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        self.encoded.clear();
+        self.encoded
+            .extend_from_slice(base64::encode(&self.raw[..filled]).as_bytes());
+        self.position = 0;
+        if filled > 0 {
+            self.total += filled as u64;
+            (self.progress)(self.total);
+        }
+        Ok(())
+    }
+}
+
+impl<R: Read, F: FnMut(u64)> Read for EncodingReader<R, F> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.position == self.encoded.len() {
+            if self.done {
+                return Ok(0);
+            }
+            self.fill()?;
+        }
+
+        let n = buf.len().min(self.encoded.len() - self.position);
+        buf[..n].copy_from_slice(&self.encoded[self.position..self.position + n]);
+        self.position += n;
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::http::StatusCode;
+
+    fn streamed(body: &str) -> StreamedResponse {
+        StreamedResponse {
+            status: StatusCode::OK,
+            body: Box::new(Cursor::new(body.as_bytes().to_vec())),
+        }
+    }
+
+    #[test]
+    fn encodes_data_into_envelope() {
+        let data = (0..200_000).map(|i| i as u8).collect::<Vec<_>>();
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let envelope = format!("<a>x</a><data>{}</data>", DATA_PLACEHOLDER);
+
+        let mut body = String::new();
+        upload_body(&envelope, Cursor::new(data.clone()), move |n| {
+            sender.send(n).unwrap()
+        })
+        .read_to_string(&mut body)
+        .unwrap();
+        let reported = receiver.try_iter().collect::<Vec<_>>();
+
+        assert_eq!(
+            body,
+            format!("<a>x</a><data>{}</data>", base64::encode(&data))
+        );
+        assert_eq!(reported, vec![49152, 98304, 147456, 196608, 200000]);
+    }
+
+    #[test]
+    fn decodes_data_from_response() {
+        let data = (0..100_000).map(|i| (i * 7) as u8).collect::<Vec<_>>();
+        let encoded = base64::encode(&data);
+        let wrapped = encoded
+            .as_bytes()
+            .chunks(76)
+            .map(|line| std::str::from_utf8(line).unwrap())
+            .collect::<Vec<_>>()
+            .join("&#xd;\n");
+        let xml = format!(
+            concat!(
+                r#"<?xml version="1.0" encoding="utf-8"?>"#,
+                r#"<soapenv:Envelope xmlns:soapenv="http://schemas.xmlsoap.org/soap/envelope/">"#,
+                r#"<soapenv:Body><ns1:getAttachmentDataResponse xmlns:ns1="urn:x">"#,
+                r#"<getAttachmentDataReturn xsi:type="xsd:base64Binary">{}</getAttachmentDataReturn>"#,
+                r#"</ns1:getAttachmentDataResponse></soapenv:Body></soapenv:Envelope>"#
+            ),
+            wrapped
+        );
+
+        let mut output = vec![];
+        let mut last = 0;
+        let written = decode_response(
+            streamed(&xml),
+            "getAttachmentDataReturn",
+            &mut output,
+            |n| last = n,
+        )
+        .unwrap();
+
+        assert_eq!(written, data.len() as u64);
+        assert_eq!(last, data.len() as u64);
+        assert_eq!(output, data);
+    }
+
+    #[test]
+    fn reports_fault_instead_of_data() {
+        let xml = concat!(
+            r#"<?xml version="1.0" encoding="utf-8"?>"#,
+            r#"<soapenv:Envelope xmlns:soapenv="http://schemas.xmlsoap.org/soap/envelope/">"#,
+            r#"<soapenv:Body><soapenv:Fault><faultcode>soapenv:Server</faultcode>"#,
+            r#"<faultstring>com.atlassian.confluence.rpc.RemoteException: missing</faultstring>"#,
+            r#"<detail/></soapenv:Fault></soapenv:Body></soapenv:Envelope>"#
+        );
+
+        let mut output = vec![];
+        match decode_response(
+            streamed(xml),
+            "getAttachmentDataReturn",
+            &mut output,
+            |_| {},
+        ) {
+            Err(Error::Rpc(ref e)) => assert_eq!(e.exception_name(), Some("RemoteException")),
+            other => panic!("expected fault, received {:?}", other),
+        }
+        assert!(output.is_empty());
+    }
+}
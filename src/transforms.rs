@@ -1,7 +1,13 @@
-use rpser::xml::BuildElement;
+use crate::rpser::xml::BuildElement;
+use chrono::{DateTime, Utc};
 use xmltree::Element;
 
-use {Page, PageSummary, Result, ServerInfo, Space};
+use crate::{
+    Attachment, BlogEntry, BlogEntrySummary, Comment, ContentPermission, ContentPermissionSet,
+    ContentPermissionType, ContentSummaries, ContentSummary, Group, Label, Page,
+    PageHistorySummary, PageSummary, Permission, Result, SearchResult, ServerInfo, Space,
+    SpaceStatus, SpaceSummary, User, UserInformation,
+};
 
 pub trait FromElement {
     fn from_element(element: Element) -> Result<Self>
@@ -29,13 +35,30 @@ impl FromElement for Space {
             home_page: element.get_at_path(&["homePage"]).and_then(|e| e.as_long())?,
             key: element.get_at_path(&["key"]).and_then(|e| e.as_string())?,
             name: element.get_at_path(&["name"]).and_then(|e| e.as_string())?,
-            space_group:element.get_at_path(&["name"])?.text,
+            space_group: optional_field(&element, "spaceGroup")?,
             space_type: element.get_at_path(&["type"]).and_then(|e| e.as_string())?,
             url: element.get_at_path(&["url"]).and_then(|e| e.as_string())?,
         })
     }
 }
 
+impl FromElement for SpaceSummary {
+    fn from_element(element: Element) -> Result<SpaceSummary> {
+        Ok(SpaceSummary {
+            key: element.get_at_path(&["key"]).and_then(|e| e.as_string())?,
+            name: element.get_at_path(&["name"]).and_then(|e| e.as_string())?,
+            space_type: element.get_at_path(&["type"]).and_then(|e| e.as_string())?,
+            url: element.get_at_path(&["url"]).and_then(|e| e.as_string())?,
+        })
+    }
+}
+
+impl FromElement for SpaceStatus {
+    fn from_element(element: Element) -> Result<SpaceStatus> {
+        Ok(SpaceStatus::from(element.as_string()?.as_str()))
+    }
+}
+
 impl FromElement for Page {
     fn from_element(element: Element) -> Result<Page> {
         Ok(Page {
@@ -87,3 +110,333 @@ impl FromElement for PageSummary {
         })
     }
 }
+
+impl FromElement for Attachment {
+    fn from_element(element: Element) -> Result<Attachment> {
+        Ok(Attachment {
+            id: element.get_at_path(&["id"]).and_then(|e| e.as_long())?,
+            page_id: element.get_at_path(&["pageId"]).and_then(|e| e.as_long())?,
+            title: field(&element, "title")?,
+            file_name: element.get_at_path(&["fileName"]).and_then(|e| e.as_string())?,
+            file_size: element.get_at_path(&["fileSize"]).and_then(|e| e.as_long())?,
+            content_type: element.get_at_path(&["contentType"]).and_then(|e| e.as_string())?,
+            created: element.get_at_path(&["created"]).and_then(|e| e.as_datetime())?,
+            creator: element.get_at_path(&["creator"]).and_then(|e| e.as_string())?,
+            url: element.get_at_path(&["url"]).and_then(|e| e.as_string())?,
+            comment: optional_field(&element, "comment")?,
+        })
+    }
+}
+
+impl FromElement for PageHistorySummary {
+    fn from_element(element: Element) -> Result<PageHistorySummary> {
+        Ok(PageHistorySummary {
+            id: element.get_at_path(&["id"]).and_then(|e| e.as_long())?,
+            version: element.get_at_path(&["version"]).and_then(|e| e.as_int())?,
+            modifier: element.get_at_path(&["modifier"]).and_then(|e| e.as_string())?,
+            modified: element.get_at_path(&["modified"]).and_then(|e| e.as_datetime())?,
+            version_comment: optional_field(&element, "versionComment")?,
+        })
+    }
+}
+
+impl FromElement for BlogEntry {
+    fn from_element(element: Element) -> Result<BlogEntry> {
+        Ok(BlogEntry {
+            id: element.get_at_path(&["id"]).and_then(|e| e.as_long())?,
+            space: element.get_at_path(&["space"]).and_then(|e| e.as_string())?,
+            title: element.get_at_path(&["title"]).and_then(|e| e.as_string())?,
+            url: element.get_at_path(&["url"]).and_then(|e| e.as_string())?,
+            version: element.get_at_path(&["version"]).and_then(|e| e.as_int())?,
+            content: field(&element, "content")?,
+            created: element.get_at_path(&["created"]).and_then(|e| e.as_datetime())?,
+            creator: element.get_at_path(&["creator"]).and_then(|e| e.as_string())?,
+            modified: element.get_at_path(&["modified"]).and_then(|e| e.as_datetime())?,
+            modifier: element.get_at_path(&["modifier"]).and_then(|e| e.as_string())?,
+            publish_date: element.get_at_path(&["publishDate"]).and_then(|e| e.as_datetime())?,
+            content_status: element.get_at_path(&["contentStatus"]).and_then(|e| e.as_string())?,
+            current: element.get_at_path(&["current"]).and_then(|e| e.as_boolean())?,
+        })
+    }
+}
+
+impl FromElement for BlogEntrySummary {
+    fn from_element(element: Element) -> Result<BlogEntrySummary> {
+        Ok(BlogEntrySummary {
+            id: element.get_at_path(&["id"]).and_then(|e| e.as_long())?,
+            space: element.get_at_path(&["space"]).and_then(|e| e.as_string())?,
+            title: element.get_at_path(&["title"]).and_then(|e| e.as_string())?,
+            url: element.get_at_path(&["url"]).and_then(|e| e.as_string())?,
+            author: element.get_at_path(&["author"]).and_then(|e| e.as_string())?,
+            publish_date: element.get_at_path(&["publishDate"]).and_then(|e| e.as_datetime())?,
+        })
+    }
+}
+
+impl FromElement for Comment {
+    fn from_element(element: Element) -> Result<Comment> {
+        Ok(Comment {
+            id: element.get_at_path(&["id"]).and_then(|e| e.as_long())?,
+            page_id: element.get_at_path(&["pageId"]).and_then(|e| e.as_long())?,
+            parent_id: element.get_at_path(&["parentId"]).and_then(|e| e.as_long())?,
+            title: field(&element, "title")?,
+            content: field(&element, "content")?,
+            url: element.get_at_path(&["url"]).and_then(|e| e.as_string())?,
+            created: element.get_at_path(&["created"]).and_then(|e| e.as_datetime())?,
+            creator: element.get_at_path(&["creator"]).and_then(|e| e.as_string())?,
+            modified: element.get_at_path(&["modified"]).and_then(|e| e.as_datetime())?,
+            modifier: element.get_at_path(&["modifier"]).and_then(|e| e.as_string())?,
+        })
+    }
+}
+
+impl FromElement for Label {
+    fn from_element(element: Element) -> Result<Label> {
+        Ok(Label {
+            id: element.get_at_path(&["id"]).and_then(|e| e.as_long())?,
+            name: element.get_at_path(&["name"]).and_then(|e| e.as_string())?,
+            namespace: element.get_at_path(&["namespace"]).and_then(|e| e.as_string())?,
+            owner: optional_field(&element, "owner")?,
+        })
+    }
+}
+
+impl FromElement for SearchResult {
+    fn from_element(element: Element) -> Result<SearchResult> {
+        Ok(SearchResult {
+            id: element.get_at_path(&["id"]).and_then(|e| e.as_long())?,
+            title: element.get_at_path(&["title"]).and_then(|e| e.as_string())?,
+            content_type: element.get_at_path(&["type"]).and_then(|e| e.as_string())?,
+            url: element.get_at_path(&["url"]).and_then(|e| e.as_string())?,
+            excerpt: optional_field(&element, "excerpt")?,
+        })
+    }
+}
+
+impl FromElement for ContentSummary {
+    fn from_element(element: Element) -> Result<ContentSummary> {
+        Ok(ContentSummary {
+            id: element.get_at_path(&["id"]).and_then(|e| e.as_long())?,
+            content_type: element.get_at_path(&["type"]).and_then(|e| e.as_string())?,
+            space: element.get_at_path(&["space"]).and_then(|e| e.as_string())?,
+            status: element.get_at_path(&["status"]).and_then(|e| e.as_string())?,
+            title: field(&element, "title")?,
+            created: optional_field(&element, "created")?,
+            creator: optional_field(&element, "creator")?,
+            modified: optional_field(&element, "modified")?,
+            modifier: optional_field(&element, "modifier")?,
+        })
+    }
+}
+
+impl FromElement for ContentSummaries {
+    fn from_element(element: Element) -> Result<ContentSummaries> {
+        Ok(ContentSummaries {
+            content: optional_field(&element, "content")?.unwrap_or_default(),
+            offset: element.get_at_path(&["offset"]).and_then(|e| e.as_int())?,
+            total_available: element.get_at_path(&["totalAvailable"]).and_then(|e| e.as_int())?,
+        })
+    }
+}
+
+impl FromElement for Permission {
+    fn from_element(element: Element) -> Result<Permission> {
+        Ok(Permission::from(element.as_string()?.as_str()))
+    }
+}
+
+impl FromElement for ContentPermission {
+    fn from_element(element: Element) -> Result<ContentPermission> {
+        let permission_type = element.get_at_path(&["type"]).and_then(|e| e.as_string())?;
+        Ok(ContentPermission {
+            permission_type: ContentPermissionType::from(permission_type.as_str()),
+            user_name: optional_field(&element, "userName")?,
+            group_name: optional_field(&element, "groupName")?,
+        })
+    }
+}
+
+impl FromElement for ContentPermissionSet {
+    fn from_element(element: Element) -> Result<ContentPermissionSet> {
+        let permission_type = element.get_at_path(&["type"]).and_then(|e| e.as_string())?;
+        Ok(ContentPermissionSet {
+            permission_type: ContentPermissionType::from(permission_type.as_str()),
+            permissions: optional_field(&element, "contentPermissions")?.unwrap_or_default(),
+        })
+    }
+}
+
+impl FromElement for User {
+    fn from_element(element: Element) -> Result<User> {
+        Ok(User {
+            name: element.get_at_path(&["name"]).and_then(|e| e.as_string())?,
+            full_name: field(&element, "fullname")?,
+            email: field(&element, "email")?,
+            url: element.get_at_path(&["url"]).and_then(|e| e.as_string())?,
+        })
+    }
+}
+
+impl FromElement for UserInformation {
+    fn from_element(element: Element) -> Result<UserInformation> {
+        Ok(UserInformation {
+            id: element.get_at_path(&["id"]).and_then(|e| e.as_long())?,
+            username: element.get_at_path(&["username"]).and_then(|e| e.as_string())?,
+            content: optional_field(&element, "content")?.unwrap_or_default(),
+            version: element.get_at_path(&["version"]).and_then(|e| e.as_int())?,
+            created: optional_field(&element, "creationDate")?,
+            creator: optional_field(&element, "creatorName")?,
+            modified: optional_field(&element, "lastModificationDate")?,
+            modifier: optional_field(&element, "lastModifierName")?,
+        })
+    }
+}
+
+impl FromElement for Group {
+    fn from_element(element: Element) -> Result<Group> {
+        Ok(Group {
+            name: element.as_string()?,
+        })
+    }
+}
+
+impl FromElement for String {
+    fn from_element(element: Element) -> Result<String> {
+        // empty strings are sent as typed elements without text
+        match element.attributes.get("type") {
+            Some(value_type) if element.text.is_none() && value_type.ends_with("string") => {
+                Ok(String::new())
+            }
+            _ => Ok(element.as_string()?),
+        }
+    }
+}
+
+impl FromElement for i64 {
+    fn from_element(element: Element) -> Result<i64> {
+        Ok(element.as_long()?)
+    }
+}
+
+impl FromElement for i32 {
+    fn from_element(element: Element) -> Result<i32> {
+        Ok(element.as_int()?)
+    }
+}
+
+impl FromElement for bool {
+    fn from_element(element: Element) -> Result<bool> {
+        Ok(element.as_boolean()?)
+    }
+}
+
+impl FromElement for DateTime<Utc> {
+    fn from_element(element: Element) -> Result<DateTime<Utc>> {
+        Ok(element.as_datetime()?)
+    }
+}
+
+impl FromElement for Element {
+    fn from_element(element: Element) -> Result<Element> {
+        Ok(element)
+    }
+}
+
+impl<T: FromElement> FromElement for Vec<T> {
+    fn from_element(element: Element) -> Result<Vec<T>> {
+        element.children.into_iter().map(T::from_element).collect()
+    }
+}
+
+/// Conversion of a value to the SOAP encoded element with the specified name.
+pub trait ToElement {
+    fn to_element(&self, name: &str) -> Element;
+}
+
+impl ToElement for str {
+    fn to_element(&self, name: &str) -> Element {
+        Element::node(name).with_text(self)
+    }
+}
+
+impl ToElement for String {
+    fn to_element(&self, name: &str) -> Element {
+        Element::node(name).with_text(self.as_str())
+    }
+}
+
+impl ToElement for i64 {
+    fn to_element(&self, name: &str) -> Element {
+        Element::node(name).with_text(self.to_string())
+    }
+}
+
+impl ToElement for i32 {
+    fn to_element(&self, name: &str) -> Element {
+        Element::node(name).with_text(self.to_string())
+    }
+}
+
+impl ToElement for bool {
+    fn to_element(&self, name: &str) -> Element {
+        Element::node(name).with_text(self.to_string())
+    }
+}
+
+impl ToElement for DateTime<Utc> {
+    fn to_element(&self, name: &str) -> Element {
+        Element::node(name).with_text(self.to_rfc3339())
+    }
+}
+
+impl ToElement for Element {
+    fn to_element(&self, name: &str) -> Element {
+        self.cloned().with_name(name)
+    }
+}
+
+impl<T: ToElement> ToElement for [T] {
+    fn to_element(&self, name: &str) -> Element {
+        Element::node(name).with_children(self.iter().map(|item| item.to_element("item")))
+    }
+}
+
+impl<T: ToElement> ToElement for Vec<T> {
+    fn to_element(&self, name: &str) -> Element {
+        self[..].to_element(name)
+    }
+}
+
+/// Convert the required child element.
+pub(crate) fn field<T: FromElement>(element: &Element, name: &str) -> Result<T> {
+    T::from_element(element.get_at_path(&[name])?)
+}
+
+/// Convert the child element, `None` if it is missing or nil.
+pub(crate) fn optional_field<T: FromElement>(element: &Element, name: &str) -> Result<Option<T>> {
+    match element.get_child(name) {
+        Some(child) if child.attributes.get("nil").map(String::as_str) != Some("true") => {
+            Ok(Some(T::from_element(child.clone())?))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Append the field to the element.
+pub(crate) fn add_field<T: ToElement + ?Sized>(element: &mut Element, name: &str, value: &T) {
+    element.children.push(value.to_element(name));
+}
+
+/// Append the field to the element if it has a value.
+pub(crate) fn add_optional_field<T: ToElement>(element: &mut Element, name: &str, value: &Option<T>) {
+    if let Some(ref value) = *value {
+        add_field(element, name, value);
+    }
+}
+
+/// Decode `base64Binary` element.
+pub(crate) fn decode_base64(element: &Element) -> Result<Vec<u8>> {
+    let text = element.text.as_deref().unwrap_or("");
+    let text = text.split_whitespace().collect::<String>();
+    Ok(base64::decode(&text)?)
+}
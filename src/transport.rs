@@ -0,0 +1,363 @@
+//! Transports used by `Session` to reach the SOAP endpoint.
+//!
+//! `HttpTransport` is the default and talks to a real server. `ScriptedTransport`
+//! replies with canned responses, so code built on this crate can be tested
+//! without a live Confluence.
+
+use crate::http::{self, RequestBody, StatusCode, StreamedResponse, TransportOptions};
+use crate::{Error, Result};
+use std::collections::VecDeque;
+use std::io::{Cursor, Read};
+use std::sync::Mutex;
+
+/// Way to send requests to Confluence.
+pub trait Transport: Send + Sync {
+    /// Fetch document (usually WSDL) from specified URL.
+    fn get(&self, url: &str) -> Result<http::Response>;
+
+    /// Perform a SOAP action to specified URL.
+    fn soap_action(&self, url: &str, action: &str, xml: &str) -> Result<http::Response>;
+
+    /// Perform a SOAP action with the envelope read from `body` as it is sent,
+    /// returning the response before its body is read.
+    ///
+    /// Used for attachment transfers. The default implementation buffers both
+    /// the envelope and the response and delegates to `soap_action`.
+    fn soap_action_streamed(
+        &self,
+        url: &str,
+        action: &str,
+        mut body: RequestBody,
+    ) -> Result<StreamedResponse> {
+        let mut xml = String::new();
+        body.read_to_string(&mut xml)?;
+        let response = self.soap_action(url, action, &xml)?;
+
+        Ok(StreamedResponse {
+            status: response.status,
+            body: Box::new(Cursor::new(response.body.into_bytes())),
+        })
+    }
+}
+
+/// Transport over HTTP, backed by blocking `reqwest` client.
+#[derive(Debug, Clone)]
+pub struct HttpTransport {
+    client: http::Client,
+}
+
+impl HttpTransport {
+    /// Create new transport that uses the specified HTTP client.
+    pub fn new(client: http::Client) -> HttpTransport {
+        HttpTransport { client }
+    }
+
+    /// Create new transport with HTTP client built from options.
+    pub fn with_options(options: &TransportOptions) -> Result<HttpTransport> {
+        Ok(HttpTransport::new(options.build_client()?))
+    }
+}
+
+impl Default for HttpTransport {
+    fn default() -> HttpTransport {
+        HttpTransport::new(http::Client::new())
+    }
+}
+
+impl Transport for HttpTransport {
+    fn get(&self, url: &str) -> Result<http::Response> {
+        Ok(http::get(&self.client, url)?)
+    }
+
+    fn soap_action(&self, url: &str, action: &str, xml: &str) -> Result<http::Response> {
+        Ok(http::soap_action(&self.client, url, action, xml)?)
+    }
+
+    fn soap_action_streamed(
+        &self,
+        url: &str,
+        action: &str,
+        body: RequestBody,
+    ) -> Result<StreamedResponse> {
+        Ok(http::soap_action_streamed(&self.client, url, action, body)?)
+    }
+}
+
+/// Request received by `ScriptedTransport`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Request {
+    /// Requested URL
+    pub url: String,
+    /// SOAP action name, `None` for GET requests
+    pub action: Option<String>,
+    /// SOAP envelope, `None` for GET requests
+    pub body: Option<String>,
+}
+
+#[derive(Debug)]
+struct Exchange {
+    action: Option<String>,
+    status: StatusCode,
+    body: String,
+}
+
+/**
+In-memory transport that replies with canned responses, in order.
+
+Every received request is recorded and can be inspected with `requests`.
+A request that does not match the next scripted exchange results in
+`Error::UnexpectedRequest`.
+
+## Example
+
+```
+use confluence::transport::{wsdl_with_operations, ScriptedTransport};
+
+let transport = ScriptedTransport::new()
+    .expect_get(wsdl_with_operations("http://fake/rpc", &["logout"]))
+    .expect_action("logout", r#"<?xml version="1.0" encoding="utf-8"?>
+        <soapenv:Envelope xmlns:soapenv="http://schemas.xmlsoap.org/soap/envelope/">
+            <soapenv:Body>
+                <logoutResponse><logoutReturn>true</logoutReturn></logoutResponse>
+            </soapenv:Body>
+        </soapenv:Envelope>"#);
+```
+*/
+#[derive(Debug, Default)]
+pub struct ScriptedTransport {
+    exchanges: Mutex<VecDeque<Exchange>>,
+    requests: Mutex<Vec<Request>>,
+}
+
+impl ScriptedTransport {
+    /// Create new transport without any scripted exchanges.
+    pub fn new() -> ScriptedTransport {
+        ScriptedTransport::default()
+    }
+
+    /// Reply to the next GET request with specified body.
+    pub fn expect_get<S: Into<String>>(self, body: S) -> Self {
+        self.push(None, StatusCode::OK, body.into())
+    }
+
+    /// Reply to the next SOAP action with specified body.
+    ///
+    /// The action of the received request must match `action`.
+    pub fn expect_action<A, S>(self, action: A, body: S) -> Self
+    where
+        A: Into<String>,
+        S: Into<String>,
+    {
+        self.push(Some(action.into()), StatusCode::OK, body.into())
+    }
+
+    /// Reply to the next SOAP action with specified HTTP status and body.
+    pub fn expect_action_with_status<A, S>(self, action: A, status: StatusCode, body: S) -> Self
+    where
+        A: Into<String>,
+        S: Into<String>,
+    {
+        self.push(Some(action.into()), status, body.into())
+    }
+
+    /// Requests received so far.
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Number of scripted exchanges not yet consumed.
+    pub fn remaining(&self) -> usize {
+        self.exchanges.lock().unwrap().len()
+    }
+
+    fn push(self, action: Option<String>, status: StatusCode, body: String) -> Self {
+        self.exchanges
+            .lock()
+            .unwrap()
+            .push_back(Exchange { action, status, body });
+        self
+    }
+
+    fn reply(&self, request: Request) -> Result<http::Response> {
+        let description = match request.action {
+            Some(ref action) => action.clone(),
+            None => format!("GET {}", request.url),
+        };
+        let action = request.action.clone();
+        self.requests.lock().unwrap().push(request);
+
+        let mut exchanges = self.exchanges.lock().unwrap();
+        match exchanges.front() {
+            Some(exchange) if exchange.action == action => {}
+            _ => return Err(Error::UnexpectedRequest(description)),
+        }
+
+        let exchange = exchanges.pop_front().unwrap();
+        Ok(http::Response {
+            status: exchange.status,
+            body: exchange.body,
+        })
+    }
+}
+
+impl Transport for ScriptedTransport {
+    fn get(&self, url: &str) -> Result<http::Response> {
+        self.reply(Request {
+            url: url.into(),
+            action: None,
+            body: None,
+        })
+    }
+
+    fn soap_action(&self, url: &str, action: &str, xml: &str) -> Result<http::Response> {
+        self.reply(Request {
+            url: url.into(),
+            action: Some(action.into()),
+            body: Some(xml.into()),
+        })
+    }
+}
+
+impl<T: Transport + ?Sized> Transport for std::sync::Arc<T> {
+    fn get(&self, url: &str) -> Result<http::Response> {
+        (**self).get(url)
+    }
+
+    fn soap_action(&self, url: &str, action: &str, xml: &str) -> Result<http::Response> {
+        (**self).soap_action(url, action, xml)
+    }
+
+    fn soap_action_streamed(
+        &self,
+        url: &str,
+        action: &str,
+        body: RequestBody,
+    ) -> Result<StreamedResponse> {
+        (**self).soap_action_streamed(url, action, body)
+    }
+}
+
+/// Build minimal WSDL document that declares specified operations at `url`.
+///
+/// Useful together with `ScriptedTransport` to satisfy the WSDL fetch during login.
+pub fn wsdl_with_operations(url: &str, operations: &[&str]) -> String {
+    let mut wsdl = format!(
+        concat!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+            r#"<wsdl:definitions targetNamespace="{url}" xmlns:impl="{url}" "#,
+            r#"xmlns:wsdl="http://schemas.xmlsoap.org/wsdl/">"#,
+            r#"<wsdl:portType name="ConfluenceSoapService">"#
+        ),
+        url = url
+    );
+    for operation in operations {
+        wsdl.push_str(&format!(r#"<wsdl:operation name="{}"/>"#, operation));
+    }
+    wsdl.push_str("</wsdl:portType></wsdl:definitions>");
+    wsdl
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SessionBuilder;
+    use std::sync::Arc;
+
+    const URL: &str = "http://fake/rpc/soap-axis/confluenceservice-v2";
+
+    fn envelope(body: &str) -> String {
+        format!(
+            concat!(
+                r#"<?xml version="1.0" encoding="utf-8"?>"#,
+                r#"<soapenv:Envelope xmlns:soapenv="http://schemas.xmlsoap.org/soap/envelope/" "#,
+                r#"xmlns:xsd="http://www.w3.org/2001/XMLSchema" "#,
+                r#"xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">"#,
+                r#"<soapenv:Body>{}</soapenv:Body></soapenv:Envelope>"#
+            ),
+            body
+        )
+    }
+
+    fn login_script(operations: &[&str]) -> ScriptedTransport {
+        ScriptedTransport::new()
+            .expect_get(wsdl_with_operations(URL, operations))
+            .expect_action(
+                "login",
+                envelope(r#"<loginResponse><loginReturn xsi:type="xsd:string">t0ken</loginReturn></loginResponse>"#),
+            )
+            .expect_action(
+                "getServerInfo",
+                envelope(concat!(
+                    r#"<getServerInfoResponse><getServerInfoReturn>"#,
+                    r#"<baseUrl xsi:type="xsd:string">http://fake</baseUrl>"#,
+                    r#"<buildId xsi:type="xsd:string">7801</buildId>"#,
+                    r#"<developmentBuild xsi:type="xsd:boolean">false</developmentBuild>"#,
+                    r#"<majorVersion xsi:type="xsd:int">6</majorVersion>"#,
+                    r#"<minorVersion xsi:type="xsd:int">4</minorVersion>"#,
+                    r#"<patchLevel xsi:type="xsd:int">0</patchLevel>"#,
+                    r#"</getServerInfoReturn></getServerInfoResponse>"#
+                )),
+            )
+    }
+
+    fn logout_response() -> String {
+        envelope(r#"<logoutResponse><logoutReturn xsi:type="xsd:boolean">true</logoutReturn></logoutResponse>"#)
+    }
+
+    #[test]
+    fn session_runs_against_scripted_transport() {
+        let transport = Arc::new(
+            login_script(&["login", "logout", "getServerInfo", "getChildren"])
+                .expect_action(
+                    "getChildren",
+                    envelope(concat!(
+                        r#"<getChildrenResponse><getChildrenReturn>"#,
+                        r#"<item><id xsi:type="xsd:long">2</id>"#,
+                        r#"<parentId xsi:type="xsd:long">1</parentId>"#,
+                        r#"<space xsi:type="xsd:string">KEY</space>"#,
+                        r#"<title xsi:type="xsd:string">Child</title>"#,
+                        r#"<url xsi:type="xsd:string">http://fake/child</url></item>"#,
+                        r#"</getChildrenReturn></getChildrenResponse>"#
+                    )),
+                )
+                .expect_action("logout", logout_response()),
+        );
+
+        {
+            let session = SessionBuilder::new()
+                .transport(transport.clone())
+                .login("http://fake", "user", "pass")
+                .unwrap();
+
+            let children = session.get_children(1).unwrap();
+            assert_eq!(children.len(), 1);
+            assert_eq!(children[0].title, "Child");
+        }
+
+        assert_eq!(transport.remaining(), 0);
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].url, [URL, "?wsdl"].concat());
+        let children_request = requests[3].body.as_ref().unwrap();
+        assert!(children_request.contains("<token>t0ken</token>"));
+        assert!(children_request.contains("<pageId>1</pageId>"));
+    }
+
+    #[test]
+    fn unexpected_request_is_an_error() {
+        let transport = Arc::new(
+            login_script(&["login", "logout", "getServerInfo", "getPage"])
+                .expect_action("logout", logout_response()),
+        );
+
+        let session = SessionBuilder::new()
+            .transport(transport.clone())
+            .login("http://fake", "user", "pass")
+            .unwrap();
+
+        match session.get_page_by_id(1) {
+            Err(Error::UnexpectedRequest(ref action)) if action == "getPage" => {}
+            other => panic!("expected unexpected request error, received {:?}", other),
+        }
+    }
+}
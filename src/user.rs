@@ -0,0 +1,95 @@
+use chrono::offset::Utc;
+use chrono::DateTime;
+
+/// User.
+#[derive(Debug)]
+pub struct User {
+    /// The username
+    pub name: String,
+    /// The full name of the user
+    pub full_name: String,
+    /// The email address of the user
+    pub email: String,
+    /// The url to view the profile of this user online
+    pub url: String,
+}
+
+/// User Object for adding or editing a User.
+#[derive(Debug)]
+pub struct UpdateUser {
+    /// The username
+    pub name: String,
+    /// The full name of the user
+    pub full_name: String,
+    /// The email address of the user
+    pub email: String,
+}
+
+impl UpdateUser {
+    pub fn new(name: &str, full_name: &str, email: &str) -> UpdateUser {
+        UpdateUser {
+            name: name.into(),
+            full_name: full_name.into(),
+            email: email.into(),
+        }
+    }
+}
+
+impl From<User> for UpdateUser {
+    fn from(other: User) -> UpdateUser {
+        UpdateUser {
+            name: other.name,
+            full_name: other.full_name,
+            email: other.email,
+        }
+    }
+}
+
+/// Profile information of a user.
+#[derive(Debug)]
+pub struct UserInformation {
+    /// The id of the profile
+    pub id: i64,
+    /// The username
+    pub username: String,
+    /// The text of the profile
+    pub content: String,
+    /// The version number of the profile
+    pub version: i32,
+    /// Timestamp profile was created
+    pub created: Option<DateTime<Utc>>,
+    /// Username of the creator
+    pub creator: Option<String>,
+    /// Timestamp profile was modified
+    pub modified: Option<DateTime<Utc>>,
+    /// Username of the profile's last modifier
+    pub modifier: Option<String>,
+}
+
+/// Group of users.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Group {
+    /// The name of the group
+    pub name: String,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn user_can_be_edited() {
+        let user = User {
+            name: "jdoe".into(),
+            full_name: "Jane Doe".into(),
+            email: "jdoe@example.com".into(),
+            url: "https://confluence/display/~jdoe".into(),
+        };
+
+        let update = UpdateUser::from(user);
+        assert_eq!(
+            (update.name.as_str(), update.full_name.as_str(), update.email.as_str()),
+            ("jdoe", "Jane Doe", "jdoe@example.com")
+        );
+    }
+}
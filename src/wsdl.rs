@@ -1,8 +1,23 @@
 //! WSDL inspection helpers.
+//!
+//! `Wsdl` describes the remote API: complex types, messages, port types,
+//! bindings and service endpoints. It can be used to introspect the arguments
+//! of any operation and to validate a `Method` before sending it.
 
-use http;
+use crate::rpser::{Method, RpcError};
+use crate::transport::Transport;
 use std::collections::HashMap;
-use xml::reader::{EventReader, XmlEvent};
+use std::path::{Path, PathBuf};
+use std::{fmt, fs, io};
+use xmltree::Element;
+
+/// Descriptor of confluenceservice-v2 API bundled with the crate.
+pub const BUNDLED: &str = include_str!("../wsdl/confluenceservice-v2.wsdl");
+
+/// Namespace of XML Schema builtin types.
+pub const XSD_NAMESPACE: &str = "http://www.w3.org/2001/XMLSchema";
+/// Namespace of SOAP encoding types.
+pub const SOAP_ENCODING_NAMESPACE: &str = "http://schemas.xmlsoap.org/soap/encoding/";
 
 /// WSDL operation info.
 #[derive(Debug)]
@@ -10,48 +25,891 @@ pub struct Operation {
     pub url: String,
 }
 
+/// Qualified name of a type.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QName {
+    /// Resolved namespace URI, `None` if the prefix was not declared
+    pub namespace: Option<String>,
+    /// Local name
+    pub name: String,
+}
+
+impl QName {
+    /// Check if this is a builtin XML Schema or SOAP encoding type.
+    pub fn is_builtin(&self) -> bool {
+        match self.namespace {
+            Some(ref namespace) => {
+                namespace == XSD_NAMESPACE || namespace == SOAP_ENCODING_NAMESPACE
+            }
+            None => false,
+        }
+    }
+
+    /// Resolve `prefix:name` value in the scope of specified element.
+    fn resolve(value: &str, scope: &Element) -> QName {
+        let (prefix, name) = match value.find(':') {
+            Some(index) => (&value[..index], &value[index + 1..]),
+            None => ("", value),
+        };
+        QName {
+            namespace: scope
+                .namespaces
+                .as_ref()
+                .and_then(|namespaces| namespaces.get(prefix))
+                .map(String::from),
+            name: name.into(),
+        }
+    }
+}
+
+impl fmt::Display for QName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.namespace {
+            Some(ref namespace) => write!(f, "{{{}}}{}", namespace, self.name),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+/// Complex type declared in WSDL schema.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ComplexType {
+    /// Structure with named fields, optionally extending a base type
+    Struct {
+        base: Option<QName>,
+        fields: Vec<Field>,
+    },
+    /// SOAP encoded array of items
+    Array { item_type: QName },
+}
+
+/// Field of a structure.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    pub name: String,
+    pub type_name: QName,
+    pub nillable: bool,
+}
+
+/// Message with its parts, used as input or output of an operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message {
+    pub name: String,
+    pub parts: Vec<Part>,
+}
+
+/// Part of a message, one argument or return value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Part {
+    pub name: String,
+    pub type_name: QName,
+}
+
+/// Abstract set of operations.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PortType {
+    pub name: String,
+    pub operations: Vec<PortOperation>,
+}
+
+/// Operation of a port type, referring to its messages by name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PortOperation {
+    pub name: String,
+    pub input: Option<String>,
+    pub output: Option<String>,
+    pub faults: Vec<String>,
+}
+
+/// Concrete protocol of a port type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Binding {
+    pub name: String,
+    pub port_type: String,
+    pub style: Option<String>,
+    pub operations: Vec<BindingOperation>,
+}
+
+/// SOAP details of a bound operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BindingOperation {
+    pub name: String,
+    pub soap_action: Option<String>,
+    pub namespace: Option<String>,
+}
+
+/// Service with its endpoints.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Service {
+    pub name: String,
+    pub ports: Vec<Port>,
+}
+
+/// Service endpoint.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Port {
+    pub name: String,
+    pub binding: String,
+    pub address: Option<String>,
+}
+
+/// Signature of one (possibly overloaded) operation.
+#[derive(Debug, Clone, Copy)]
+pub struct Signature<'a> {
+    pub name: &'a str,
+    pub inputs: &'a [Part],
+    pub outputs: &'a [Part],
+}
+
 /// WSDL document.
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct Wsdl {
+    pub target_namespace: Option<String>,
     pub operations: HashMap<String, Operation>,
+    pub types: HashMap<String, ComplexType>,
+    pub messages: HashMap<String, Message>,
+    pub port_types: HashMap<String, PortType>,
+    pub bindings: HashMap<String, Binding>,
+    pub services: HashMap<String, Service>,
+}
+
+/// Reason why `Method` does not match WSDL.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationError {
+    /// Operation is not declared
+    UnknownOperation { operation: String },
+    /// No overload of the operation takes this number of arguments
+    ArgumentCount {
+        operation: String,
+        expected: Vec<usize>,
+        given: usize,
+    },
+    /// Argument text does not match its builtin type
+    InvalidValue {
+        operation: String,
+        argument: String,
+        expected_type: QName,
+    },
+    /// Structure argument contains a field not declared in its type
+    UnknownField {
+        operation: String,
+        argument: String,
+        field: String,
+    },
+}
+
+impl Wsdl {
+    /// All signatures of the operation, one per overload.
+    pub fn signatures(&self, operation: &str) -> Vec<Signature<'_>> {
+        let parts = |message: &Option<String>| -> &[Part] {
+            message
+                .as_ref()
+                .and_then(|name| self.messages.get(name))
+                .map(|message| &message.parts[..])
+                .unwrap_or(&[])
+        };
+
+        self.port_types
+            .values()
+            .flat_map(|port_type| port_type.operations.iter())
+            .filter(|op| op.name == operation)
+            .map(|op| Signature {
+                name: &op.name,
+                inputs: parts(&op.input),
+                outputs: parts(&op.output),
+            })
+            .collect()
+    }
+
+    /// Endpoint address of the service, if declared.
+    pub fn address(&self) -> Option<&str> {
+        self.services
+            .values()
+            .flat_map(|service| service.ports.iter())
+            .filter_map(|port| port.address.as_deref())
+            .next()
+    }
+
+    /// Send all operations to specified endpoint url.
+    ///
+    /// Used for WSDL that was not fetched from the server it is used with.
+    pub fn set_endpoint(&mut self, endpoint: &str) {
+        for operation in self.operations.values_mut() {
+            operation.url = endpoint.into();
+        }
+    }
+
+    /// Fields of the structure type, including fields of its base types.
+    pub fn fields(&self, type_name: &QName) -> Vec<&Field> {
+        let mut fields = vec![];
+        let mut next = Some(type_name);
+        while let Some(type_name) = next.take() {
+            if let Some(ComplexType::Struct { base, fields: own }) = self.types.get(&type_name.name)
+            {
+                fields.extend(own.iter());
+                next = base.as_ref();
+            }
+        }
+        fields
+    }
+
+    /// Check that the method matches one of operation's signatures.
+    ///
+    /// Arguments are matched by position, because the WSDL names them `in0`, `in1`, ...
+    pub fn validate(&self, method: &Method) -> Result<(), ValidationError> {
+        let signatures = self.signatures(&method.name);
+        if signatures.is_empty() {
+            return Err(ValidationError::UnknownOperation {
+                operation: method.name.clone(),
+            });
+        }
+
+        let mut first_error = None;
+        for signature in signatures
+            .iter()
+            .filter(|s| s.inputs.len() == method.args.len())
+        {
+            match self.validate_args(method, signature) {
+                Ok(()) => return Ok(()),
+                Err(e) => {
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+
+        Err(first_error.unwrap_or_else(|| ValidationError::ArgumentCount {
+            operation: method.name.clone(),
+            expected: signatures.iter().map(|s| s.inputs.len()).collect(),
+            given: method.args.len(),
+        }))
+    }
+
+    fn validate_args(&self, method: &Method, signature: &Signature) -> Result<(), ValidationError> {
+        for (arg, part) in method.args.iter().zip(signature.inputs) {
+            if part.type_name.is_builtin() {
+                if !is_valid_builtin(&part.type_name.name, arg.text.as_deref()) {
+                    return Err(ValidationError::InvalidValue {
+                        operation: method.name.clone(),
+                        argument: arg.name.clone(),
+                        expected_type: part.type_name.clone(),
+                    });
+                }
+            } else if let Some(ComplexType::Struct { .. }) = self.types.get(&part.type_name.name) {
+                let fields = self.fields(&part.type_name);
+                for child in &arg.children {
+                    if !fields.iter().any(|field| field.name == child.name) {
+                        return Err(ValidationError::UnknownField {
+                            operation: method.name.clone(),
+                            argument: arg.name.clone(),
+                            field: child.name.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn is_valid_builtin(type_name: &str, text: Option<&str>) -> bool {
+    let text = text.unwrap_or("").trim();
+    match type_name {
+        "long" => text.parse::<i64>().is_ok(),
+        "int" => text.parse::<i32>().is_ok(),
+        "boolean" => text == "true" || text == "false",
+        _ => true,
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ValidationError::UnknownOperation { ref operation } => {
+                write!(f, "operation {} is not declared in WSDL", operation)
+            }
+            ValidationError::ArgumentCount {
+                ref operation,
+                ref expected,
+                given,
+            } => write!(
+                f,
+                "operation {} expects {:?} arguments, given {}",
+                operation, expected, given
+            ),
+            ValidationError::InvalidValue {
+                ref operation,
+                ref argument,
+                ref expected_type,
+            } => write!(
+                f,
+                "argument {} of operation {} is not a valid {}",
+                argument, operation, expected_type.name
+            ),
+            ValidationError::UnknownField {
+                ref operation,
+                ref argument,
+                ref field,
+            } => write!(
+                f,
+                "argument {} of operation {} has unknown field {}",
+                argument, operation, field
+            ),
+        }
+    }
+}
+
+/**
+Where the session gets the WSDL from.
+
+By default, the WSDL is fetched from the server every time a session is created.
+
+## Example
+
+```no_run
+use confluence::wsdl::WsdlSource;
+
+let session = confluence::SessionBuilder::new()
+    .wsdl_source(WsdlSource::Cache("/var/cache/publisher".into()))
+    .login("https://confluence", "user", "pass")
+    .unwrap();
+```
+*/
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum WsdlSource {
+    /// Fetch WSDL from the server
+    #[default]
+    Fetch,
+    /// Use the WSDL bundled with the crate, without any requests
+    Bundled,
+    /// Load WSDL from the file, without any requests
+    File(PathBuf),
+    /// Use WSDL cached in the directory, fetching it only when missing or
+    /// when the build id of the server changes
+    Cache(PathBuf),
+}
+
+/// WSDL documents stored on disk, keyed by server URL and build id.
+#[derive(Debug, Clone)]
+pub struct WsdlCache {
+    dir: PathBuf,
+}
+
+impl WsdlCache {
+    /// Use specified directory for cached documents.
+    pub fn new<P: Into<PathBuf>>(dir: P) -> WsdlCache {
+        WsdlCache { dir: dir.into() }
+    }
+
+    /// Load cached WSDL of the server, regardless of its build id.
+    ///
+    /// Unreadable or invalid entries are treated as missing.
+    pub fn load(&self, server_url: &str) -> Option<Wsdl> {
+        let prefix = cache_key(server_url) + "-";
+        let entries = fs::read_dir(&self.dir).ok()?;
+
+        for entry in entries.filter_map(|entry| entry.ok()) {
+            if !is_cache_entry(&entry, &prefix) {
+                continue;
+            }
+            match fs::read_to_string(entry.path()).map(|xml| parse(&xml)) {
+                Ok(Ok(wsdl)) => return Some(wsdl),
+                Ok(Err(e)) => warn!("ignoring invalid cached WSDL {:?}: {:?}", entry.path(), e),
+                Err(e) => warn!("ignoring unreadable cached WSDL {:?}: {}", entry.path(), e),
+            }
+        }
+
+        None
+    }
+
+    /// Check if WSDL of the server build is cached.
+    pub fn contains(&self, server_url: &str, build_id: &str) -> bool {
+        self.path(server_url, build_id).is_file()
+    }
+
+    /// Store WSDL of the server with the build id, replacing entries of other builds.
+    pub fn store(&self, server_url: &str, build_id: &str, xml: &str) -> io::Result<()> {
+        let prefix = cache_key(server_url) + "-";
+        fs::create_dir_all(&self.dir)?;
+
+        for entry in fs::read_dir(&self.dir)?.filter_map(|entry| entry.ok()) {
+            if is_cache_entry(&entry, &prefix) {
+                fs::remove_file(entry.path())?;
+            }
+        }
+
+        fs::write(self.path(server_url, build_id), xml)
+    }
+
+    fn path(&self, server_url: &str, build_id: &str) -> PathBuf {
+        let file_name = format!("{}-{}.wsdl", cache_key(server_url), cache_key(build_id));
+        self.dir.join(file_name)
+    }
+}
+
+/// File name safe representation of the value, never containing `-`.
+///
+/// Characters other than lowercase letters, digits and `.` are percent-encoded, so distinct
+/// values get distinct keys, even on case-insensitive file systems.
+fn cache_key(value: &str) -> String {
+    let mut key = String::new();
+    for byte in value.trim_end_matches('/').bytes() {
+        match byte {
+            b'a'..=b'z' | b'0'..=b'9' | b'.' => key.push(byte as char),
+            _ => key.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    key
+}
+
+/// Check if the directory entry is a cached WSDL whose key starts with the prefix.
+fn is_cache_entry(entry: &fs::DirEntry, prefix: &str) -> bool {
+    matches!(
+        entry.file_name().to_str(),
+        Some(name) if name.starts_with(prefix) && name.ends_with(".wsdl")
+    )
+}
+
+/// Parse the bundled WSDL, with operations pointing to specified endpoint.
+pub fn bundled(endpoint: &str) -> Wsdl {
+    let mut wsdl = parse(BUNDLED).expect("bundled WSDL is valid");
+    wsdl.set_endpoint(endpoint);
+    wsdl
+}
+
+/// Load WSDL from file, with operations pointing to specified endpoint.
+pub fn load<P: AsRef<Path>>(path: P, endpoint: &str) -> crate::Result<Wsdl> {
+    let mut wsdl = parse(&fs::read_to_string(path)?)?;
+    wsdl.set_endpoint(endpoint);
+    Ok(wsdl)
 }
 
 /// Fetch WSDL from specified URL and store results in `Wsdl` structure.
-pub fn fetch(url: &str) -> http::Result<Wsdl> {
-    let response = http::get(&url)?;
-    let mut bytes = response.body.as_bytes();
-
-    let mut operations = HashMap::new();
-
-    let parser = EventReader::new(&mut bytes);
-    for e in parser {
-        match e {
-            Ok(XmlEvent::StartElement {
-                ref name,
-                ref attributes,
-                ref namespace,
-            }) => {
-                if name.to_string().contains("wsdl:operation") {
-                    if let (Some(name_attribute), Some(impl_url)) = (
-                        attributes.iter().find(|a| a.name.to_string() == "name"),
-                        namespace.get("impl"),
-                    ) {
-                        operations.insert(
-                            name_attribute.value.to_string(),
-                            Operation {
-                                url: impl_url.into(),
-                            },
-                        );
+pub fn fetch(transport: &dyn Transport, url: &str) -> crate::Result<Wsdl> {
+    let response = transport.get(url)?;
+    Ok(parse(&response.body)?)
+}
+
+/// Fetch WSDL from specified URL without blocking.
+#[cfg(feature = "async")]
+pub async fn fetch_async(client: &reqwest::Client, url: &str) -> crate::Result<Wsdl> {
+    let response = crate::http::get_async(client, url).await?;
+    Ok(parse(&response.body)?)
+}
+
+/// Parse WSDL document and store results in `Wsdl` structure.
+pub fn parse(xml: &str) -> Result<Wsdl, RpcError> {
+    let mut bytes = xml.as_bytes();
+    let root = Element::parse(&mut bytes).map_err(|e| RpcError::MalformedXml {
+        message: e.to_string(),
+    })?;
+
+    if root.name != "definitions" {
+        return Err(RpcError::UnexpectedElement { tag: root.name });
+    }
+
+    let mut wsdl = Wsdl {
+        target_namespace: root.attributes.get("targetNamespace").cloned(),
+        ..Wsdl::default()
+    };
+
+    for child in &root.children {
+        match child.name.as_str() {
+            "types" => {
+                for schema in children(child, "schema") {
+                    for complex_type in children(schema, "complexType") {
+                        if let Some(name) = complex_type.attributes.get("name") {
+                            wsdl.types
+                                .insert(name.clone(), parse_complex_type(complex_type));
+                        }
                     }
                 }
             }
-            Err(e) => {
-                error!("Error: {}", e);
-                break;
+            "message" => {
+                let message = Message {
+                    name: attribute(child, "name"),
+                    parts: children(child, "part")
+                        .map(|part| Part {
+                            name: attribute(part, "name"),
+                            type_name: QName::resolve(
+                                part.attributes
+                                    .get("type")
+                                    .or_else(|| part.attributes.get("element"))
+                                    .map(String::as_str)
+                                    .unwrap_or(""),
+                                part,
+                            ),
+                        })
+                        .collect(),
+                };
+                wsdl.messages.insert(message.name.clone(), message);
+            }
+            "portType" => {
+                let port_type = PortType {
+                    name: attribute(child, "name"),
+                    operations: children(child, "operation")
+                        .map(|operation| PortOperation {
+                            name: attribute(operation, "name"),
+                            input: message_ref(operation, "input"),
+                            output: message_ref(operation, "output"),
+                            faults: children(operation, "fault")
+                                .filter_map(|fault| fault.attributes.get("message"))
+                                .map(|message| local_name(message))
+                                .collect(),
+                        })
+                        .collect(),
+                };
+                wsdl.port_types.insert(port_type.name.clone(), port_type);
+            }
+            "binding" => {
+                let binding = Binding {
+                    name: attribute(child, "name"),
+                    port_type: local_name(&attribute(child, "type")),
+                    style: children(child, "binding")
+                        .filter_map(|soap| soap.attributes.get("style"))
+                        .next()
+                        .cloned(),
+                    operations: children(child, "operation")
+                        .map(|operation| BindingOperation {
+                            name: attribute(operation, "name"),
+                            soap_action: children(operation, "operation")
+                                .filter_map(|soap| soap.attributes.get("soapAction"))
+                                .next()
+                                .cloned(),
+                            namespace: children(operation, "input")
+                                .flat_map(|input| children(input, "body"))
+                                .filter_map(|body| body.attributes.get("namespace"))
+                                .next()
+                                .cloned(),
+                        })
+                        .collect(),
+                };
+                wsdl.bindings.insert(binding.name.clone(), binding);
+            }
+            "service" => {
+                let service = Service {
+                    name: attribute(child, "name"),
+                    ports: children(child, "port")
+                        .map(|port| Port {
+                            name: attribute(port, "name"),
+                            binding: local_name(&attribute(port, "binding")),
+                            address: children(port, "address")
+                                .filter_map(|address| address.attributes.get("location"))
+                                .next()
+                                .cloned(),
+                        })
+                        .collect(),
+                };
+                wsdl.services.insert(service.name.clone(), service);
             }
             _ => {}
         }
     }
 
-    Ok(Wsdl { operations })
+    let url = root
+        .namespaces
+        .as_ref()
+        .and_then(|namespaces| namespaces.get("impl"))
+        .map(String::from)
+        .or_else(|| wsdl.target_namespace.clone());
+    if let Some(url) = url {
+        for port_type in wsdl.port_types.values() {
+            for operation in &port_type.operations {
+                wsdl.operations
+                    .insert(operation.name.clone(), Operation { url: url.clone() });
+            }
+        }
+    }
+
+    Ok(wsdl)
+}
+
+fn parse_complex_type(element: &Element) -> ComplexType {
+    if let Some(content) = children(element, "complexContent").next() {
+        if let Some(restriction) = children(content, "restriction").next() {
+            let array_type = children(restriction, "attribute")
+                .filter_map(|attribute| attribute.attributes.get("arrayType"))
+                .next();
+            if let Some(array_type) = array_type {
+                return ComplexType::Array {
+                    item_type: QName::resolve(array_type.trim_end_matches("[]"), restriction),
+                };
+            }
+        }
+        if let Some(extension) = children(content, "extension").next() {
+            return ComplexType::Struct {
+                base: extension
+                    .attributes
+                    .get("base")
+                    .map(|base| QName::resolve(base, extension)),
+                fields: sequence_fields(extension),
+            };
+        }
+    }
+
+    ComplexType::Struct {
+        base: None,
+        fields: sequence_fields(element),
+    }
+}
+
+fn sequence_fields(element: &Element) -> Vec<Field> {
+    children(element, "sequence")
+        .flat_map(|sequence| children(sequence, "element"))
+        .map(|field| Field {
+            name: attribute(field, "name"),
+            type_name: QName::resolve(
+                field.attributes.get("type").map(String::as_str).unwrap_or(""),
+                field,
+            ),
+            nillable: field.attributes.get("nillable").map(String::as_str) == Some("true"),
+        })
+        .collect()
+}
+
+fn children<'a>(element: &'a Element, name: &'a str) -> impl Iterator<Item = &'a Element> + 'a {
+    element.children.iter().filter(move |child| child.name == name)
+}
+
+fn attribute(element: &Element, name: &str) -> String {
+    element.attributes.get(name).cloned().unwrap_or_default()
+}
+
+fn message_ref(operation: &Element, direction: &str) -> Option<String> {
+    children(operation, direction)
+        .filter_map(|element| element.attributes.get("message"))
+        .map(|message| local_name(message))
+        .next()
+}
+
+fn local_name(value: &str) -> String {
+    value.rsplit(':').next().unwrap_or_default().into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::rpser::xml::BuildElement;
+
+    const WSDL: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <wsdl:definitions targetNamespace="https://confluence/rpc/soap-axis/confluenceservice-v2"
+            xmlns:impl="https://confluence/rpc/soap-axis/confluenceservice-v2"
+            xmlns:tns2="http://beans.soap.rpc.confluence.atlassian.com"
+            xmlns:wsdl="http://schemas.xmlsoap.org/wsdl/"
+            xmlns:wsdlsoap="http://schemas.xmlsoap.org/wsdl/soap/"
+            xmlns:xsd="http://www.w3.org/2001/XMLSchema">
+            <wsdl:types>
+                <schema targetNamespace="http://beans.soap.rpc.confluence.atlassian.com" xmlns="http://www.w3.org/2001/XMLSchema">
+                    <complexType name="RemoteAbstractPage">
+                        <sequence>
+                            <element name="id" type="xsd:long"/>
+                            <element name="space" nillable="true" type="xsd:string"/>
+                            <element name="title" nillable="true" type="xsd:string"/>
+                            <element name="version" type="xsd:int"/>
+                        </sequence>
+                    </complexType>
+                    <complexType name="RemotePage">
+                        <complexContent>
+                            <extension base="tns2:RemoteAbstractPage">
+                                <sequence>
+                                    <element name="content" nillable="true" type="xsd:string"/>
+                                    <element name="parentId" type="xsd:long"/>
+                                </sequence>
+                            </extension>
+                        </complexContent>
+                    </complexType>
+                    <complexType name="ArrayOf_tns2_RemotePageSummary">
+                        <complexContent>
+                            <restriction base="soapenc:Array" xmlns:soapenc="http://schemas.xmlsoap.org/soap/encoding/">
+                                <attribute ref="soapenc:arrayType" wsdl:arrayType="tns2:RemotePageSummary[]"/>
+                            </restriction>
+                        </complexContent>
+                    </complexType>
+                </schema>
+            </wsdl:types>
+            <wsdl:message name="getPageRequest">
+                <wsdl:part name="in0" type="xsd:string"/>
+                <wsdl:part name="in1" type="xsd:long"/>
+            </wsdl:message>
+            <wsdl:message name="getPageRequest1">
+                <wsdl:part name="in0" type="xsd:string"/>
+                <wsdl:part name="in1" type="xsd:string"/>
+                <wsdl:part name="in2" type="xsd:string"/>
+            </wsdl:message>
+            <wsdl:message name="getPageResponse">
+                <wsdl:part name="getPageReturn" type="tns2:RemotePage"/>
+            </wsdl:message>
+            <wsdl:message name="storePageRequest">
+                <wsdl:part name="in0" type="xsd:string"/>
+                <wsdl:part name="in1" type="tns2:RemotePage"/>
+            </wsdl:message>
+            <wsdl:message name="storePageResponse">
+                <wsdl:part name="storePageReturn" type="tns2:RemotePage"/>
+            </wsdl:message>
+            <wsdl:portType name="ConfluenceSoapService">
+                <wsdl:operation name="getPage" parameterOrder="in0 in1">
+                    <wsdl:input message="impl:getPageRequest" name="getPageRequest"/>
+                    <wsdl:output message="impl:getPageResponse" name="getPageResponse"/>
+                </wsdl:operation>
+                <wsdl:operation name="getPage" parameterOrder="in0 in1 in2">
+                    <wsdl:input message="impl:getPageRequest1" name="getPageRequest1"/>
+                    <wsdl:output message="impl:getPageResponse" name="getPageResponse1"/>
+                </wsdl:operation>
+                <wsdl:operation name="storePage" parameterOrder="in0 in1">
+                    <wsdl:input message="impl:storePageRequest" name="storePageRequest"/>
+                    <wsdl:output message="impl:storePageResponse" name="storePageResponse"/>
+                </wsdl:operation>
+            </wsdl:portType>
+            <wsdl:binding name="confluenceservice-v2SoapBinding" type="impl:ConfluenceSoapService">
+                <wsdlsoap:binding style="rpc" transport="http://schemas.xmlsoap.org/soap/http"/>
+                <wsdl:operation name="getPage">
+                    <wsdlsoap:operation soapAction=""/>
+                    <wsdl:input name="getPageRequest">
+                        <wsdlsoap:body encodingStyle="http://schemas.xmlsoap.org/soap/encoding/" namespace="http://soap.rpc.confluence.atlassian.com" use="encoded"/>
+                    </wsdl:input>
+                </wsdl:operation>
+            </wsdl:binding>
+            <wsdl:service name="ConfluenceSoapServiceService">
+                <wsdl:port binding="impl:confluenceservice-v2SoapBinding" name="confluenceservice-v2">
+                    <wsdlsoap:address location="https://confluence/rpc/soap-axis/confluenceservice-v2"/>
+                </wsdl:port>
+            </wsdl:service>
+        </wsdl:definitions>
+    "#;
+
+    fn xsd(name: &str) -> QName {
+        QName {
+            namespace: Some(XSD_NAMESPACE.into()),
+            name: name.into(),
+        }
+    }
+
+    fn token() -> Element {
+        Element::node("token").with_text("t")
+    }
+
+    #[test]
+    fn parses_wsdl_model() {
+        let wsdl = parse(WSDL).unwrap();
+
+        assert_eq!(
+            wsdl.operations["getPage"].url,
+            "https://confluence/rpc/soap-axis/confluenceservice-v2"
+        );
+        assert_eq!(
+            wsdl.address(),
+            Some("https://confluence/rpc/soap-axis/confluenceservice-v2")
+        );
+        assert_eq!(
+            wsdl.bindings["confluenceservice-v2SoapBinding"].style,
+            Some("rpc".into())
+        );
+
+        let signatures = wsdl.signatures("getPage");
+        assert_eq!(signatures.len(), 2);
+        assert_eq!(signatures[0].inputs[1].type_name, xsd("long"));
+        assert_eq!(signatures[0].outputs[0].type_name.name, "RemotePage");
+
+        let page = QName {
+            namespace: Some("http://beans.soap.rpc.confluence.atlassian.com".into()),
+            name: "RemotePage".into(),
+        };
+        let fields = wsdl
+            .fields(&page)
+            .iter()
+            .map(|field| field.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            fields,
+            vec!["content", "parentId", "id", "space", "title", "version"]
+        );
+
+        match wsdl.types["ArrayOf_tns2_RemotePageSummary"] {
+            ComplexType::Array { ref item_type } => assert_eq!(item_type.name, "RemotePageSummary"),
+            ref other => panic!("expected array, received {:?}", other),
+        }
+    }
+
+    #[test]
+    fn validates_methods() {
+        let wsdl = parse(WSDL).unwrap();
+
+        let by_id = Method::new("getPage")
+            .with(token())
+            .with(Element::node("pageId").with_text("12"));
+        assert_eq!(wsdl.validate(&by_id), Ok(()));
+
+        let by_title = Method::new("getPage")
+            .with(token())
+            .with(Element::node("spaceKey").with_text("KEY"))
+            .with(Element::node("pageTitle").with_text("Title"));
+        assert_eq!(wsdl.validate(&by_title), Ok(()));
+
+        assert_eq!(
+            wsdl.validate(&Method::new("getPage").with(token())),
+            Err(ValidationError::ArgumentCount {
+                operation: "getPage".into(),
+                expected: vec![2, 3],
+                given: 1,
+            })
+        );
+
+        let bad_id = Method::new("getPage")
+            .with(token())
+            .with(Element::node("pageId").with_text("x"));
+        assert_eq!(
+            wsdl.validate(&bad_id),
+            Err(ValidationError::InvalidValue {
+                operation: "getPage".into(),
+                argument: "pageId".into(),
+                expected_type: xsd("long"),
+            })
+        );
+
+        let bad_page = Method::new("storePage")
+            .with(token())
+            .with(Element::node("page").with_child(Element::node("body").with_text("x")));
+        assert_eq!(
+            wsdl.validate(&bad_page),
+            Err(ValidationError::UnknownField {
+                operation: "storePage".into(),
+                argument: "page".into(),
+                field: "body".into(),
+            })
+        );
+
+        assert_eq!(
+            wsdl.validate(&Method::new("removePage")),
+            Err(ValidationError::UnknownOperation {
+                operation: "removePage".into()
+            })
+        );
+    }
+
+    #[test]
+    fn cache_keys_of_distinct_servers_do_not_collide() {
+        let urls = [
+            "https://a/x-y",
+            "https://a/x_y",
+            "https://a/X_y",
+            "http://h:8080",
+            "http://h_8080",
+            "http://h%3A8080",
+        ];
+        let keys: std::collections::HashSet<_> = urls.iter().map(|url| cache_key(url)).collect();
+        assert_eq!(keys.len(), urls.len());
+        assert!(keys.iter().all(|key| !key.contains('-')));
+        assert_eq!(cache_key("https://a/"), cache_key("https://a"));
+
+        let dir = std::env::temp_dir().join(format!("confluence-wsdl-keys-{}", std::process::id()));
+        let cache = WsdlCache::new(&dir);
+        cache.store("https://a/x-y", "8100", WSDL).unwrap();
+        cache.store("http://h:8080", "8100", WSDL).unwrap();
+
+        assert!(cache.load("https://a/x-y").is_some());
+        assert!(cache.load("https://a/x_y").is_none());
+        assert!(cache.contains("http://h:8080", "8100"));
+        assert!(!cache.contains("http://h_8080", "8100"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
 }
@@ -0,0 +1,482 @@
+/*!
+Generates `src/generated.rs` from the bundled `wsdl/confluenceservice-v2.wsdl`.
+
+```text
+cargo xtask codegen [WSDL] [OUTPUT]
+```
+
+Every operation becomes a function that takes the `Session` and typed arguments,
+and every structure becomes a Rust struct with `FromElement` and `ToElement` impls.
+*/
+
+use crate::{fail, BUNDLED_WSDL};
+use confluence::wsdl::{ComplexType, Part, QName, Wsdl};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::fs;
+
+const DEFAULT_OUTPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../src/generated.rs");
+
+const KEYWORDS: &[&str] = &[
+    "as", "break", "const", "continue", "crate", "else", "enum", "extern", "fn", "for", "if",
+    "impl", "in", "let", "loop", "match", "mod", "move", "mut", "ref", "return", "self",
+    "static", "struct", "super", "trait", "type", "unsafe", "use", "where", "while",
+];
+
+pub fn run<I: Iterator<Item = String>>(mut args: I) {
+    let input = args.next().unwrap_or_else(|| BUNDLED_WSDL.into());
+    let output = args.next().unwrap_or_else(|| DEFAULT_OUTPUT.into());
+
+    let xml = fs::read_to_string(&input).unwrap_or_else(|e| fail(&input, e));
+    let wsdl = confluence::wsdl::parse(&xml).unwrap_or_else(|e| fail(&input, format!("{:?}", e)));
+    fs::write(&output, generate(&wsdl)).unwrap_or_else(|e| fail(&output, e));
+}
+
+/// Rust representation of a WSDL type.
+#[derive(Debug, Clone, PartialEq)]
+enum Type {
+    String,
+    Long,
+    Int,
+    Bool,
+    DateTime,
+    Bytes,
+    Struct(String),
+    Array(Box<Type>),
+    Raw,
+}
+
+impl Type {
+    fn of(wsdl: &Wsdl, name: &QName) -> Type {
+        if name.is_builtin() {
+            return match name.name.as_str() {
+                "string" => Type::String,
+                "long" => Type::Long,
+                "int" => Type::Int,
+                "boolean" => Type::Bool,
+                "dateTime" => Type::DateTime,
+                "base64Binary" => Type::Bytes,
+                _ => Type::Raw,
+            };
+        }
+        match wsdl.types.get(&name.name) {
+            Some(ComplexType::Struct { .. }) => Type::Struct(name.name.clone()),
+            Some(ComplexType::Array { item_type }) => match Type::of(wsdl, item_type) {
+                Type::Bytes => Type::Array(Box::new(Type::Raw)),
+                item => Type::Array(Box::new(item)),
+            },
+            None => Type::Raw,
+        }
+    }
+
+    /// Same as `of`, but binary data is only supported in arguments and results.
+    fn of_field(wsdl: &Wsdl, name: &QName) -> Type {
+        match Type::of(wsdl, name) {
+            Type::Bytes => Type::Raw,
+            other => other,
+        }
+    }
+
+    fn owned(&self) -> String {
+        match *self {
+            Type::String => "String".into(),
+            Type::Long => "i64".into(),
+            Type::Int => "i32".into(),
+            Type::Bool => "bool".into(),
+            Type::DateTime => "DateTime<Utc>".into(),
+            Type::Bytes => "Vec<u8>".into(),
+            Type::Struct(ref name) => name.clone(),
+            Type::Array(ref item) => format!("Vec<{}>", item.owned()),
+            Type::Raw => "Element".into(),
+        }
+    }
+
+    fn borrowed(&self) -> String {
+        match *self {
+            Type::String => "&str".into(),
+            Type::Bytes => "&[u8]".into(),
+            Type::Array(ref item) => format!("&[{}]", item.owned()),
+            Type::Struct(_) | Type::Raw => format!("&{}", self.owned()),
+            _ => self.owned(),
+        }
+    }
+
+    /// Type name usable in expression position.
+    fn path(&self) -> String {
+        self.owned().replacen('<', "::<", 1)
+    }
+
+    /// Primitive values are always present, everything else may be nil.
+    fn is_required(&self) -> bool {
+        matches!(*self, Type::Long | Type::Int | Type::Bool)
+    }
+}
+
+/// Imports used by the generated code.
+#[derive(Default)]
+struct Imports {
+    datetime: bool,
+    bytes: bool,
+    from_element: bool,
+    structs: bool,
+}
+
+fn generate(wsdl: &Wsdl) -> String {
+    let mut imports = Imports::default();
+    let mut body = String::new();
+
+    for name in referenced_structs(wsdl) {
+        imports.structs = true;
+        generate_struct(wsdl, &name, &mut body, &mut imports);
+    }
+
+    let mut port_types = wsdl.port_types.values().collect::<Vec<_>>();
+    port_types.sort_by(|a, b| a.name.cmp(&b.name));
+    for port_type in port_types {
+        let mut overloads = BTreeMap::new();
+        for operation in &port_type.operations {
+            let inputs = message_parts(wsdl, &operation.input);
+            overloads.entry(operation.name.as_str()).or_insert_with(Vec::new).push(inputs);
+        }
+        for operation in &port_type.operations {
+            let inputs = message_parts(wsdl, &operation.input);
+            let outputs = message_parts(wsdl, &operation.output);
+            let name = function_name(&operation.name, inputs, &overloads[operation.name.as_str()]);
+            let (body, imports) = (&mut body, &mut imports);
+            generate_function(wsdl, &operation.name, &name, inputs, outputs, body, imports);
+        }
+    }
+
+    let mut out = String::new();
+    out.push_str("//! Typed wrappers for every operation of the confluenceservice-v2 API.\n");
+    out.push_str("//!\n");
+    out.push_str("//! Generated by `cargo xtask codegen` from `wsdl/confluenceservice-v2.wsdl`, do not edit.\n");
+    out.push('\n');
+    out.push_str("use crate::rpser::xml::BuildElement;\n");
+    out.push_str("use crate::rpser::Method;\n");
+    let mut transforms = vec![];
+    if imports.structs {
+        transforms.extend(&["add_field", "add_optional_field"]);
+    }
+    if imports.bytes {
+        transforms.push("decode_base64");
+    }
+    if imports.structs {
+        transforms.extend(&["field", "optional_field"]);
+    }
+    if imports.from_element {
+        transforms.push("FromElement");
+    }
+    transforms.push("ToElement");
+    let line = format!("use crate::transforms::{{{}}};", transforms.join(", "));
+    if line.len() <= 100 {
+        let _ = writeln!(out, "{}", line);
+    } else {
+        let _ = writeln!(out, "use crate::transforms::{{\n    {},\n}};", transforms.join(", "));
+    }
+    out.push_str("use crate::{Result, Session};\n");
+    if imports.datetime {
+        out.push_str("use chrono::{DateTime, Utc};\n");
+    }
+    out.push_str("use xmltree::Element;\n");
+    out.push_str(&body);
+    out
+}
+
+/// Names of structures used by operations, directly or through other types.
+fn referenced_structs(wsdl: &Wsdl) -> BTreeSet<String> {
+    let mut pending = wsdl
+        .port_types
+        .values()
+        .flat_map(|port_type| port_type.operations.iter())
+        .flat_map(|operation| {
+            let inputs = message_parts(wsdl, &operation.input);
+            let outputs = message_parts(wsdl, &operation.output);
+            inputs.iter().chain(outputs)
+        })
+        .map(|part| part.type_name.clone())
+        .collect::<Vec<_>>();
+    let mut structs = BTreeSet::new();
+
+    while let Some(name) = pending.pop() {
+        match wsdl.types.get(&name.name) {
+            Some(ComplexType::Struct { base, fields })
+                if !name.is_builtin() && structs.insert(name.name.clone()) =>
+            {
+                pending.extend(base.iter().cloned());
+                pending.extend(fields.iter().map(|field| field.type_name.clone()));
+            }
+            Some(ComplexType::Array { item_type }) if !name.is_builtin() => {
+                pending.push(item_type.clone())
+            }
+            _ => {}
+        }
+    }
+
+    structs
+}
+
+fn generate_struct(wsdl: &Wsdl, name: &str, out: &mut String, imports: &mut Imports) {
+    let type_name = QName {
+        namespace: None,
+        name: name.into(),
+    };
+
+    // base type fields go first
+    let mut levels = vec![];
+    let mut next = wsdl.types.get(name);
+    while let Some(ComplexType::Struct { base, fields }) = next {
+        levels.push(fields);
+        next = base.as_ref().and_then(|base| wsdl.types.get(&base.name));
+    }
+    let fields = levels
+        .into_iter()
+        .rev()
+        .flatten()
+        .map(|field| (field.name.as_str(), Type::of_field(wsdl, &field.type_name)))
+        .collect::<Vec<_>>();
+    debug_assert_eq!(fields.len(), wsdl.fields(&type_name).len());
+
+    let _ = writeln!(out, "\n/// `{}` structure of the remote API.", name);
+    out.push_str("#[derive(Debug, Clone, Default, PartialEq)]\n");
+    let _ = writeln!(out, "pub struct {} {{", name);
+    for &(field, ref ty) in &fields {
+        imports.datetime |= uses_datetime(ty);
+        if ty.is_required() {
+            let _ = writeln!(out, "    pub {}: {},", ident(field), ty.owned());
+        } else {
+            let _ = writeln!(out, "    pub {}: Option<{}>,", ident(field), ty.owned());
+        }
+    }
+    out.push_str("}\n");
+
+    imports.from_element = true;
+    let _ = writeln!(out, "\nimpl FromElement for {} {{", name);
+    let element = if fields.is_empty() { "_element" } else { "element" };
+    let _ = writeln!(out, "    fn from_element({}: Element) -> Result<{}> {{", element, name);
+    let _ = writeln!(out, "        Ok({} {{", name);
+    for &(field, ref ty) in &fields {
+        let convert = if ty.is_required() { "field" } else { "optional_field" };
+        let _ = writeln!(out, "            {}: {}(&element, \"{}\")?,", ident(field), convert, field);
+    }
+    out.push_str("        })\n    }\n}\n");
+
+    let _ = writeln!(out, "\nimpl ToElement for {} {{", name);
+    out.push_str("    fn to_element(&self, name: &str) -> Element {\n");
+    if fields.is_empty() {
+        out.push_str("        Element::node(name)\n");
+    } else {
+        out.push_str("        let mut element = Element::node(name);\n");
+        for &(field, ref ty) in &fields {
+            let add = if ty.is_required() { "add_field" } else { "add_optional_field" };
+            let _ = writeln!(out, "        {}(&mut element, \"{}\", &self.{});", add, field, ident(field));
+        }
+        out.push_str("        element\n");
+    }
+    out.push_str("    }\n}\n");
+}
+
+/// Name of the function generated for the operation, given inputs of all its overloads.
+///
+/// The overload with the fewest parameters keeps the plain name. Other overloads are
+/// named after the parameters that tell them apart, so the names do not depend on
+/// the order of operations in the WSDL: `search_with_parameters` extends `search`,
+/// while `get_page_by_space_key_and_page_title` replaces the parameters of `get_page`.
+fn function_name(operation: &str, inputs: &[Part], overloads: &[&[Part]]) -> String {
+    let names = |parts: &[Part]| parts.iter().map(|part| part.name.clone()).collect::<Vec<_>>();
+    let base = overloads
+        .iter()
+        .min_by_key(|parts| (parts.len(), names(parts)))
+        .map_or_else(Vec::new, |parts| names(parts));
+    let own = names(inputs);
+    if own == base {
+        return snake_case(operation);
+    }
+
+    let distinct = own.iter().filter(|name| !base.contains(name)).map(|name| snake_case(name));
+    let distinct = distinct.collect::<Vec<_>>();
+    assert!(
+        !distinct.is_empty(),
+        "overloads of {} differ only in parameter types",
+        operation
+    );
+    let joiner = if base.iter().all(|name| own.contains(name)) { "with" } else { "by" };
+    format!("{}_{}_{}", snake_case(operation), joiner, distinct.join("_and_"))
+}
+
+fn generate_function(
+    wsdl: &Wsdl,
+    operation: &str,
+    name: &str,
+    inputs: &[Part],
+    outputs: &[Part],
+    out: &mut String,
+    imports: &mut Imports,
+) {
+    // all operations except login take the session token first
+    let takes_token = operation != "login"
+        && matches!(
+            inputs.first(),
+            Some(part) if part.type_name.is_builtin() && part.type_name.name == "string"
+        );
+    let args = inputs
+        .iter()
+        .skip(if takes_token { 1 } else { 0 })
+        .map(|part| (part.name.as_str(), ident(&part.name), Type::of(wsdl, &part.type_name)))
+        .collect::<Vec<_>>();
+    let result = outputs.first().map(|part| (part.name.as_str(), Type::of(wsdl, &part.type_name)));
+
+    if args.is_empty() {
+        let _ = writeln!(out, "\n/// Call `{}`.", operation);
+    } else {
+        let described = args
+            .iter()
+            .map(|&(part, _, ref ty)| format!("`{}: {}`", part, ty.owned()))
+            .collect::<Vec<_>>();
+        let _ = writeln!(out, "\n/// Call `{}` with {}.", operation, described.join(", "));
+    }
+
+    let returns = result.as_ref().map_or_else(|| "()".into(), |(_, ty)| ty.owned());
+    let params = std::iter::once("session: &Session".to_string())
+        .chain(args.iter().map(|(_, arg, ty)| format!("{}: {}", arg, ty.borrowed())))
+        .collect::<Vec<_>>();
+    let signature = format!("pub fn {}({}) -> Result<{}> {{", name, params.join(", "), returns);
+    if signature.len() <= 100 {
+        let _ = writeln!(out, "{}", signature);
+    } else {
+        let _ = writeln!(out, "pub fn {}(", name);
+        for param in &params {
+            let _ = writeln!(out, "    {},", param);
+        }
+        let _ = writeln!(out, ") -> Result<{}> {{", returns);
+    }
+
+    let call = if result.is_some() { "    let response = session.call(\n" } else { "    session.call(\n" };
+    out.push_str(call);
+    let _ = writeln!(out, "        Method::new(\"{}\")", operation);
+    if takes_token {
+        out.push_str("            .with(Element::node(\"token\").with_text(session.token()))\n");
+    }
+    for &(part, ref arg, ref ty) in &args {
+        imports.datetime |= uses_datetime(ty);
+        if *ty == Type::Bytes {
+            let _ = writeln!(
+                out,
+                "            .with(Element::node(\"{}\").with_text(base64::encode({})))",
+                part, arg
+            );
+        } else {
+            let _ = writeln!(out, "            .with({}.to_element(\"{}\"))", arg, part);
+        }
+    }
+    out.push_str("    )?;\n");
+
+    match result {
+        None => out.push_str("\n    Ok(())\n"),
+        Some((part, ty)) => {
+            imports.datetime |= uses_datetime(&ty);
+            let _ = writeln!(out, "\n    let element = response.body.descend(&[\"{}\"])?;\n", part);
+            if ty == Type::Bytes {
+                imports.bytes = true;
+                out.push_str("    decode_base64(&element)\n");
+            } else {
+                imports.from_element = true;
+                let _ = writeln!(out, "    {}::from_element(element)", ty.path());
+            }
+        }
+    }
+    out.push_str("}\n");
+}
+
+fn message_parts<'a>(wsdl: &'a Wsdl, message: &Option<String>) -> &'a [Part] {
+    message
+        .as_ref()
+        .and_then(|name| wsdl.messages.get(name))
+        .map_or(&[], |message| &message.parts[..])
+}
+
+fn uses_datetime(ty: &Type) -> bool {
+    match *ty {
+        Type::DateTime => true,
+        Type::Array(ref item) => uses_datetime(item),
+        _ => false,
+    }
+}
+
+/// Rust identifier for a camel case WSDL name.
+fn ident(name: &str) -> String {
+    let name = snake_case(name);
+    if KEYWORDS.contains(&name.as_str()) {
+        name + "_"
+    } else {
+        name
+    }
+}
+
+fn snake_case(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut out = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let previous = chars[i - 1];
+            let next_is_lower = matches!(chars.get(i + 1), Some(next) if next.is_lowercase());
+            if previous.is_lowercase() || previous.is_ascii_digit() || (previous.is_uppercase() && next_is_lower) {
+                out.push('_');
+            }
+        }
+        out.extend(c.to_lowercase());
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn generated_code_is_up_to_date() {
+        let wsdl = confluence::wsdl::parse(include_str!("../../wsdl/confluenceservice-v2.wsdl")).unwrap();
+        assert!(
+            generate(&wsdl) == include_str!("../../src/generated.rs"),
+            "src/generated.rs is outdated, run `cargo xtask codegen`"
+        );
+    }
+
+    #[test]
+    fn overload_names_do_not_depend_on_wsdl_order() {
+        let functions = |wsdl: &Wsdl| {
+            let code = generate(wsdl);
+            let mut names = code
+                .lines()
+                .filter_map(|line| line.strip_prefix("pub fn "))
+                .map(|line| line.split('(').next().unwrap().to_string())
+                .collect::<Vec<_>>();
+            names.sort();
+            names
+        };
+        let xml = include_str!("../../wsdl/confluenceservice-v2.wsdl");
+        let mut wsdl = confluence::wsdl::parse(xml).unwrap();
+        let names = functions(&wsdl);
+        for port_type in wsdl.port_types.values_mut() {
+            port_type.operations.reverse();
+        }
+
+        assert_eq!(functions(&wsdl), names);
+        for name in &[
+            "get_page",
+            "get_page_by_space_key_and_page_title",
+            "render_content_with_parameters",
+            "add_attachment_with_content_id",
+            "search_with_parameters",
+        ] {
+            assert!(names.contains(&name.to_string()), "{} is not generated", name);
+        }
+    }
+
+    #[test]
+    fn converts_names_to_snake_case() {
+        assert_eq!(snake_case("getPage"), "get_page");
+        assert_eq!(snake_case("getRSSFeed"), "get_rss_feed");
+        assert_eq!(ident("type"), "type_");
+    }
+}
@@ -0,0 +1,51 @@
+/*!
+Development tasks of the `confluence` crate, kept out of the published package.
+
+```text
+cargo xtask fetch-wsdl URL [OUTPUT]
+cargo xtask codegen [WSDL] [OUTPUT]
+```
+*/
+
+mod codegen;
+
+use confluence::http::{self, Client};
+use std::{env, fs, process};
+
+/// Location of the WSDL bundled with the crate.
+const BUNDLED_WSDL: &str =
+    concat!(env!("CARGO_MANIFEST_DIR"), "/../wsdl/confluenceservice-v2.wsdl");
+
+/// Path of the WSDL published by Confluence, relative to the server url.
+const WSDL_PATH: &str = "/rpc/soap-axis/confluenceservice-v2?wsdl";
+
+const USAGE: &str = "usage:
+    cargo xtask fetch-wsdl URL [OUTPUT]   store WSDL published by the Confluence server at URL
+    cargo xtask codegen [WSDL] [OUTPUT]   generate src/generated.rs from the WSDL";
+
+fn main() {
+    let mut args = env::args().skip(1);
+    match args.next().as_deref() {
+        Some("fetch-wsdl") => fetch_wsdl(args),
+        Some("codegen") => codegen::run(args),
+        _ => fail("xtask", USAGE),
+    }
+}
+
+/// Replace the bundled WSDL with the one published by a live server.
+fn fetch_wsdl<I: Iterator<Item = String>>(mut args: I) {
+    let server_url = args.next().unwrap_or_else(|| fail("xtask", USAGE));
+    let output = args.next().unwrap_or_else(|| BUNDLED_WSDL.into());
+    let url = [server_url.trim_end_matches('/'), WSDL_PATH].concat();
+
+    let response = http::get(&Client::new(), &url).unwrap_or_else(|e| fail(&url, e));
+    if !response.status.is_success() {
+        fail::<_, ()>(&url, response.status);
+    }
+    fs::write(&output, response.body).unwrap_or_else(|e| fail(&output, e));
+}
+
+fn fail<E: std::fmt::Display, T>(path: &str, error: E) -> T {
+    eprintln!("{}: {}", path, error);
+    process::exit(1)
+}